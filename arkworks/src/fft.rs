@@ -20,17 +20,29 @@ impl FFTFr<BlstFr> for FFTSettings {
             &self.expanded_roots_of_unity
         };
 
-        fft_fr_fast(&mut ret, data, 1, roots, stride);
+        let transform = |ret: &mut [BlstFr]| {
+            fft_fr_fast(ret, data, 1, roots, stride);
 
-        if inverse {
-            let inv_fr_len = BlstFr::from_u64(data.len() as u64).inverse();
-            ret[..data.len()]
-                .iter_mut()
-                .for_each(|f| *f = BlstFr::mul(f, &inv_fr_len));
-        }
+            if inverse {
+                let inv_fr_len = BlstFr::from_u64(data.len() as u64).inverse();
+                ret[..data.len()]
+                    .iter_mut()
+                    .for_each(|f| *f = BlstFr::mul(f, &inv_fr_len));
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| transform(&mut ret));
+
+        #[cfg(not(feature = "parallel"))]
+        transform(&mut ret);
 
         Ok(ret)
     }
+
+    fn fft_fr_inplace(&self, data: &mut [BlstFr], inverse: bool) -> Result<(), String> {
+        kzg::fft::fft_fr_inplace(self, data, inverse)
+    }
 }
 
 pub fn fft_fr_fast(
@@ -56,16 +56,29 @@ impl FFTG1<ArkG1> for FFTSettings {
             &self.expanded_roots_of_unity
         };
 
-        fft_g1_fast(&mut ret, data, 1, roots, stride, 1);
+        let transform = |ret: &mut [ArkG1]| {
+            fft_g1_fast(ret, data, 1, roots, stride, 1);
+
+            if inverse {
+                let inv_fr_len = ArkFr::from_u64(data.len() as u64).inverse();
+                ret[..data.len()]
+                    .iter_mut()
+                    .for_each(|f| f.0.mul_assign(&inv_fr_len.fr));
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| transform(&mut ret));
+
+        #[cfg(not(feature = "parallel"))]
+        transform(&mut ret);
 
-        if inverse {
-            let inv_fr_len = ArkFr::from_u64(data.len() as u64).inverse();
-            ret[..data.len()]
-                .iter_mut()
-                .for_each(|f| f.0.mul_assign(&inv_fr_len.fr));
-        }
         Ok(ret)
     }
+
+    fn fft_g1_inplace(&self, data: &mut [ArkG1], inverse: bool) -> Result<(), String> {
+        kzg::fft::fft_g1_inplace(self, data, inverse)
+    }
 }
 
 pub fn fft_g1_slow(
@@ -1,7 +1,9 @@
 #![allow(non_camel_case_types)]
 use super::utils::{blst_poly_into_pc_poly, PolyData};
 use crate::consts::{G1_GENERATOR, G2_GENERATOR};
-use crate::kzg_types::{ArkFp, ArkFr, ArkG1Affine};
+use crate::kzg_types::ArkFr;
+#[cfg(feature = "prover")]
+use crate::kzg_types::{ArkFp, ArkG1Affine};
 use crate::kzg_types::{ArkFr as BlstFr, ArkG1, ArkG2};
 use ark_bls12_381::Bls12_381;
 use ark_ec::pairing::Pairing;
@@ -9,10 +11,14 @@ use ark_ec::CurveGroup;
 use ark_poly::Polynomial;
 use ark_std::{vec, One};
 use kzg::eip_4844::hash_to_bls_field;
+use kzg::fft::CosetCache;
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::PrecomputationTable;
 use kzg::Fr as FrTrait;
 use kzg::{G1Mul, G2Mul};
 use std::ops::Neg;
+#[cfg(feature = "prover")]
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone)]
 pub struct FFTSettings {
@@ -21,6 +27,10 @@ pub struct FFTSettings {
     pub expanded_roots_of_unity: Vec<BlstFr>,
     pub reverse_roots_of_unity: Vec<BlstFr>,
     pub roots_of_unity: Vec<BlstFr>,
+    pub coset_cache: CosetCache<BlstFr>,
+    /// Pool FFTs and recovery run on, see [`kzg::FFTSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, String> {
@@ -41,12 +51,22 @@ pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>,
     Ok(generated_powers)
 }
 
+#[cfg(feature = "prover")]
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fs: FFTSettings,
     pub secret_g1: Vec<ArkG1>,
+    /// `[τ^i]` in G1 when `secret_g1` are Lagrange-form points, as for loaded EIP-4844 setups,
+    /// derived from them on first use unless given. `None` when `secret_g1` are the monomial
+    /// powers themselves.
+    pub secret_g1_monomial: Option<OnceLock<Vec<ArkG1>>>,
+    /// `[τ^i]` in G2
     pub secret_g2: Vec<ArkG2>,
     pub precomputation: Option<PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>>,
+    /// Pool commitments, proofs and batch verification run on, see
+    /// [`KZGSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 pub fn generate_trusted_setup(len: usize, secret: [u8; 32usize]) -> (Vec<ArkG1>, Vec<ArkG2>) {
@@ -2,12 +2,16 @@ use crate::consts::{
     G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR, G2_GENERATOR, G2_NEGATIVE_GENERATOR,
     SCALE2_ROOT_OF_UNITY,
 };
+#[cfg(feature = "prover")]
 use crate::fft_g1::g1_linear_combination;
+#[cfg(feature = "prover")]
+use crate::kzg_proofs::KZGSettings as LKZGSettings;
 use crate::kzg_proofs::{
     eval_poly, expand_root_of_unity, pairings_verify, FFTSettings as LFFTSettings,
-    KZGSettings as LKZGSettings,
 };
-use crate::poly::{poly_fast_div, poly_inverse, poly_long_div, poly_mul_direct, poly_mul_fft};
+use crate::poly::{
+    poly_fast_div, poly_inverse, poly_long_div, poly_mul, poly_mul_direct, poly_mul_fft,
+};
 use crate::recover::{scale_poly, unscale_poly};
 use crate::utils::{
     blst_fp_into_pc_fq, blst_fr_into_pc_fr, blst_p1_into_pc_g1projective,
@@ -25,14 +29,27 @@ use ark_std::{One, Zero};
 use ark_std::UniformRand;
 
 use blst::{blst_fp, blst_fr, blst_p1};
-use kzg::common_utils::reverse_bit_order;
+use kzg::common_utils::{log2_pow2, next_pow_of_2, reverse_bit_order};
+#[cfg(feature = "prover")]
+use kzg::eip_4844::g1_monomial_from_lagrange_brp;
 use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::fft::ShiftPowers;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{
+    g1_fixed_base_mul, g1_fixed_base_table, g2_fixed_base_mul, g2_fixed_base_table, FixedBaseTable,
+};
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::{precompute, PrecomputationTable};
+#[cfg(feature = "prover")]
+use kzg::{FFTFr, G1LinComb, KZGSettings};
 use kzg::{
-    FFTFr, FFTSettings, FFTSettingsPoly, Fr as KzgFr, G1Affine as G1AffineTrait, G1Fp, G1GetFp,
-    G1LinComb, G1Mul, G1ProjAddAffine, G2Mul, KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    FFTSettings, FFTSettingsPoly, Fr as KzgFr, G1Affine as G1AffineTrait, G1Fp, G1GetFp, G1Mul,
+    G1ProjAddAffine, G2Mul, PairingVerify, Poly, Scalar256, G1, G2,
 };
 use std::ops::{AddAssign, Mul, Neg, Sub};
+use std::sync::Arc;
+#[cfg(feature = "prover")]
+use std::sync::OnceLock;
 
 fn bytes_be_to_uint64(inp: &[u8]) -> u64 {
     u64::from_be_bytes(inp.try_into().expect("Input wasn't 8 elements..."))
@@ -386,8 +403,15 @@ impl G1Mul<ArkFr> for ArkG1 {
     fn mul(&self, b: &ArkFr) -> Self {
         Self(self.0.mul(b.fr))
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &ArkFr) -> Self {
+        static TABLE: OnceLock<FixedBaseTable<ArkG1>> = OnceLock::new();
+        g1_fixed_base_mul(TABLE.get_or_init(|| g1_fixed_base_table(&G1_GENERATOR)), b)
+    }
 }
 
+#[cfg(feature = "prover")]
 impl G1LinComb<ArkFr, ArkFp, ArkG1Affine> for ArkG1 {
     fn g1_lincomb(
         points: &[Self],
@@ -477,6 +501,12 @@ impl G2Mul<ArkFr> for ArkG2 {
     fn mul(&self, b: &ArkFr) -> Self {
         Self(self.0.mul(&b.fr))
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &ArkFr) -> Self {
+        static TABLE: OnceLock<FixedBaseTable<ArkG2>> = OnceLock::new();
+        g2_fixed_base_mul(TABLE.get_or_init(|| g2_fixed_base_table(&G2_GENERATOR)), b)
+    }
 }
 
 impl Poly<ArkFr> for PolyData {
@@ -486,6 +516,12 @@ impl Poly<ArkFr> for PolyData {
         }
     }
 
+    fn from_coeffs(coeffs: &[ArkFr]) -> Self {
+        Self {
+            coeffs: coeffs.to_vec(),
+        }
+    }
+
     fn get_coeff_at(&self, i: usize) -> ArkFr {
         self.coeffs[i]
     }
@@ -515,7 +551,7 @@ impl Poly<ArkFr> for PolyData {
     }
 
     fn inverse(&mut self, new_len: usize) -> Result<Self, String> {
-        poly_inverse(self, new_len)
+        poly_inverse(self, new_len, None)
     }
 
     fn div(&mut self, x: &Self) -> Result<Self, String> {
@@ -537,6 +573,33 @@ impl Poly<ArkFr> for PolyData {
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String> {
         poly_mul_direct(self, x, len)
     }
+
+    fn compose(&self, other: &Self) -> Result<Self, String> {
+        let Some((last, rest)) = self.coeffs.split_last() else {
+            return Ok(Self::new(0));
+        };
+        if other.coeffs.is_empty() {
+            return Ok(Self {
+                coeffs: vec![self.coeffs[0]],
+            });
+        }
+
+        // Every intermediate product is shorter than the last one, so settings sized for it can
+        // be used throughout
+        let out_len = rest.len() * (other.len() - 1) + 1;
+        let fs = LFFTSettings::new(log2_pow2(next_pow_of_2(out_len)))?;
+
+        let mut out = Self {
+            coeffs: vec![*last],
+        };
+        for coeff in rest.iter().rev() {
+            let len = out.len() + other.len() - 1;
+            out = poly_mul(&out, other, Some(&fs), len)?;
+            out.coeffs[0] = out.coeffs[0].add(coeff);
+        }
+
+        Ok(out)
+    }
 }
 
 impl FFTSettingsPoly<ArkFr, PolyData, LFFTSettings> for LFFTSettings {
@@ -558,6 +621,9 @@ impl Default for LFFTSettings {
             expanded_roots_of_unity: Vec::new(),
             reverse_roots_of_unity: Vec::new(),
             roots_of_unity: Vec::new(),
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         }
     }
 }
@@ -587,6 +653,9 @@ impl FFTSettings<ArkFr> for LFFTSettings {
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         })
     }
 
@@ -617,8 +686,18 @@ impl FFTSettings<ArkFr> for LFFTSettings {
     fn get_roots_of_unity(&self) -> &[ArkFr] {
         &self.roots_of_unity
     }
+
+    fn get_coset_shift_powers(&self, shift: &ArkFr, len: usize) -> Arc<ShiftPowers<ArkFr>> {
+        self.coset_cache.get(shift, len)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
 
+#[cfg(feature = "prover")]
 impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine> for LKZGSettings {
     fn new(
         secret_g1: &[ArkG1],
@@ -628,25 +707,72 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
     ) -> Result<LKZGSettings, String> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
+            secret_g1_monomial: None,
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
             precomputation: precompute(secret_g1).ok().flatten(),
+            #[cfg(feature = "parallel")]
+            execution_context: fft_settings.execution_context.clone(),
+        })
+    }
+
+    fn new_with_monomial(
+        secret_g1: &[ArkG1],
+        secret_g1_monomial: Option<&[ArkG1]>,
+        secret_g2: &[ArkG2],
+        length: usize,
+        fft_settings: &LFFTSettings,
+    ) -> Result<LKZGSettings, String> {
+        let monomial = match secret_g1_monomial {
+            Some(secret_g1_monomial) => {
+                if secret_g1_monomial.len() != secret_g1.len() {
+                    return Err(String::from(
+                        "Monomial and Lagrange-form G1 points must be as many",
+                    ));
+                }
+                OnceLock::from(secret_g1_monomial.to_vec())
+            }
+            None => {
+                // Checked here, deriving the monomial points later can't return an error
+                if !secret_g1.len().is_power_of_two() || secret_g1.len() > fft_settings.max_width {
+                    return Err(String::from(
+                        "Lagrange-form G1 points must be a power of two, up to the FFT width",
+                    ));
+                }
+                OnceLock::new()
+            }
+        };
+
+        Ok(Self {
+            secret_g1_monomial: Some(monomial),
+            ..Self::new(secret_g1, secret_g2, length, fft_settings)?
         })
     }
 
     fn commit_to_poly(&self, p: &PolyData) -> Result<ArkG1, String> {
-        if p.coeffs.len() > self.secret_g1.len() {
+        let secret_g1 = self.get_g1_monomial();
+        if p.coeffs.len() > secret_g1.len() {
             return Err(String::from("Polynomial is longer than secret g1"));
         }
 
         let mut out = ArkG1::default();
-        g1_linear_combination(
-            &mut out,
-            &self.secret_g1,
-            &p.coeffs,
-            p.coeffs.len(),
-            self.get_precomputation(),
-        );
+        // The precomputation is over `secret_g1`, which isn't monomial for loaded setups. Those
+        // commit to coefficients with a plain MSM, rather than keep a second table as large
+        let precomputation = if self.secret_g1_monomial.is_none() {
+            self.get_precomputation()
+        } else {
+            None
+        };
+
+        let lincomb = |out: &mut ArkG1| {
+            g1_linear_combination(out, secret_g1, &p.coeffs, p.coeffs.len(), precomputation)
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| lincomb(&mut out));
+
+        #[cfg(not(feature = "parallel"))]
+        lincomb(&mut out);
 
         Ok(out)
     }
@@ -656,18 +782,7 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
             return Err(String::from("Polynomial must not be empty"));
         }
 
-        // `-(x0^n)`, where `n` is `1`
-        let divisor_0 = x.negate();
-
-        // Calculate `q = p / (x^n - x0^n)` for our reduced case (see `compute_proof_multi` for
-        // generic implementation)
-        let mut out_coeffs = Vec::from(&p.coeffs[1..]);
-        for i in (1..out_coeffs.len()).rev() {
-            let tmp = out_coeffs[i].mul(&divisor_0);
-            out_coeffs[i - 1] = out_coeffs[i - 1].sub(&tmp);
-        }
-
-        let q = PolyData { coeffs: out_coeffs };
+        let q = p.divide_by_linear(x);
         let ret = self.commit_to_poly(&q)?;
         Ok(ret)
         // Ok(compute_single(p, x, self))
@@ -680,9 +795,9 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         x: &ArkFr,
         y: &ArkFr,
     ) -> Result<bool, String> {
-        let x_g2: ArkG2 = G2_GENERATOR.mul(x);
+        let x_g2: ArkG2 = ArkG2::generator_mul(x);
         let s_minus_x: ArkG2 = self.secret_g2[1].sub(&x_g2);
-        let y_g1 = G1_GENERATOR.mul(y);
+        let y_g1 = ArkG1::generator_mul(y);
         let commitment_minus_y: ArkG1 = com.sub(&y_g1);
 
         Ok(pairings_verify(
@@ -724,7 +839,14 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
 
         // Calculate q = p / (x^n - x0^n)
         // let q = p.div(&divisor).unwrap();
-        let q = new_polina.div(&divisor)?;
+        let divide = |dividend: &mut PolyData| dividend.div(&divisor);
+
+        #[cfg(feature = "parallel")]
+        let q = self.execution_context.install(|| divide(&mut new_polina))?;
+
+        #[cfg(not(feature = "parallel"))]
+        let q = divide(&mut new_polina)?;
+
         let ret = self.commit_to_poly(&q)?;
         Ok(ret)
     }
@@ -742,21 +864,14 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         }
 
         // Interpolate at a coset.
-        let mut interp = PolyData {
-            coeffs: self.fs.fft_fr(ys, true)?,
+        let interp = PolyData {
+            coeffs: self.fs.coset_fft_fr(ys, x, true)?,
         };
 
-        let inv_x = x.inverse(); // Not euclidean?
-        let mut inv_x_pow = inv_x;
-        for i in 1..n {
-            interp.coeffs[i] = interp.coeffs[i].mul(&inv_x_pow);
-            inv_x_pow = inv_x_pow.mul(&inv_x);
-        }
-
         // [x^n]_2
-        let x_pow = inv_x_pow.inverse();
+        let x_pow = x.pow(n);
 
-        let xn2 = G2_GENERATOR.mul(&x_pow);
+        let xn2 = ArkG2::generator_mul(&x_pow);
 
         // [s^n - x^n]_2
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
@@ -788,6 +903,24 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[ArkG1] {
+        match &self.secret_g1_monomial {
+            None => &self.secret_g1,
+            // Only fails for settings built by hand with a wrong number of points
+            Some(monomial) => monomial.get_or_init(|| {
+                g1_monomial_from_lagrange_brp(&self.secret_g1, &self.fs).unwrap_or_default()
+            }),
+        }
+    }
+
+    fn get_g1_lagrange_brp(&self) -> &[ArkG1] {
+        if self.secret_g1_monomial.is_some() {
+            &self.secret_g1
+        } else {
+            &[]
+        }
+    }
+
     fn get_g2_secret(&self) -> &[ArkG2] {
         &self.secret_g2
     }
@@ -795,6 +928,11 @@ impl KZGSettings<ArkFr, ArkG1, ArkG2, LFFTSettings, PolyData, ArkFp, ArkG1Affine
     fn get_precomputation(&self) -> Option<&PrecomputationTable<ArkFr, ArkG1, ArkFp, ArkG1Affine>> {
         self.precomputation.as_ref()
     }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
 
 type ArkFpInt = <ark_bls12_381::g1::Config as CurveConfig>::BaseField;
@@ -11,10 +11,14 @@ pub type Scalar = blst::blst_scalar;
 pub type Uniq = blst::blst_uniq;
 
 pub mod consts;
+#[cfg(feature = "prover")]
 pub mod das;
+#[cfg(feature = "prover")]
 pub mod eip_4844;
 pub mod fft;
+#[cfg(feature = "prover")]
 pub mod fft_g1;
+#[cfg(feature = "prover")]
 pub mod fk20_proofs;
 pub mod kzg_proofs;
 pub mod kzg_types;
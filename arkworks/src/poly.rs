@@ -11,7 +11,11 @@ use kzg::common_utils::{log2_pow2, next_pow_of_2};
 use kzg::{FFTFr, FFTSettings as FFTSettingsT, Fr as FrTrait, Poly};
 use std::cmp::min;
 
-pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String> {
+pub fn poly_inverse(
+    b: &PolyData,
+    output_len: usize,
+    fs: Option<&FFTSettings>,
+) -> Result<PolyData, String> {
     if b.coeffs.is_empty() {
         return Err(String::from("b.coeffs is empty"));
     }
@@ -32,8 +36,16 @@ pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String>
     }
 
     let maxd = output_len - 1;
-    let scale = next_pow_of_2(log2_pow2(2 * output_len - 1));
-    let fs = FFTSettings::new(scale).unwrap();
+    // Every product below is truncated to at most output_len coefficients, so settings for the
+    // longest one can be shared by all of them
+    let local_fs;
+    let fs = match fs {
+        Some(fs) => fs,
+        None => {
+            local_fs = FFTSettings::new(log2_pow2(next_pow_of_2(2 * output_len - 1)))?;
+            &local_fs
+        }
+    };
 
     let mut tmp0: PolyData;
     let mut tmp1: PolyData;
@@ -48,7 +60,7 @@ pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String>
 
         let len_temp: usize = min(d + 1, b.coeffs.len() + output.coeffs.len() - 1);
 
-        tmp0 = poly_mul(b, &output, Some(&fs), len_temp).unwrap();
+        tmp0 = poly_mul(b, &output, Some(fs), len_temp).unwrap();
 
         for i in 0..len_temp {
             tmp0.coeffs[i] = tmp0.coeffs[i].negate();
@@ -58,7 +70,7 @@ pub fn poly_inverse(b: &PolyData, output_len: usize) -> Result<PolyData, String>
 
         let len_temp2: usize = d + 1;
 
-        tmp1 = poly_mul(&output, &tmp0, Some(&fs), len_temp2).unwrap();
+        tmp1 = poly_mul(&output, &tmp0, Some(fs), len_temp2).unwrap();
 
         if tmp1.coeffs.len() > output_len {
             tmp1.coeffs = tmp1.coeffs[..output_len].to_vec();
@@ -126,11 +138,13 @@ pub fn poly_mul_fft(
     let length = next_pow_of_2(a_len + b_len - 1);
 
     // If the FFT settings are NULL then make a local set, otherwise use the ones passed in.
-    let fs_p = if let Some(x) = fs {
-        x.clone()
-    } else {
-        let scale = log2_pow2(length);
-        FFTSettings::new(scale).unwrap()
+    let local_fs;
+    let fs_p = match fs {
+        Some(fs) => fs,
+        None => {
+            local_fs = FFTSettings::new(log2_pow2(length))?;
+            &local_fs
+        }
     };
 
     if length > fs_p.max_width {
@@ -232,8 +246,10 @@ pub fn poly_fast_div(dividend: &PolyData, divisor: &PolyData) -> Result<PolyData
     let a_flip = poly_flip(dividend).unwrap();
     let b_flip = poly_flip(divisor).unwrap();
 
-    let inv_b_flip = poly_inverse(&b_flip, m - n + 1).unwrap();
-    let q_flip = poly_mul(&a_flip, &inv_b_flip, None, m - n + 1).unwrap();
+    let out_len = m - n + 1;
+    let fs = FFTSettings::new(log2_pow2(next_pow_of_2(2 * out_len - 1)))?;
+    let inv_b_flip = poly_inverse(&b_flip, out_len, Some(&fs)).unwrap();
+    let q_flip = poly_mul(&a_flip, &inv_b_flip, Some(&fs), out_len).unwrap();
 
     out = poly_flip(&q_flip).unwrap();
 
@@ -3,90 +3,41 @@ use crate::kzg_proofs::FFTSettings;
 use crate::kzg_types::ArkFr as BlstFr;
 use crate::utils::PolyData;
 
+use kzg::fft::ShiftPowers;
 use kzg::{FFTFr, Fr, Poly, PolyRecover, ZeroPoly};
 
 #[cfg(feature = "parallel")]
 use kzg::common_utils::next_pow_of_2;
 
-#[cfg(feature = "parallel")]
-static mut INVERSE_FACTORS: Vec<BlstFr> = Vec::new();
-#[cfg(feature = "parallel")]
-static mut UNSCALE_FACTOR_POWERS: Vec<BlstFr> = Vec::new();
+/// `1 / SCALE_FACTOR`, the shift of the coset the division in recovery happens over. None of the
+/// roots of unity lie on that coset, so `Z_r,I` has no zeroes there.
+fn recovery_shift() -> BlstFr {
+    BlstFr::from_u64(SCALE_FACTOR).inverse()
+}
 
-#[allow(clippy::needless_range_loop)]
+/// x -> k * x, for `k` the recovery shift
 pub fn scale_poly(p: &mut PolyData) {
-    let scale_factor = BlstFr::from_u64(SCALE_FACTOR);
-    let inv_factor = scale_factor.inverse();
-    #[cfg(feature = "parallel")]
-    {
-        let optim = next_pow_of_2(p.len() - 1);
-        if optim <= 1024 {
-            unsafe {
-                if INVERSE_FACTORS.len() < p.len() {
-                    if INVERSE_FACTORS.is_empty() {
-                        INVERSE_FACTORS.push(BlstFr::one());
-                    }
-                    for i in (INVERSE_FACTORS.len())..p.len() {
-                        INVERSE_FACTORS.push(INVERSE_FACTORS[i - 1].mul(&inv_factor));
-                    }
-                }
-
-                for i in 1..p.len() {
-                    p.coeffs[i] = p.coeffs[i].mul(&INVERSE_FACTORS[i]);
-                }
-            }
-        } else {
-            let mut factor_power = BlstFr::one();
-            for i in 1..p.len() {
-                factor_power = factor_power.mul(&inv_factor);
-                p.set_coeff_at(i, &p.get_coeff_at(i).mul(&factor_power));
-            }
-        }
-    }
-    #[cfg(not(feature = "parallel"))]
-    {
-        let mut factor_power = BlstFr::one();
-        for i in 1..p.len() {
-            factor_power = factor_power.mul(&inv_factor);
-            p.set_coeff_at(i, &p.get_coeff_at(i).mul(&factor_power));
-        }
+    let shift_powers = ShiftPowers::new(&recovery_shift(), p.len());
+    for (coeff, power) in p.coeffs.iter_mut().zip(&shift_powers.powers).skip(1) {
+        *coeff = coeff.mul(power);
     }
 }
 
-#[allow(clippy::needless_range_loop)]
+/// k * x -> x, undoing [`scale_poly`]
 pub fn unscale_poly(p: &mut PolyData) {
-    let scale_factor = BlstFr::from_u64(SCALE_FACTOR);
-    #[cfg(feature = "parallel")]
-    {
-        unsafe {
-            if UNSCALE_FACTOR_POWERS.len() < p.len() {
-                if UNSCALE_FACTOR_POWERS.is_empty() {
-                    UNSCALE_FACTOR_POWERS.push(BlstFr::one());
-                }
-                for i in (UNSCALE_FACTOR_POWERS.len())..p.len() {
-                    UNSCALE_FACTOR_POWERS.push(UNSCALE_FACTOR_POWERS[i - 1].mul(&scale_factor));
-                }
-            }
-
-            for i in 1..p.len() {
-                p.coeffs[i] = p.coeffs[i].mul(&UNSCALE_FACTOR_POWERS[i]);
-            }
-        }
-    }
-    #[cfg(not(feature = "parallel"))]
+    let shift_powers = ShiftPowers::new(&recovery_shift(), p.len());
+    for (coeff, power) in p
+        .coeffs
+        .iter_mut()
+        .zip(&shift_powers.inverse_powers)
+        .skip(1)
     {
-        let mut factor_power = BlstFr::one();
-        for i in 1..p.len() {
-            factor_power = factor_power.mul(&scale_factor);
-            p.set_coeff_at(i, &p.get_coeff_at(i).mul(&factor_power));
-        }
+        *coeff = coeff.mul(power);
     }
 }
-impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
-    fn recover_poly_coeffs_from_samples(
-        samples: &[Option<BlstFr>],
-        fs: &FFTSettings,
-    ) -> Result<Self, String> {
+
+impl PolyData {
+    fn recover_coeffs(samples: &[Option<BlstFr>], fs: &FFTSettings) -> Result<Self, String> {
         if !samples.len().is_power_of_two() {
             return Err(String::from("samples lenght has to be power of 2"));
         }
@@ -106,7 +57,7 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         }
 
         // Calculate `Z_r,I`
-        let (zero_eval, mut zero_poly) =
+        let (zero_eval, zero_poly) =
             fs.zero_poly_via_multiplication(samples.len(), missing.as_slice())?;
 
         // Check all is well
@@ -129,85 +80,60 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         }
 
         // Now inverse FFT so that poly_with_zero is (E * Z_r,I)(x) = (D * Z_r,I)(x)
-        let mut poly_with_zero = PolyData {
-            coeffs: fs
-                .fft_fr(poly_evaluations_with_zero.as_slice(), true)
-                .unwrap(),
-        };
+        fs.fft_fr_inplace(&mut poly_evaluations_with_zero, true)
+            .unwrap();
+        let poly_with_zero = poly_evaluations_with_zero;
 
-        #[cfg(feature = "parallel")]
-        let optim = next_pow_of_2(poly_with_zero.len() - 1);
+        // Evaluate both over the coset `k * w^i`, where `Z_r,I` has no zeroes:
+        // Q1 = (D * Z_r,I)(k * x) and Q2 = Z_r,I(k * x)
+        let shift = recovery_shift();
+        let coset_evals = |coeffs: &[BlstFr]| fs.coset_fft_fr(coeffs, &shift, false);
 
         #[cfg(feature = "parallel")]
-        {
-            if optim > 1024 {
+        let (eval_scaled_reconstructed_poly, eval_scaled_zero_poly) =
+            if next_pow_of_2(poly_with_zero.len() - 1) > 1024 {
                 rayon::join(
-                    || scale_poly(&mut poly_with_zero),
-                    || scale_poly(&mut zero_poly),
-                );
+                    || coset_evals(&poly_with_zero),
+                    || coset_evals(&zero_poly.coeffs),
+                )
             } else {
-                scale_poly(&mut poly_with_zero);
-                scale_poly(&mut zero_poly);
-            }
-        }
+                (coset_evals(&poly_with_zero), coset_evals(&zero_poly.coeffs))
+            };
         #[cfg(not(feature = "parallel"))]
-        {
-            scale_poly(&mut poly_with_zero);
-            scale_poly(&mut zero_poly);
-        }
-
-        // Q1 = (D * Z_r,I)(k * x)
-        let scaled_poly_with_zero = poly_with_zero; // Renaming
-                                                    // Q2 = Z_r,I(k * x)
-        let scaled_zero_poly = zero_poly.coeffs; // Renaming
+        let (eval_scaled_reconstructed_poly, eval_scaled_zero_poly) =
+            (coset_evals(&poly_with_zero), coset_evals(&zero_poly.coeffs));
 
-        let eval_scaled_poly_with_zero;
-        let eval_scaled_zero_poly;
+        let mut eval_scaled_reconstructed_poly = eval_scaled_reconstructed_poly?;
+        let eval_scaled_zero_poly = eval_scaled_zero_poly?;
 
-        #[cfg(feature = "parallel")]
-        {
-            if optim > 1024 {
-                let mut eval_scaled_poly_with_zero_temp = vec![];
-                let mut eval_scaled_zero_poly_temp = vec![];
-                rayon::join(
-                    || {
-                        eval_scaled_poly_with_zero_temp =
-                            fs.fft_fr(&scaled_poly_with_zero.coeffs, false).unwrap()
-                    },
-                    || eval_scaled_zero_poly_temp = fs.fft_fr(&scaled_zero_poly, false).unwrap(),
-                );
-
-                eval_scaled_poly_with_zero = eval_scaled_poly_with_zero_temp;
-                eval_scaled_zero_poly = eval_scaled_zero_poly_temp;
-            } else {
-                eval_scaled_poly_with_zero =
-                    fs.fft_fr(&scaled_poly_with_zero.coeffs, false).unwrap();
-                eval_scaled_zero_poly = fs.fft_fr(&scaled_zero_poly, false).unwrap();
-            }
-        }
-        #[cfg(not(feature = "parallel"))]
+        // Q3 = Q1 / Q2
+        for (reconstructed, zero) in eval_scaled_reconstructed_poly
+            .iter_mut()
+            .zip(&eval_scaled_zero_poly)
         {
-            eval_scaled_poly_with_zero = fs.fft_fr(&scaled_poly_with_zero.coeffs, false).unwrap();
-            eval_scaled_zero_poly = fs.fft_fr(&scaled_zero_poly, false).unwrap();
+            *reconstructed = reconstructed.div(zero).unwrap();
         }
 
-        let mut eval_scaled_reconstructed_poly = eval_scaled_poly_with_zero.clone();
-        for i in 0..samples.len() {
-            eval_scaled_reconstructed_poly[i] = eval_scaled_poly_with_zero[i]
-                .div(&eval_scaled_zero_poly[i])
-                .unwrap();
-        }
+        // The result of the division is D(k * x), and interpolating it over the coset gives D(x),
+        // which evaluates to our original data at the powers of roots of unity
+        Ok(PolyData {
+            coeffs: fs.coset_fft_fr(&eval_scaled_reconstructed_poly, &shift, true)?,
+        })
+    }
+}
 
-        // The result of the division is D(k * x):
-        let mut scaled_reconstructed_poly = PolyData {
-            coeffs: fs.fft_fr(&eval_scaled_reconstructed_poly, true).unwrap(),
-        };
+impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
+    fn recover_poly_coeffs_from_samples(
+        samples: &[Option<BlstFr>],
+        fs: &FFTSettings,
+    ) -> Result<Self, String> {
+        let recover = || Self::recover_coeffs(samples, fs);
 
-        // k * x -> x
-        unscale_poly(&mut scaled_reconstructed_poly);
+        #[cfg(feature = "parallel")]
+        return fs.execution_context.install(recover);
 
-        // Finally we have D(x) which evaluates to our original data at the powers of roots of unity
-        Ok(scaled_reconstructed_poly)
+        #[cfg(not(feature = "parallel"))]
+        recover()
     }
 
     fn recover_poly_from_samples(
@@ -217,9 +143,8 @@ impl PolyRecover<BlstFr, PolyData, FFTSettings> for PolyData {
         let reconstructed_poly = Self::recover_poly_coeffs_from_samples(samples, fs)?;
 
         // The evaluation polynomial for D(x) is the reconstructed data:
-        let out = PolyData {
-            coeffs: fs.fft_fr(&reconstructed_poly.coeffs, false).unwrap(),
-        };
+        let mut out = reconstructed_poly;
+        fs.fft_fr_inplace(&mut out.coeffs, false).unwrap();
 
         // Check all is well
         for (i, sample) in samples.iter().enumerate() {
@@ -62,6 +62,11 @@ mod tests {
         p1_mul_works::<ArkFr, ArkG1>();
     }
 
+    #[test]
+    pub fn g1_generator_mul_works_() {
+        g1_generator_mul_works::<ArkFr, ArkG1>();
+    }
+
     #[test]
     pub fn p1_sub_works_() {
         p1_sub_works::<ArkG1>();
@@ -77,6 +82,11 @@ mod tests {
         p2_mul_works::<ArkFr, ArkG2>();
     }
 
+    #[test]
+    pub fn g2_generator_mul_works_() {
+        g2_generator_mul_works::<ArkFr, ArkG2>();
+    }
+
     #[test]
     pub fn p2_sub_works_() {
         p2_sub_works::<ArkG2>();
@@ -102,6 +112,11 @@ mod tests {
         g1_random_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine>(&g1_linear_combination);
     }
 
+    #[test]
+    pub fn g1_sparse_linear_combination_() {
+        g1_sparse_linear_combination::<ArkFr, ArkG1, ArkFp, ArkG1Affine>(&g1_linear_combination);
+    }
+
     #[test]
     pub fn pairings_work_() {
         pairings_work::<ArkFr, ArkG1, ArkG2>(&pairings_verify);
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::das2d::{das_2d_commitments, das_2d_extend_and_recover};
+    use rust_kzg_arkworks::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
+    use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
+    use rust_kzg_arkworks::utils::PolyData;
+
+    #[test]
+    fn das_2d_extend_and_recover_() {
+        das_2d_extend_and_recover::<ArkFr, FFTSettings, PolyData, PolyData>();
+    }
+
+    #[test]
+    fn das_2d_commitments_() {
+        das_2d_commitments::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+}
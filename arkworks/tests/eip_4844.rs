@@ -9,11 +9,13 @@ mod tests {
     use kzg::Fr;
     use kzg_bench::tests::eip_4844::{
         blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        commit_to_poly_monomial_and_lagrange_test, compute_all_kzg_proofs_for_blob_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_kzg_proof_round_trip_test, compute_kzg_proof_test, compute_powers_test,
-        verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        transcript_test, verify_kzg_proof_batch_fails_with_incorrect_proof_test,
+        verify_kzg_proof_batch_test, verifying_key_test,
     };
     #[cfg(not(feature = "minimal-spec"))]
     use kzg_bench::tests::eip_4844::{
@@ -24,6 +26,7 @@ mod tests {
     };
     use rust_kzg_arkworks::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_arkworks::eip_4844::load_trusted_setup_filename_rust;
+    use rust_kzg_arkworks::fk20_proofs::KzgFK20SingleSettings;
     use rust_kzg_arkworks::kzg_proofs::{expand_root_of_unity, FFTSettings, KZGSettings};
     use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
     use rust_kzg_arkworks::utils::PolyData;
@@ -38,6 +41,11 @@ mod tests {
         compute_powers_test::<ArkFr>(&compute_powers);
     }
 
+    #[test]
+    pub fn transcript_test_() {
+        transcript_test::<ArkFr, ArkG1>();
+    }
+
     #[test]
     pub fn blob_to_kzg_commitment_test_() {
         blob_to_kzg_commitment_test::<
@@ -161,6 +169,49 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_all_kzg_proofs_for_blob_test_() {
+        compute_all_kzg_proofs_for_blob_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20SingleSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
+    #[test]
+    pub fn commit_to_poly_monomial_and_lagrange_test_() {
+        commit_to_poly_monomial_and_lagrange_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
+    #[test]
+    pub fn verifying_key_test_() {
+        verifying_key_test::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
     #[test]
     pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test_() {
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test::<
@@ -2,7 +2,10 @@ mod batch_adder;
 
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_fr::{compare_sft_fft, inverse_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_fr::{
+        compare_sft_fft, coset_fft, dif_dit_roundtrip, inplace_fft, inverse_fft, mixed_radix_fft,
+        roundtrip_fft, rs_extension_by_factor, stride_fft,
+    };
     use rust_kzg_arkworks::fft::{fft_fr_fast, fft_fr_slow};
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::ArkFr;
@@ -26,4 +29,29 @@ mod tests {
     fn stride_fft_() {
         stride_fft::<ArkFr, FFTSettings>();
     }
+
+    #[test]
+    fn inplace_fft_() {
+        inplace_fft::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn dif_dit_roundtrip_() {
+        dif_dit_roundtrip::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn mixed_radix_fft_() {
+        mixed_radix_fft::<ArkFr, FFTSettings>();
+    }
+
+    #[test]
+    fn rs_extension_by_factor_() {
+        rs_extension_by_factor::<ArkFr, FFTSettings>();
+    }
 }
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_g1::{compare_sft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{
+        compare_sft_fft, coset_fft, inplace_fft, roundtrip_fft, stride_fft,
+    };
     use rust_kzg_arkworks::fft_g1::{fft_g1_fast, fft_g1_slow, make_data};
     use rust_kzg_arkworks::kzg_proofs::FFTSettings;
     use rust_kzg_arkworks::kzg_types::{ArkFr, ArkG1};
@@ -19,4 +21,14 @@ mod tests {
     fn compare_sft_fft_() {
         compare_sft_fft::<ArkFr, ArkG1, FFTSettings>(&fft_g1_slow, &fft_g1_fast, &make_data);
     }
+
+    #[test]
+    fn inplace_fft_() {
+        inplace_fft::<ArkFr, ArkG1, FFTSettings>(&make_data);
+    }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<ArkFr, ArkG1, FFTSettings>(&make_data);
+    }
 }
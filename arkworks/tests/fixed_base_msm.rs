@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::msm::fixed_base_msm::{
+        fixed_base_msm_respects_params, fixed_base_msm_works,
+    };
+    use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine};
+
+    #[test]
+    pub fn fixed_base_msm_works_() {
+        fixed_base_msm_works::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    pub fn fixed_base_msm_respects_params_() {
+        fixed_base_msm_respects_params::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+}
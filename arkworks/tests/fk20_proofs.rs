@@ -38,6 +38,21 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    fn test_fk_single_with_params() {
+        fk_single_with_params::<
+            BlstFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20SingleSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup, &KzgFK20SingleSettings::with_params);
+    }
+
     #[test]
     fn test_fk_multi_settings() {
         fk_multi_settings::<
@@ -97,4 +112,34 @@ mod tests {
             ArkG1Affine,
         >(&generate_trusted_setup);
     }
+
+    #[test]
+    fn test_fk_multi_with_params() {
+        fk_multi_with_params::<
+            BlstFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20MultiSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup, &KzgFK20MultiSettings::with_params);
+    }
+
+    #[test]
+    fn test_fk_multi_commitment_scheme() {
+        fk_multi_commitment_scheme::<
+            BlstFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20MultiSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly_returns_err, commitment_schemes,
+        degree_bound_proofs, proof_aggregation, proof_batch_same_point, proof_multi, proof_shplonk,
+        proof_single,
     };
     use rust_kzg_arkworks::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
@@ -46,4 +48,67 @@ mod tests {
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn proof_batch_same_point_() {
+        proof_batch_same_point::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn proof_shplonk_() {
+        proof_shplonk::<ArkFr, ArkG1, ArkG2, PolyData, FFTSettings, KZGSettings, ArkFp, ArkG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn proof_aggregation_() {
+        proof_aggregation::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn degree_bound_proofs_() {
+        degree_bound_proofs::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    fn commitment_schemes_() {
+        commitment_schemes::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
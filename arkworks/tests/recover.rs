@@ -16,6 +16,11 @@ mod recover_tests {
         recover_random::<Fr, FFTSettings, PolyData, PolyData>();
     }
 
+    #[test]
+    fn recoverer_incremental_() {
+        recoverer_incremental::<Fr, FFTSettings, PolyData>();
+    }
+
     #[test]
     fn more_than_half_missing_() {
         more_than_half_missing::<Fr, FFTSettings, PolyData, PolyData>();
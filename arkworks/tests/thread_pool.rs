@@ -0,0 +1,52 @@
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use kzg::msm::thread_pool::ExecutionContext;
+    use kzg::FFTSettings as _;
+    use kzg_bench::tests::msm::thread_pool::{
+        fft_and_fk20_stay_in_execution_context, g1_lincomb_is_pool_independent,
+    };
+    use rust_kzg_arkworks::fk20_proofs::{KzgFK20MultiSettings, KzgFK20SingleSettings};
+    use rust_kzg_arkworks::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
+    use rust_kzg_arkworks::kzg_types::{ArkFp, ArkFr, ArkG1, ArkG1Affine, ArkG2};
+    use rust_kzg_arkworks::utils::PolyData;
+
+    #[test]
+    pub fn g1_lincomb_is_pool_independent_() {
+        g1_lincomb_is_pool_independent::<ArkFr, ArkG1, ArkFp, ArkG1Affine>();
+    }
+
+    #[test]
+    pub fn fft_and_fk20_stay_in_execution_context_() {
+        let fs = FFTSettings {
+            execution_context: ExecutionContext::with_threads(1).unwrap(),
+            ..FFTSettings::new(12).unwrap()
+        };
+
+        fft_and_fk20_stay_in_execution_context::<
+            ArkFr,
+            ArkG1,
+            ArkG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20SingleSettings,
+            KzgFK20MultiSettings,
+            ArkFp,
+            ArkG1Affine,
+        >(&generate_trusted_setup, &fs);
+    }
+
+    #[test]
+    pub fn execution_context_has_requested_threads() {
+        for threads in [1, 2, 3, 8] {
+            let context = ExecutionContext::with_threads(threads).unwrap();
+            assert_eq!(context.num_threads(), threads);
+            assert_eq!(context.install(rayon::current_num_threads), threads);
+        }
+    }
+
+    #[test]
+    pub fn execution_context_rejects_zero_threads() {
+        assert!(ExecutionContext::with_threads(0).is_err());
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::eip_4844::test_vectors_verify_with_embedded_key;
+    use rust_kzg_arkworks::kzg_types::{ArkFr, ArkG1, ArkG2};
+
+    #[test]
+    pub fn test_vectors_verify_with_embedded_key_() {
+        test_vectors_verify_with_embedded_key::<ArkFr, ArkG1, ArkG2>();
+    }
+}
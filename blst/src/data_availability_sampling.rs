@@ -86,15 +86,8 @@ impl DAS<FsFr> for FsFFTSettings {
             ));
         }
 
-        // In case more roots are provided with fft_settings, use a larger stride
-        let stride = self.max_width / (evens.len() * 2);
         let mut odds = evens.to_vec();
-        self.das_fft_extension_stride(&mut odds, stride);
-
-        // TODO: explain why each odd member is multiplied by euclidean inverse of length
-        let mut inv_len = FsFr::from_u64(odds.len() as u64);
-        inv_len = inv_len.eucl_inverse();
-        let odds = odds.iter().map(|f| f.mul(&inv_len)).collect();
+        kzg::fft::das_fft_extension(self, &mut odds);
 
         Ok(odds)
     }
@@ -101,12 +101,20 @@ impl FsFFTSettings {
             &self.expanded_roots_of_unity
         };
 
-        fft_fr_fast(output, data, 1, roots, stride);
+        let transform = |output: &mut [FsFr]| {
+            fft_fr_fast(output, data, 1, roots, stride);
 
-        if inverse {
-            let inv_fr_len = FsFr::from_u64(data.len() as u64).inverse();
-            output.iter_mut().for_each(|f| *f = f.mul(&inv_fr_len));
-        }
+            if inverse {
+                let inv_fr_len = FsFr::from_u64(data.len() as u64).inverse();
+                output.iter_mut().for_each(|f| *f = f.mul(&inv_fr_len));
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| transform(output));
+
+        #[cfg(not(feature = "parallel"))]
+        transform(output);
 
         Ok(())
     }
@@ -121,6 +129,10 @@ impl FFTFr<FsFr> for FsFFTSettings {
 
         Ok(ret)
     }
+
+    fn fft_fr_inplace(&self, data: &mut [FsFr], inverse: bool) -> Result<(), String> {
+        kzg::fft::fft_fr_inplace(self, data, inverse)
+    }
 }
 
 /// Simplified Discrete Fourier Transform, mainly used for testing
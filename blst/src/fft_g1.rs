@@ -69,17 +69,29 @@ impl FFTG1<FsG1> for FsFFTSettings {
             &self.expanded_roots_of_unity
         };
 
-        fft_g1_fast(&mut ret, data, 1, roots, stride);
+        let transform = |ret: &mut [FsG1]| {
+            fft_g1_fast(ret, data, 1, roots, stride);
 
-        if inverse {
-            let inv_fr_len = FsFr::from_u64(data.len() as u64).inverse();
-            ret[..data.len()]
-                .iter_mut()
-                .for_each(|f| *f = f.mul(&inv_fr_len));
-        }
+            if inverse {
+                let inv_fr_len = FsFr::from_u64(data.len() as u64).inverse();
+                ret[..data.len()]
+                    .iter_mut()
+                    .for_each(|f| *f = f.mul(&inv_fr_len));
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| transform(&mut ret));
+
+        #[cfg(not(feature = "parallel"))]
+        transform(&mut ret);
 
         Ok(ret)
     }
+
+    fn fft_g1_inplace(&self, data: &mut [FsG1], inverse: bool) -> Result<(), String> {
+        kzg::fft::fft_g1_inplace(self, data, inverse)
+    }
 }
 
 // Used for testing
@@ -1,13 +1,17 @@
 extern crate alloc;
 
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 
+use kzg::fft::{fft_fr_dif, fft_g1_dif, fft_g1_dit};
+use kzg::msm::fixed_base::FixedBaseMsm;
 use kzg::{FFTFr, Fr, G1Mul, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::FsFFTSettings;
+use crate::types::fp::FsFp;
 use crate::types::fr::FsFr;
-use crate::types::g1::FsG1;
+use crate::types::g1::{FsG1, FsG1Affine};
 use crate::types::poly::FsPoly;
 
 #[cfg(feature = "parallel")]
@@ -25,6 +29,26 @@ impl FsFFTSettings {
         self.fft_g1(&x_ext, false).unwrap()
     }
 
+    /// `toeplitz_part_1`, but with the transform left in bit-reversed order. The FK20 settings
+    /// keep everything up to `toeplitz_part_3_brp` in that order, which the pointwise products
+    /// in `toeplitz_part_2_multi_brp` do not mind, so that no permutation is needed at all.
+    pub(crate) fn toeplitz_part_1_brp(&self, x: &[FsG1]) -> Vec<FsG1> {
+        let n = x.len();
+        let n2 = n * 2;
+        let mut x_ext = Vec::with_capacity(n2);
+
+        x_ext.extend(x.iter().take(n));
+        x_ext.resize(n2, FsG1::identity());
+
+        fft_g1_dif(
+            &mut x_ext,
+            &self.expanded_roots_of_unity,
+            self.max_width / n2,
+        );
+
+        x_ext
+    }
+
     /// poly and x_ext_fft should be of same length
     pub fn toeplitz_part_2(&self, poly: &FsPoly, x_ext_fft: &[FsG1]) -> Vec<FsG1> {
         let coeffs_fft = self.fft_fr(&poly.coeffs, false).unwrap();
@@ -50,6 +74,80 @@ impl FsFFTSettings {
         }
     }
 
+    /// Fixed-base tables for `toeplitz_part_2_multi_brp`. Table `j` holds the `j`-th point of
+    /// every `toeplitz_part_1_brp` output in `x_ext_fft_files`. `window` is passed on to
+    /// [`FixedBaseMsm::with_params`], and `max_table_size` caps the precomputed points of all
+    /// tables together.
+    pub(crate) fn toeplitz_part_2_tables(
+        x_ext_fft_files: &[Vec<FsG1>],
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Vec<FixedBaseMsm<FsFr, FsG1, FsFp, FsG1Affine>>, String> {
+        let n2 = x_ext_fft_files[0].len();
+        let max_table_size = max_table_size.map(|max| max / n2);
+
+        let column = |j: usize| {
+            let points = x_ext_fft_files
+                .iter()
+                .map(|x_ext_fft| x_ext_fft[j])
+                .collect::<Vec<_>>();
+            FixedBaseMsm::with_params(&points, window, max_table_size)
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            (0..n2).into_par_iter().map(column).collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            (0..n2).map(column).collect()
+        }
+    }
+
+    /// Sum of the pointwise Toeplitz products over all `toeplitz_coeffs`, where
+    /// `x_ext_fft_tables` comes from `toeplitz_part_2_tables` over the matching files
+    pub(crate) fn toeplitz_part_2_multi_brp(
+        &self,
+        toeplitz_coeffs: &[FsPoly],
+        x_ext_fft_tables: &[FixedBaseMsm<FsFr, FsG1, FsFp, FsG1Affine>],
+    ) -> Vec<FsG1> {
+        let coeffs_fft = toeplitz_coeffs
+            .iter()
+            .map(|poly| self.toeplitz_coeffs_fft_brp(poly))
+            .collect::<Vec<_>>();
+
+        let column = |(j, table): (usize, &FixedBaseMsm<FsFr, FsG1, FsFp, FsG1Affine>)| {
+            let scalars = coeffs_fft
+                .iter()
+                .map(|coeff_fft| coeff_fft[j])
+                .collect::<Vec<_>>();
+            table.multiply(&scalars).unwrap()
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            x_ext_fft_tables
+                .par_iter()
+                .enumerate()
+                .map(column)
+                .collect()
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        {
+            x_ext_fft_tables.iter().enumerate().map(column).collect()
+        }
+    }
+
+    fn toeplitz_coeffs_fft_brp(&self, poly: &FsPoly) -> Vec<FsFr> {
+        let mut coeffs = poly.coeffs.clone();
+        let stride = self.max_width / coeffs.len();
+        fft_fr_dif(&mut coeffs, &self.expanded_roots_of_unity, stride);
+
+        coeffs
+    }
+
     pub fn toeplitz_part_3(&self, h_ext_fft: &[FsG1]) -> Vec<FsG1> {
         let n2 = h_ext_fft.len();
         let n = n2 / 2;
@@ -59,6 +157,23 @@ impl FsFFTSettings {
 
         ret
     }
+
+    /// `toeplitz_part_3` for the bit-reversed `toeplitz_part_2_multi_brp` output. The result is in
+    /// natural order.
+    pub(crate) fn toeplitz_part_3_brp(&self, h_ext_fft: &[FsG1]) -> Vec<FsG1> {
+        let n2 = h_ext_fft.len();
+        let n = n2 / 2;
+
+        let mut ret = h_ext_fft.to_vec();
+        fft_g1_dit(&mut ret, &self.reverse_roots_of_unity, self.max_width / n2);
+
+        // The upper half is dropped, so only the lower half needs the division by n2
+        let inv_len = FsFr::from_u64(n2 as u64).inverse();
+        ret[..n].iter_mut().for_each(|h| *h = h.mul(&inv_len));
+        ret[n..n2].copy_from_slice(&vec![FsG1::identity(); n2 - n]);
+
+        ret
+    }
 }
 
 impl FsPoly {
@@ -1,11 +1,15 @@
 extern crate alloc;
 
+#[cfg(feature = "prover")]
 use crate::types::fp::FsFp;
 use crate::types::g1::FsG1;
+#[cfg(feature = "prover")]
 use crate::types::{fr::FsFr, g1::FsG1Affine};
 
+#[cfg(feature = "prover")]
 use crate::types::g1::FsG1ProjAddAffine;
 
+#[cfg(feature = "prover")]
 use kzg::msm::{msm_impls::msm, precompute::PrecomputationTable};
 
 use crate::types::g2::FsG2;
@@ -22,6 +26,7 @@ impl PairingVerify<FsG1, FsG2> for FsG1 {
     }
 }
 
+#[cfg(feature = "prover")]
 pub fn g1_linear_combination(
     out: &mut FsG1,
     points: &[FsG1],
@@ -1,13 +1,19 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod consts;
+#[cfg(feature = "prover")]
 pub mod data_availability_sampling;
+#[cfg(feature = "prover")]
 pub mod eip_4844;
 pub mod fft_fr;
+#[cfg(feature = "prover")]
 pub mod fft_g1;
+#[cfg(feature = "prover")]
 pub mod fk20_proofs;
 pub mod kzg_proofs;
+#[cfg(feature = "prover")]
 pub mod recovery;
 pub mod types;
 pub mod utils;
+#[cfg(feature = "prover")]
 pub mod zero_poly;
@@ -1,10 +1,12 @@
 extern crate alloc;
 
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
+use kzg::fft::{CosetCache, ShiftPowers};
 use kzg::{FFTSettings, Fr};
 
 use crate::consts::SCALE2_ROOT_OF_UNITY;
@@ -17,6 +19,10 @@ pub struct FsFFTSettings {
     pub expanded_roots_of_unity: Vec<FsFr>,
     pub reverse_roots_of_unity: Vec<FsFr>,
     pub roots_of_unity: Vec<FsFr>,
+    pub coset_cache: CosetCache<FsFr>,
+    /// Pool FFTs and recovery run on, see [`FFTSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 impl Default for FsFFTSettings {
@@ -54,6 +60,9 @@ impl FFTSettings<FsFr> for FsFFTSettings {
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         })
     }
 
@@ -84,6 +93,15 @@ impl FFTSettings<FsFr> for FsFFTSettings {
     fn get_roots_of_unity(&self) -> &[FsFr] {
         &self.roots_of_unity
     }
+
+    fn get_coset_shift_powers(&self, shift: &FsFr, len: usize) -> Arc<ShiftPowers<FsFr>> {
+        self.coset_cache.get(shift, len)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
 
 /// Multiply a given root of unity by itself until it results in a 1 and result all multiplication values in a vector
@@ -4,7 +4,8 @@ use alloc::string::String;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
-use kzg::{FK20SingleSettings, Poly, FFTG1, G1};
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::{FK20SingleSettings, KZGSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::FsFFTSettings;
 use crate::types::fr::FsFr;
@@ -19,13 +20,20 @@ use super::g1::FsG1Affine;
 #[derive(Debug, Clone, Default)]
 pub struct FsFK20SingleSettings {
     pub kzg_settings: FsKZGSettings,
-    pub x_ext_fft: Vec<FsG1>,
+    pub x_ext_fft_tables: Vec<FixedBaseMsm<FsFr, FsG1, FsFp, FsG1Affine>>,
 }
 
-impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings, FsFp, FsG1Affine>
-    for FsFK20SingleSettings
-{
-    fn new(kzg_settings: &FsKZGSettings, n2: usize) -> Result<Self, String> {
+impl FsFK20SingleSettings {
+    /// Same as [`FK20SingleSettings::new`], with `window` and `max_table_size` passed on to the
+    /// fixed-base tables of the Toeplitz products. There are `n2` of them, with a single point
+    /// each, and `max_table_size` caps the precomputed points of all of them together. See
+    /// [`FixedBaseMsm::with_params`].
+    pub fn with_params(
+        kzg_settings: &FsKZGSettings,
+        n2: usize,
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Self, String> {
         let n = n2 / 2;
 
         if n2 > kzg_settings.fs.max_width {
@@ -40,21 +48,31 @@ impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings,
 
         let mut x = Vec::with_capacity(n);
         for i in 0..n - 1 {
-            x.push(kzg_settings.secret_g1[n - 2 - i]);
+            x.push(kzg_settings.get_g1_monomial()[n - 2 - i]);
         }
         x.push(FsG1::identity());
 
-        let x_ext_fft = kzg_settings.fs.toeplitz_part_1(&x);
+        let x_ext_fft = kzg_settings.fs.toeplitz_part_1_brp(&x);
         drop(x);
+        let x_ext_fft_tables =
+            FsFFTSettings::toeplitz_part_2_tables(&[x_ext_fft], window, max_table_size)?;
         let kzg_settings = kzg_settings.clone();
 
         let ret = Self {
             kzg_settings,
-            x_ext_fft,
+            x_ext_fft_tables,
         };
 
         Ok(ret)
     }
+}
+
+impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings, FsFp, FsG1Affine>
+    for FsFK20SingleSettings
+{
+    fn new(kzg_settings: &FsKZGSettings, n2: usize) -> Result<Self, String> {
+        Self::with_params(kzg_settings, n2, None, None)
+    }
 
     fn data_availability(&self, p: &FsPoly) -> Result<Vec<FsG1>, String> {
         let n = p.len();
@@ -86,17 +104,25 @@ impl FK20SingleSettings<FsFr, FsG1, FsG2, FsFFTSettings, FsPoly, FsKZGSettings,
             return Err(String::from("n2 must be a power of two"));
         }
 
-        let toeplitz_coeffs = p.toeplitz_coeffs_step();
+        let proofs = || {
+            let toeplitz_coeffs = p.toeplitz_coeffs_step();
 
-        let h_ext_fft = self
-            .kzg_settings
-            .fs
-            .toeplitz_part_2(&toeplitz_coeffs, &self.x_ext_fft);
+            let h_ext_fft = self
+                .kzg_settings
+                .fs
+                .toeplitz_part_2_multi_brp(&[toeplitz_coeffs], &self.x_ext_fft_tables);
 
-        let h = self.kzg_settings.fs.toeplitz_part_3(&h_ext_fft);
+            let mut h = self.kzg_settings.fs.toeplitz_part_3_brp(&h_ext_fft);
 
-        let ret = self.kzg_settings.fs.fft_g1(&h, false).unwrap();
+            self.kzg_settings.fs.fft_g1_inplace(&mut h, false)?;
 
-        Ok(ret)
+            Ok(h)
+        };
+
+        #[cfg(feature = "parallel")]
+        return self.kzg_settings.execution_context.install(proofs);
+
+        #[cfg(not(feature = "parallel"))]
+        proofs()
     }
 }
@@ -15,14 +15,22 @@ use blst::{
 };
 use kzg::common_utils::log_2_byte;
 use kzg::eip_4844::BYTES_PER_G1;
+use kzg::msm::constant_time::ct_select_limbs;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{g1_fixed_base_mul, g1_fixed_base_table, FixedBaseTable};
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::PrecomputationTable;
 use kzg::G1Affine;
 use kzg::G1GetFp;
+#[cfg(feature = "prover")]
 use kzg::G1LinComb;
 use kzg::G1ProjAddAffine;
-use kzg::{G1Mul, G1};
+use kzg::{G1ConstantTime, G1Mul, G1};
+#[cfg(feature = "prover")]
+use once_cell::sync::OnceCell;
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
+#[cfg(feature = "prover")]
 use crate::kzg_proofs::g1_linear_combination;
 use crate::types::fr::FsFr;
 
@@ -232,6 +240,22 @@ impl G1GetFp<FsFp> for FsG1 {
     }
 }
 
+impl G1ConstantTime for FsG1 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self::from_xyz(
+            blst_fp {
+                l: ct_select_limbs(&a.0.x.l, &b.0.x.l, choice),
+            },
+            blst_fp {
+                l: ct_select_limbs(&a.0.y.l, &b.0.y.l, choice),
+            },
+            blst_fp {
+                l: ct_select_limbs(&a.0.z.l, &b.0.z.l, choice),
+            },
+        )
+    }
+}
+
 impl G1Mul<FsFr> for FsG1 {
     fn mul(&self, b: &FsFr) -> Self {
         let mut scalar: blst_scalar = blst_scalar::default();
@@ -263,8 +287,15 @@ impl G1Mul<FsFr> for FsG1 {
         }
         result
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &FsFr) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<FsG1>> = OnceCell::new();
+        g1_fixed_base_mul(TABLE.get_or_init(|| g1_fixed_base_table(&G1_GENERATOR)), b)
+    }
 }
 
+#[cfg(feature = "prover")]
 impl G1LinComb<FsFr, FsFp, FsG1Affine> for FsG1 {
     fn g1_lincomb(
         points: &[Self],
@@ -10,9 +10,13 @@ use blst::{
     blst_scalar, blst_scalar_from_fr, BLST_ERROR,
 };
 use kzg::eip_4844::BYTES_PER_G2;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{g2_fixed_base_mul, g2_fixed_base_table, FixedBaseTable};
 #[cfg(feature = "rand")]
 use kzg::Fr;
 use kzg::{G2Mul, G2};
+#[cfg(feature = "prover")]
+use once_cell::sync::OnceCell;
 
 use crate::consts::{G2_GENERATOR, G2_NEGATIVE_GENERATOR};
 use crate::types::fr::FsFr;
@@ -35,6 +39,12 @@ impl G2Mul<FsFr> for FsG2 {
         }
         Self(result)
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &FsFr) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<FsG2>> = OnceCell::new();
+        g2_fixed_base_mul(TABLE.get_or_init(|| g2_fixed_base_table(&G2_GENERATOR)), b)
+    }
 }
 
 impl G2 for FsG2 {
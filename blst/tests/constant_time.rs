@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::constant_time::{
+        g1_lincomb_ct_is_constant_time, g1_mul_ct_is_constant_time,
+    };
+    use rust_kzg_blst::types::fp::FsFp;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    fn g1_mul_ct_is_constant_time_() {
+        g1_mul_ct_is_constant_time::<FsFr, FsG1>()
+    }
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    fn g1_lincomb_ct_is_constant_time_() {
+        g1_lincomb_ct_is_constant_time::<FsFr, FsG1, FsFp, FsG1Affine>()
+    }
+}
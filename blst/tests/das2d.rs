@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::das2d::{das_2d_commitments, das_2d_extend_and_recover};
+    use rust_kzg_blst::types::fft_settings::FsFFTSettings;
+    use rust_kzg_blst::types::fp::FsFp;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+    use rust_kzg_blst::types::g2::FsG2;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+    use rust_kzg_blst::utils::generate_trusted_setup;
+
+    #[test]
+    fn das_2d_extend_and_recover_() {
+        das_2d_extend_and_recover::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
+    }
+
+    #[test]
+    fn das_2d_commitments_() {
+        das_2d_commitments::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+}
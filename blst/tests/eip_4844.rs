@@ -10,6 +10,7 @@ mod tests {
 
     use kzg_bench::tests::eip_4844::{
         blob_to_kzg_commitment_test, bytes_to_bls_field_test,
+        commit_to_poly_monomial_and_lagrange_test, compute_all_kzg_proofs_for_blob_test,
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test,
         compute_and_verify_blob_kzg_proof_test,
         compute_and_verify_kzg_proof_fails_with_incorrect_proof_test,
@@ -21,12 +22,14 @@ mod tests {
         compute_kzg_proof_test, compute_powers_test, test_vectors_blob_to_kzg_commitment,
         test_vectors_compute_blob_kzg_proof, test_vectors_compute_kzg_proof,
         test_vectors_verify_blob_kzg_proof, test_vectors_verify_blob_kzg_proof_batch,
-        test_vectors_verify_kzg_proof, validate_batched_input_test,
+        test_vectors_verify_kzg_proof, transcript_test, validate_batched_input_test,
         verify_kzg_proof_batch_fails_with_incorrect_proof_test, verify_kzg_proof_batch_test,
+        verifying_key_test,
     };
     use rust_kzg_blst::consts::SCALE2_ROOT_OF_UNITY;
     use rust_kzg_blst::eip_4844::load_trusted_setup_filename_rust;
     use rust_kzg_blst::types::fft_settings::expand_root_of_unity;
+    use rust_kzg_blst::types::fk20_single_settings::FsFK20SingleSettings;
     use rust_kzg_blst::types::fp::FsFp;
     use rust_kzg_blst::types::g1::FsG1Affine;
     use rust_kzg_blst::types::{
@@ -44,6 +47,11 @@ mod tests {
         compute_powers_test::<FsFr>(&compute_powers);
     }
 
+    #[test]
+    pub fn transcript_test_() {
+        transcript_test::<FsFr, FsG1>();
+    }
+
     #[test]
     pub fn blob_to_kzg_commitment_test_() {
         blob_to_kzg_commitment_test::<
@@ -166,6 +174,49 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn compute_all_kzg_proofs_for_blob_test_() {
+        compute_all_kzg_proofs_for_blob_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20SingleSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
+    #[test]
+    pub fn commit_to_poly_monomial_and_lagrange_test_() {
+        commit_to_poly_monomial_and_lagrange_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
+    #[test]
+    pub fn verifying_key_test_() {
+        verifying_key_test::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&load_trusted_setup_filename_rust, &bytes_to_blob);
+    }
+
     #[test]
     pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test_() {
         compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test::<
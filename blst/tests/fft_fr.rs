@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_fr::{compare_sft_fft, inverse_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_fr::{
+        compare_sft_fft, coset_fft, dif_dit_roundtrip, inplace_fft, inverse_fft, mixed_radix_fft,
+        roundtrip_fft, rs_extension_by_factor, stride_fft,
+    };
     use rust_kzg_blst::fft_fr::{fft_fr_fast, fft_fr_slow};
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
     use rust_kzg_blst::types::fr::FsFr;
@@ -24,4 +27,29 @@ mod tests {
     fn stride_fft_() {
         stride_fft::<FsFr, FsFFTSettings>();
     }
+
+    #[test]
+    fn inplace_fft_() {
+        inplace_fft::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn dif_dit_roundtrip_() {
+        dif_dit_roundtrip::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn mixed_radix_fft_() {
+        mixed_radix_fft::<FsFr, FsFFTSettings>();
+    }
+
+    #[test]
+    fn rs_extension_by_factor_() {
+        rs_extension_by_factor::<FsFr, FsFFTSettings>();
+    }
 }
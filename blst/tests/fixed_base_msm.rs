@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::msm::fixed_base_msm::{
+        fixed_base_msm_respects_params, fixed_base_msm_works,
+    };
+    use rust_kzg_blst::types::fp::FsFp;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+
+    #[test]
+    fn fixed_base_msm_works_() {
+        fixed_base_msm_works::<FsFr, FsG1, FsFp, FsG1Affine>()
+    }
+
+    #[test]
+    fn fixed_base_msm_respects_params_() {
+        fixed_base_msm_respects_params::<FsFr, FsG1, FsFp, FsG1Affine>()
+    }
+}
@@ -42,6 +42,21 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    fn test_fk_single_with_params() {
+        fk_single_with_params::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20SingleSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup, &FsFK20SingleSettings::with_params);
+    }
+
     #[test]
     fn test_fk_multi_settings() {
         fk_multi_settings::<
@@ -101,4 +116,34 @@ mod tests {
             FsG1Affine,
         >(&generate_trusted_setup);
     }
+
+    #[test]
+    fn test_fk_multi_with_params() {
+        fk_multi_with_params::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20MultiSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup, &FsFK20MultiSettings::with_params);
+    }
+
+    #[test]
+    fn test_fk_multi_commitment_scheme() {
+        fk_multi_commitment_scheme::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20MultiSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -6,7 +6,9 @@ mod tests {
     };
     use kzg::G1;
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly_returns_err, commitment_schemes,
+        degree_bound_proofs, hiding_commitments, proof_aggregation, proof_batch_same_point,
+        proof_multi, proof_shplonk, proof_single,
     };
 
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -60,6 +62,76 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_proof_batch_same_point() {
+        proof_batch_same_point::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_proof_shplonk() {
+        proof_shplonk::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings, FsFp, FsG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn test_proof_aggregation() {
+        proof_aggregation::<FsFr, FsG1, FsG2, FsPoly, FsFFTSettings, FsKZGSettings, FsFp, FsG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn test_hiding_commitments() {
+        hiding_commitments::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_degree_bound_proofs() {
+        degree_bound_proofs::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_commitment_schemes() {
+        commitment_schemes::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup);
+    }
+
     // This aims at showing that the use of the blst::Pairing engine in pairings_verify
     // has the desired semantics.
     #[cfg(feature = "rand")]
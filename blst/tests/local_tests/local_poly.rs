@@ -286,13 +286,17 @@ pub fn poly_mul_fft_test() {
         let multiplier: FsPoly = new_test_poly(&coeffs2);
         let expected: FsPoly = new_test_poly(&coeffs3);
 
-        let result0 = multiplicand.mul_fft(&multiplier, coeffs3.len()).unwrap();
+        let result0 = multiplicand
+            .mul_fft(&multiplier, coeffs3.len(), None)
+            .unwrap();
         for j in 0..result0.len() {
             assert!(expected.get_coeff_at(j).equals(&result0.get_coeff_at(j)))
         }
 
         // Check commutativity
-        let result1 = multiplier.mul_fft(&multiplicand, coeffs3.len()).unwrap();
+        let result1 = multiplier
+            .mul_fft(&multiplicand, coeffs3.len(), None)
+            .unwrap();
         for j in 0..result1.len() {
             assert!(expected.get_coeff_at(j).equals(&result1.get_coeff_at(j)))
         }
@@ -325,7 +329,7 @@ pub fn poly_mul_random() {
 
         let out_length: usize = (1 + (rng.next_u64() % 1000)) as usize;
         let q0 = multiplicand.mul_direct(&multiplier, out_length).unwrap();
-        let q1 = multiplicand.mul_fft(&multiplier, out_length).unwrap();
+        let q1 = multiplicand.mul_fft(&multiplier, out_length, None).unwrap();
 
         assert_eq!(q0.len(), q1.len());
         for i in 0..q0.len() {
@@ -4,9 +4,13 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::poly::{
-        create_poly_of_length_ten, poly_div_by_zero, poly_div_fast_test, poly_div_long_test,
-        poly_div_random, poly_eval_0_check, poly_eval_check, poly_eval_nil_check,
-        poly_inverse_simple_0, poly_inverse_simple_1, poly_mul_direct_test, poly_mul_fft_test,
+        create_poly_of_length_ten, multipoint_evaluate_random, multipoint_interpolate_random,
+        poly_add_sub_mul_scalar_random, poly_compose_random, poly_derivative_test,
+        poly_div_by_zero, poly_div_fast_test, poly_div_long_test, poly_div_random,
+        poly_divide_by_linear_random, poly_eval_0_check, poly_eval_check,
+        poly_eval_evaluate_random, poly_eval_nil_check, poly_eval_quotient_by_linear,
+        poly_eval_roundtrip_and_ops, poly_interpolate_random, poly_inverse_simple_0,
+        poly_inverse_simple_1, poly_mul_direct_test, poly_mul_fft_test, poly_mul_fft_with_settings,
         poly_mul_random, poly_test_div,
     };
     use rust_kzg_blst::types::fft_settings::FsFFTSettings;
@@ -138,6 +142,11 @@ mod tests {
         poly_mul_random::<FsFr, FsPoly, FsFFTSettings>()
     }
 
+    #[test]
+    fn poly_mul_fft_with_settings_() {
+        poly_mul_fft_with_settings::<FsFr, FsPoly, FsFFTSettings>()
+    }
+
     #[test]
     fn poly_div_random_() {
         poly_div_random::<FsFr, FsPoly>()
@@ -152,4 +161,54 @@ mod tests {
     fn poly_div_fast_test_() {
         poly_div_fast_test::<FsFr, FsPoly>()
     }
+
+    #[test]
+    fn poly_add_sub_mul_scalar_random_() {
+        poly_add_sub_mul_scalar_random::<FsFr, FsPoly>();
+    }
+
+    #[test]
+    fn poly_derivative_test_() {
+        poly_derivative_test::<FsFr, FsPoly>();
+    }
+
+    #[test]
+    fn poly_compose_random_() {
+        poly_compose_random::<FsFr, FsPoly>();
+    }
+
+    #[test]
+    fn poly_interpolate_random_() {
+        poly_interpolate_random::<FsFr, FsPoly>();
+    }
+
+    #[test]
+    fn poly_divide_by_linear_random_() {
+        poly_divide_by_linear_random::<FsFr, FsPoly>();
+    }
+
+    #[test]
+    fn multipoint_evaluate_random_() {
+        multipoint_evaluate_random::<FsFr, FsPoly, FsFFTSettings>();
+    }
+
+    #[test]
+    fn multipoint_interpolate_random_() {
+        multipoint_interpolate_random::<FsFr, FsPoly, FsFFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_roundtrip_and_ops_() {
+        poly_eval_roundtrip_and_ops::<FsFr, FsPoly, FsFFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_evaluate_random_() {
+        poly_eval_evaluate_random::<FsFr, FsPoly, FsFFTSettings>();
+    }
+
+    #[test]
+    fn poly_eval_quotient_by_linear_() {
+        poly_eval_quotient_by_linear::<FsFr, FsPoly, FsFFTSettings>();
+    }
 }
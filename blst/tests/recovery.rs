@@ -22,6 +22,11 @@ mod tests {
         recover_random::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
     }
 
+    #[test]
+    fn recoverer_incremental_() {
+        recoverer_incremental::<FsFr, FsFFTSettings, FsPoly>();
+    }
+
     #[test]
     fn more_than_half_missing_() {
         more_than_half_missing::<FsFr, FsFFTSettings, FsPoly, FsPoly>();
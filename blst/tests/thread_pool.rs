@@ -0,0 +1,58 @@
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use kzg::msm::thread_pool::ExecutionContext;
+    use kzg::FFTSettings;
+    use kzg_bench::tests::msm::thread_pool::{
+        fft_and_fk20_stay_in_execution_context, g1_lincomb_is_pool_independent,
+    };
+    use rust_kzg_blst::types::fft_settings::FsFFTSettings;
+    use rust_kzg_blst::types::fk20_multi_settings::FsFK20MultiSettings;
+    use rust_kzg_blst::types::fk20_single_settings::FsFK20SingleSettings;
+    use rust_kzg_blst::types::fp::FsFp;
+    use rust_kzg_blst::types::fr::FsFr;
+    use rust_kzg_blst::types::g1::{FsG1, FsG1Affine};
+    use rust_kzg_blst::types::g2::FsG2;
+    use rust_kzg_blst::types::kzg_settings::FsKZGSettings;
+    use rust_kzg_blst::types::poly::FsPoly;
+    use rust_kzg_blst::utils::generate_trusted_setup;
+
+    #[test]
+    fn g1_lincomb_is_pool_independent_() {
+        g1_lincomb_is_pool_independent::<FsFr, FsG1, FsFp, FsG1Affine>()
+    }
+
+    #[test]
+    fn fft_and_fk20_stay_in_execution_context_() {
+        let fs = FsFFTSettings {
+            execution_context: ExecutionContext::with_threads(1).unwrap(),
+            ..FsFFTSettings::new(12).unwrap()
+        };
+
+        fft_and_fk20_stay_in_execution_context::<
+            FsFr,
+            FsG1,
+            FsG2,
+            FsPoly,
+            FsFFTSettings,
+            FsKZGSettings,
+            FsFK20SingleSettings,
+            FsFK20MultiSettings,
+            FsFp,
+            FsG1Affine,
+        >(&generate_trusted_setup, &fs)
+    }
+
+    #[test]
+    fn execution_context_has_requested_threads() {
+        for threads in [1, 2, 3, 8] {
+            let context = ExecutionContext::with_threads(threads).unwrap();
+            assert_eq!(context.num_threads(), threads);
+            assert_eq!(context.install(rayon::current_num_threads), threads);
+        }
+    }
+
+    #[test]
+    fn execution_context_rejects_zero_threads() {
+        assert!(ExecutionContext::with_threads(0).is_err());
+    }
+}
@@ -19,7 +19,10 @@ use std::fs::File;
 use std::io::Read;
 
 #[cfg(feature = "std")]
-use kzg::eip_4844::load_trusted_setup_string;
+use kzg::eip_4844::{
+    load_trusted_setup_string, load_trusted_setup_string_with_monomial,
+    load_trusted_setup_with_monomial_rust,
+};
 
 use kzg::eip_4844::{
     Blob, Bytes32, Bytes48, CKZGSettings, KZGCommitment, KZGProof, BYTES_PER_FIELD_ELEMENT,
@@ -44,8 +47,13 @@ pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<CtKZGSettings,
     file.read_to_string(&mut contents)
         .map_err(|_| "Unable to read file".to_string())?;
 
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+    let (g1_bytes, g2_bytes, g1_monomial_bytes) =
+        load_trusted_setup_string_with_monomial(&contents)?;
+    load_trusted_setup_with_monomial_rust(
+        g1_bytes.as_slice(),
+        g2_bytes.as_slice(),
+        g1_monomial_bytes.as_slice(),
+    )
 }
 
 fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<CtFFTSettings, String> {
@@ -73,6 +81,9 @@ fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<CtFFTSettings
         expanded_roots_of_unity,
         reverse_roots_of_unity,
         roots_of_unity,
+        coset_cache: Default::default(),
+        #[cfg(feature = "parallel")]
+        execution_context: Default::default(),
     })
 }
 
@@ -86,6 +97,9 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<CtKZGSettings, Stri
     Ok(CtKZGSettings {
         fs: fft_settings_to_rust(c_settings)?,
         secret_g1,
+        // `CKZGSettings` carries only the Lagrange-form points, the monomial ones are derived
+        // from them if needed
+        secret_g1_monomial: Some(Default::default()),
         secret_g2: unsafe {
             core::slice::from_raw_parts(c_settings.g2_values, TRUSTED_SETUP_NUM_G2_POINTS)
                 .iter()
@@ -93,6 +107,8 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<CtKZGSettings, Stri
                 .collect::<Vec<CtG2>>()
         },
         precomputation: None,
+        #[cfg(feature = "parallel")]
+        execution_context: Default::default(),
     })
 }
 
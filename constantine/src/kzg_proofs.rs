@@ -1,22 +1,25 @@
 extern crate alloc;
 
+#[cfg(feature = "prover")]
 use crate::types::fp::CtFp;
 use crate::types::g1::CtG1;
+#[cfg(feature = "prover")]
 use crate::types::{fr::CtFr, g1::CtG1Affine};
 
 use crate::utils::{ptr_transmute, ptr_transmute_mut};
 
-#[cfg(feature = "constantine_msm")]
+#[cfg(all(feature = "constantine_msm", feature = "prover"))]
 use constantine_sys as constantine;
-#[cfg(feature = "constantine_msm")]
+#[cfg(all(feature = "constantine_msm", feature = "prover"))]
 use kzg::G1Affine;
 
-#[cfg(not(feature = "constantine_msm"))]
+#[cfg(all(not(feature = "constantine_msm"), feature = "prover"))]
 use kzg::msm::msm_impls::msm;
 
-#[cfg(not(feature = "constantine_msm"))]
+#[cfg(all(not(feature = "constantine_msm"), feature = "prover"))]
 use crate::types::g1::CtG1ProjAddAffine;
 
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::PrecomputationTable;
 
 use crate::types::g2::CtG2;
@@ -30,6 +33,7 @@ impl PairingVerify<CtG1, CtG2> for CtG1 {
     }
 }
 
+#[cfg(feature = "prover")]
 pub fn g1_linear_combination(
     out: &mut CtG1,
     points: &[CtG1],
@@ -123,6 +123,17 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         MixedKzgSettings::new(secret_g1, secret_g2, length, fs)
     }
 
+    fn new_with_monomial(
+        secret_g1: &[CtG1],
+        secret_g1_monomial: Option<&[CtG1]>,
+        secret_g2: &[CtG2],
+        length: usize,
+        fs: &CtFFTSettings,
+    ) -> Result<Self, String> {
+        GenericContext::new_with_monomial(secret_g1, secret_g1_monomial, secret_g2, length, fs)
+            .map(Self::Generic)
+    }
+
     fn commit_to_poly(&self, p: &CtPoly) -> Result<CtG1, String> {
         match self {
             MixedKzgSettings::Constantine(_) => Err("Context not in generic format".to_string()),
@@ -212,6 +223,24 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         }
     }
 
+    fn get_g1_monomial(&self) -> &[CtG1] {
+        match self {
+            // Constantine's context doesn't expose its points, so callers find none and error
+            // out instead of panicking
+            MixedKzgSettings::Constantine(_) => &[],
+            MixedKzgSettings::Generic(generic_context) => generic_context.get_g1_monomial(),
+        }
+    }
+
+    fn get_g1_lagrange_brp(&self) -> &[CtG1] {
+        match self {
+            // Constantine's context doesn't expose its points, so callers find none and error
+            // out instead of panicking
+            MixedKzgSettings::Constantine(_) => &[],
+            MixedKzgSettings::Generic(generic_context) => generic_context.get_g1_lagrange_brp(),
+        }
+    }
+
     fn get_g2_secret(&self) -> &[CtG2] {
         match self {
             MixedKzgSettings::Constantine(_) => {
@@ -231,4 +260,14 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
             MixedKzgSettings::Generic(generic_context) => generic_context.get_precomputation(),
         }
     }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        match self {
+            MixedKzgSettings::Constantine(_) => {
+                kzg::msm::thread_pool::ExecutionContext::global_ref()
+            }
+            MixedKzgSettings::Generic(generic_context) => generic_context.get_execution_context(),
+        }
+    }
 }
@@ -3,66 +3,48 @@ extern crate alloc;
 use alloc::string::String;
 use alloc::vec::Vec;
 
+use kzg::fft::ShiftPowers;
 use kzg::{FFTFr, Fr, PolyRecover, ZeroPoly};
 
 use crate::types::fft_settings::CtFFTSettings;
 use crate::types::fr::CtFr;
 use crate::types::poly::CtPoly;
-use once_cell::sync::OnceCell;
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
 const SCALE_FACTOR: u64 = 5;
-static INVERSE_FACTORS: OnceCell<Vec<CtFr>> = OnceCell::new();
-static UNSCALE_FACTOR_POWERS: OnceCell<Vec<CtFr>> = OnceCell::new();
 
-pub fn scale_poly(p: &mut [CtFr], len_p: usize) {
-    let factors = INVERSE_FACTORS.get_or_init(|| {
-        let scale_factor = CtFr::from_u64(SCALE_FACTOR);
-        let inv_factor = CtFr::inverse(&scale_factor);
-        let mut temp = Vec::with_capacity(65536);
-        temp.push(CtFr::one());
-        for i in 1..65536 {
-            temp.push(temp[i - 1].mul(&inv_factor));
-        }
-        temp
-    });
+/// `1 / SCALE_FACTOR`, the shift of the coset the division in recovery happens over. None of the
+/// roots of unity lie on that coset, so `Z_r,I` has no zeroes there.
+fn recovery_shift() -> CtFr {
+    CtFr::from_u64(SCALE_FACTOR).inverse()
+}
 
+/// x -> k * x on the first `len_p` coefficients, for `k` the recovery shift
+pub fn scale_poly(p: &mut [CtFr], len_p: usize) {
+    let shift_powers = ShiftPowers::new(&recovery_shift(), len_p);
     p.iter_mut()
-        .zip(factors)
-        .take(len_p)
+        .zip(&shift_powers.powers)
         .skip(1)
-        .for_each(|(p, factor)| {
-            *p = p.mul(factor);
+        .for_each(|(p, power)| {
+            *p = p.mul(power);
         });
 }
 
+/// k * x -> x on the first `len_p` coefficients, undoing [`scale_poly`]
 pub fn unscale_poly(p: &mut [CtFr], len_p: usize) {
-    let factors = UNSCALE_FACTOR_POWERS.get_or_init(|| {
-        let scale_factor = CtFr::from_u64(SCALE_FACTOR);
-        let mut temp = Vec::with_capacity(65536);
-        temp.push(CtFr::one());
-        for i in 1..65536 {
-            temp.push(temp[i - 1].mul(&scale_factor));
-        }
-        temp
-    });
-
+    let shift_powers = ShiftPowers::new(&recovery_shift(), len_p);
     p.iter_mut()
-        .zip(factors)
-        .take(len_p)
+        .zip(&shift_powers.inverse_powers)
         .skip(1)
-        .for_each(|(p, factor)| {
-            *p = p.mul(factor);
+        .for_each(|(p, power)| {
+            *p = p.mul(power);
         });
 }
 
-impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
-    fn recover_poly_coeffs_from_samples(
-        samples: &[Option<CtFr>],
-        fs: &CtFFTSettings,
-    ) -> Result<Self, String> {
+impl CtPoly {
+    fn recover_coeffs(samples: &[Option<CtFr>], fs: &CtFFTSettings) -> Result<Self, String> {
         let len_samples = samples.len();
 
         if !len_samples.is_power_of_two() {
@@ -86,10 +68,10 @@ impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
         }
 
         // Calculate `Z_r,I`
-        let (zero_eval, mut zero_poly) = fs.zero_poly_via_multiplication(len_samples, &missing)?;
+        let (zero_eval, zero_poly) = fs.zero_poly_via_multiplication(len_samples, &missing)?;
 
         // Construct E * Z_r,I: the loop makes the evaluation polynomial
-        let poly_evaluations_with_zero = samples
+        let mut poly_with_zero = samples
             .iter()
             .zip(zero_eval)
             .map(|(maybe_sample, zero_eval)| {
@@ -103,45 +85,28 @@ impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
             .collect::<Vec<_>>();
 
         // Now inverse FFT so that poly_with_zero is (E * Z_r,I)(x) = (D * Z_r,I)(x)
-        let mut poly_with_zero = fs.fft_fr(&poly_evaluations_with_zero, true).unwrap();
-        drop(poly_evaluations_with_zero);
-
-        // x -> k * x
-        let len_zero_poly = zero_poly.coeffs.len();
-        scale_poly(&mut poly_with_zero, len_samples);
-        scale_poly(&mut zero_poly.coeffs, len_zero_poly);
-
-        // Q1 = (D * Z_r,I)(k * x)
-        let scaled_poly_with_zero = poly_with_zero;
+        fs.fft_fr_inplace(&mut poly_with_zero, true).unwrap();
 
-        // Q2 = Z_r,I(k * x)
-        let scaled_zero_poly = zero_poly.coeffs;
-
-        // Polynomial division by convolution: Q3 = Q1 / Q2
+        // Evaluate both over the coset `k * w^i`, where `Z_r,I` has no zeroes:
+        // Q1 = (D * Z_r,I)(k * x) and Q2 = Z_r,I(k * x)
+        let shift = recovery_shift();
+        let coset_evals = |coeffs: &[CtFr]| fs.coset_fft_fr(coeffs, &shift, false);
         #[cfg(feature = "parallel")]
-        let (eval_scaled_poly_with_zero, eval_scaled_zero_poly) = {
-            if len_zero_poly - 1 > 1024 {
-                rayon::join(
-                    || fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                    || fs.fft_fr(&scaled_zero_poly, false).unwrap(),
-                )
-            } else {
-                (
-                    fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                    fs.fft_fr(&scaled_zero_poly, false).unwrap(),
-                )
-            }
-        };
-        #[cfg(not(feature = "parallel"))]
-        let (eval_scaled_poly_with_zero, eval_scaled_zero_poly) = {
-            (
-                fs.fft_fr(&scaled_poly_with_zero, false).unwrap(),
-                fs.fft_fr(&scaled_zero_poly, false).unwrap(),
+        let (eval_scaled_reconstructed_poly, eval_scaled_zero_poly) = if len_samples > 1024 {
+            rayon::join(
+                || coset_evals(&poly_with_zero),
+                || coset_evals(&zero_poly.coeffs),
             )
+        } else {
+            (coset_evals(&poly_with_zero), coset_evals(&zero_poly.coeffs))
         };
-        drop(scaled_zero_poly);
+        #[cfg(not(feature = "parallel"))]
+        let (eval_scaled_reconstructed_poly, eval_scaled_zero_poly) =
+            (coset_evals(&poly_with_zero), coset_evals(&zero_poly.coeffs));
+        let mut eval_scaled_reconstructed_poly = eval_scaled_reconstructed_poly?;
+        let eval_scaled_zero_poly = eval_scaled_zero_poly?;
 
-        let mut eval_scaled_reconstructed_poly = eval_scaled_poly_with_zero;
+        // Polynomial division by convolution: Q3 = Q1 / Q2
         #[cfg(not(feature = "parallel"))]
         let eval_scaled_reconstructed_poly_iter = eval_scaled_reconstructed_poly.iter_mut();
         #[cfg(feature = "parallel")]
@@ -157,19 +122,27 @@ impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
                 },
             );
 
-        // The result of the division is D(k * x):
-        let mut scaled_reconstructed_poly =
-            fs.fft_fr(&eval_scaled_reconstructed_poly, true).unwrap();
-        drop(eval_scaled_reconstructed_poly);
-
-        // k * x -> x
-        unscale_poly(&mut scaled_reconstructed_poly, len_samples);
-
-        // Finally we have D(x) which evaluates to our original data at the powers of roots of unity
+        // The result of the division is D(k * x), and interpolating it over the coset gives D(x),
+        // which evaluates to our original data at the powers of roots of unity
         Ok(Self {
-            coeffs: scaled_reconstructed_poly,
+            coeffs: fs.coset_fft_fr(&eval_scaled_reconstructed_poly, &shift, true)?,
         })
     }
+}
+
+impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
+    fn recover_poly_coeffs_from_samples(
+        samples: &[Option<CtFr>],
+        fs: &CtFFTSettings,
+    ) -> Result<Self, String> {
+        let recover = || Self::recover_coeffs(samples, fs);
+
+        #[cfg(feature = "parallel")]
+        return fs.execution_context.install(recover);
+
+        #[cfg(not(feature = "parallel"))]
+        recover()
+    }
 
     fn recover_poly_from_samples(
         samples: &[Option<CtFr>],
@@ -178,7 +151,8 @@ impl PolyRecover<CtFr, CtPoly, CtFFTSettings> for CtPoly {
         let reconstructed_poly = Self::recover_poly_coeffs_from_samples(samples, fs)?;
 
         // The evaluation polynomial for D(x) is the reconstructed data:
-        let reconstructed_data = fs.fft_fr(&reconstructed_poly.coeffs, false).unwrap();
+        let mut reconstructed_data = reconstructed_poly.coeffs;
+        fs.fft_fr_inplace(&mut reconstructed_data, false).unwrap();
 
         // Check all is well
         samples
@@ -1,10 +1,12 @@
 extern crate alloc;
 
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
+use kzg::fft::{CosetCache, ShiftPowers};
 use kzg::{FFTSettings, Fr};
 
 use crate::consts::SCALE2_ROOT_OF_UNITY;
@@ -17,6 +19,10 @@ pub struct CtFFTSettings {
     pub expanded_roots_of_unity: Vec<CtFr>,
     pub reverse_roots_of_unity: Vec<CtFr>,
     pub roots_of_unity: Vec<CtFr>,
+    pub coset_cache: CosetCache<CtFr>,
+    /// Pool FFTs and recovery run on, see [`FFTSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 impl Default for CtFFTSettings {
@@ -54,6 +60,9 @@ impl FFTSettings<CtFr> for CtFFTSettings {
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         })
     }
 
@@ -84,6 +93,15 @@ impl FFTSettings<CtFr> for CtFFTSettings {
     fn get_roots_of_unity(&self) -> &[CtFr] {
         &self.roots_of_unity
     }
+
+    fn get_coset_shift_powers(&self, shift: &CtFr, len: usize) -> Arc<ShiftPowers<CtFr>> {
+        self.coset_cache.get(shift, len)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
 
 /// Multiply a given root of unity by itself until it results in a 1 and result all multiplication values in a vector
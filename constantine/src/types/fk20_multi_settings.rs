@@ -5,7 +5,8 @@ use alloc::vec;
 use alloc::vec::Vec;
 
 use kzg::common_utils::reverse_bit_order;
-use kzg::{FK20MultiSettings, Poly, FFTG1, G1};
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::{FK20MultiSettings, KZGSettings, Poly, FFTG1, G1};
 
 use crate::types::fft_settings::CtFFTSettings;
 use crate::types::fr::CtFr;
@@ -20,7 +21,7 @@ use super::g1::CtG1Affine;
 pub struct CtFK20MultiSettings {
     pub kzg_settings: CtKZGSettings,
     pub chunk_len: usize,
-    pub x_ext_fft_files: Vec<Vec<CtG1>>,
+    pub x_ext_fft_tables: Vec<FixedBaseMsm<CtFr, CtG1, CtFp, CtG1Affine>>,
 }
 
 impl Clone for CtFK20MultiSettings {
@@ -28,7 +29,7 @@ impl Clone for CtFK20MultiSettings {
         Self {
             kzg_settings: self.kzg_settings.clone(),
             chunk_len: self.chunk_len,
-            x_ext_fft_files: self.x_ext_fft_files.clone(),
+            x_ext_fft_tables: self.x_ext_fft_tables.clone(),
         }
     }
 }
@@ -38,16 +39,24 @@ impl Default for CtFK20MultiSettings {
         Self {
             kzg_settings: CtKZGSettings::default(),
             chunk_len: 1,
-            x_ext_fft_files: vec![],
+            x_ext_fft_tables: vec![],
         }
     }
 }
 
-impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, CtFp, CtG1Affine>
-    for CtFK20MultiSettings
-{
+impl CtFK20MultiSettings {
+    /// Same as [`FK20MultiSettings::new`], with `window` and `max_table_size` passed on to the
+    /// fixed-base tables of the Toeplitz products. There are `n2 / chunk_len` of them, with
+    /// `chunk_len` points each, and `max_table_size` caps the precomputed points of all of them
+    /// together. See [`kzg::msm::fixed_base::FixedBaseMsm::with_params`].
     #[allow(clippy::many_single_char_names)]
-    fn new(ks: &CtKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+    pub fn with_params(
+        ks: &CtKZGSettings,
+        n2: usize,
+        chunk_len: usize,
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Self, String> {
         if n2 > ks.fs.max_width {
             return Err(String::from(
                 "n2 must be less than or equal to kzg settings max width",
@@ -78,7 +87,7 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
                 let mut j = start;
 
                 while i + 1 < k {
-                    x.push(ks.secret_g1[j]);
+                    x.push(ks.get_g1_monomial()[j]);
 
                     i += 1;
 
@@ -90,7 +99,7 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
                 }
                 x.push(CtG1::identity());
 
-                let ext_fft_file = ks.fs.toeplitz_part_1(&x);
+                let ext_fft_file = ks.fs.toeplitz_part_1_brp(&x);
                 x.clear();
                 ext_fft_files.push(ext_fft_file);
             }
@@ -99,11 +108,23 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
         let ret = Self {
             kzg_settings: ks.clone(),
             chunk_len,
-            x_ext_fft_files: ext_fft_files,
+            x_ext_fft_tables: CtFFTSettings::toeplitz_part_2_tables(
+                &ext_fft_files,
+                window,
+                max_table_size,
+            )?,
         };
 
         Ok(ret)
     }
+}
+
+impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, CtFp, CtG1Affine>
+    for CtFK20MultiSettings
+{
+    fn new(ks: &CtKZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+        Self::with_params(ks, n2, chunk_len, None, None)
+    }
 
     fn data_availability(&self, p: &CtPoly) -> Result<Vec<CtG1>, String> {
         let n = p.len();
@@ -137,30 +158,32 @@ impl FK20MultiSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtKZGSettings, C
             return Err(String::from("n2 must be a power of two"));
         }
 
-        let n = n2 / 2;
-        let k = n / self.chunk_len;
-        let k2 = k * 2;
+        let proofs = || {
+            let n = n2 / 2;
+            let k = n / self.chunk_len;
+            let k2 = k * 2;
 
-        let mut h_ext_fft = vec![CtG1::identity(); k2];
-
-        for i in 0..self.chunk_len {
-            let toeplitz_coeffs = p.toeplitz_coeffs_stride(i, self.chunk_len);
-            let h_ext_fft_file = self
+            let toeplitz_coeffs = (0..self.chunk_len)
+                .map(|i| p.toeplitz_coeffs_stride(i, self.chunk_len))
+                .collect::<Vec<_>>();
+            let h_ext_fft = self
                 .kzg_settings
                 .fs
-                .toeplitz_part_2(&toeplitz_coeffs, &self.x_ext_fft_files[i]);
+                .toeplitz_part_2_multi_brp(&toeplitz_coeffs, &self.x_ext_fft_tables);
 
-            for j in 0..k2 {
-                h_ext_fft[j] = h_ext_fft[j].add_or_dbl(&h_ext_fft_file[j]);
-            }
-        }
+            let mut h = self.kzg_settings.fs.toeplitz_part_3_brp(&h_ext_fft);
+
+            h[k..k2].copy_from_slice(&vec![CtG1::identity(); k2 - k]);
 
-        let mut h = self.kzg_settings.fs.toeplitz_part_3(&h_ext_fft);
+            self.kzg_settings.fs.fft_g1_inplace(&mut h, false)?;
 
-        h[k..k2].copy_from_slice(&vec![CtG1::identity(); k2 - k]);
+            Ok(h)
+        };
 
-        let ret = self.kzg_settings.fs.fft_g1(&h, false).unwrap();
+        #[cfg(feature = "parallel")]
+        return self.kzg_settings.execution_context.install(proofs);
 
-        Ok(ret)
+        #[cfg(not(feature = "parallel"))]
+        proofs()
     }
 }
@@ -4,11 +4,19 @@ use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
 use constantine::ctt_codec_ecc_status;
+use kzg::msm::constant_time::ct_select_limbs;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{g1_fixed_base_mul, g1_fixed_base_table, FixedBaseTable};
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::PrecomputationTable;
+#[cfg(feature = "prover")]
 use kzg::G1LinComb;
+#[cfg(feature = "prover")]
+use once_cell::sync::OnceCell;
 
 use core::fmt::{Debug, Formatter};
 
+#[cfg(feature = "prover")]
 use crate::kzg_proofs::g1_linear_combination;
 use crate::types::fp::CtFp;
 use crate::types::fr::CtFr;
@@ -17,7 +25,7 @@ use kzg::eip_4844::BYTES_PER_G1;
 use kzg::G1Affine;
 use kzg::G1GetFp;
 use kzg::G1ProjAddAffine;
-use kzg::{G1Mul, G1};
+use kzg::{G1ConstantTime, G1Mul, G1};
 
 use crate::consts::{G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR};
 // use crate::kzg_proofs::g1_linear_combination;
@@ -241,6 +249,27 @@ impl G1 for CtG1 {
     }
 }
 
+impl G1ConstantTime for CtG1 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        let select = |a: &bls12_381_fp, b: &bls12_381_fp| unsafe {
+            // Transmute safe as limbs are 64-bit words
+            bls12_381_fp {
+                limbs: core::mem::transmute(ct_select_limbs::<6>(
+                    core::mem::transmute(&a.limbs),
+                    core::mem::transmute(&b.limbs),
+                    choice,
+                )),
+            }
+        };
+
+        Self(bls12_381_g1_jac {
+            x: select(&a.0.x, &b.0.x),
+            y: select(&a.0.y, &b.0.y),
+            z: select(&a.0.z, &b.0.z),
+        })
+    }
+}
+
 impl G1Mul<CtFr> for CtG1 {
     fn mul(&self, b: &CtFr) -> Self {
         let mut result = *self;
@@ -249,8 +278,23 @@ impl G1Mul<CtFr> for CtG1 {
         }
         result
     }
+
+    fn mul_ct(&self, b: &CtFr) -> Self
+    where
+        Self: G1ConstantTime,
+    {
+        // Constantine's scalar multiplication is constant-time already
+        self.mul(b)
+    }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &CtFr) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<CtG1>> = OnceCell::new();
+        g1_fixed_base_mul(TABLE.get_or_init(|| g1_fixed_base_table(&G1_GENERATOR)), b)
+    }
 }
 
+#[cfg(feature = "prover")]
 impl G1LinComb<CtFr, CtFp, CtG1Affine> for CtG1 {
     fn g1_lincomb(
         points: &[Self],
@@ -6,9 +6,13 @@ use alloc::string::ToString;
 
 use constantine::ctt_codec_ecc_status;
 use kzg::eip_4844::BYTES_PER_G2;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{g2_fixed_base_mul, g2_fixed_base_table, FixedBaseTable};
 #[cfg(feature = "rand")]
 use kzg::Fr;
 use kzg::{G2Mul, G2};
+#[cfg(feature = "prover")]
+use once_cell::sync::OnceCell;
 
 use crate::consts::{G2_GENERATOR, G2_NEGATIVE_GENERATOR};
 use crate::types::fr::CtFr;
@@ -107,6 +111,12 @@ impl G2Mul<CtFr> for CtG2 {
         }
         result
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &CtFr) -> Self {
+        static TABLE: OnceCell<FixedBaseTable<CtG2>> = OnceCell::new();
+        g2_fixed_base_mul(TABLE.get_or_init(|| g2_fixed_base_table(&G2_GENERATOR)), b)
+    }
 }
 
 impl G2 for CtG2 {
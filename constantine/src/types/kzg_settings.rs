@@ -2,11 +2,13 @@ extern crate alloc;
 
 use alloc::string::String;
 use alloc::vec::Vec;
+use once_cell::sync::OnceCell;
 
+use kzg::eip_4844::g1_monomial_from_lagrange_brp;
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::{FFTFr, FFTSettings, Fr, G1Mul, G2Mul, KZGSettings, Poly, G1, G2};
 
-use crate::consts::{G1_GENERATOR, G2_GENERATOR};
+use crate::consts::G2_GENERATOR;
 use crate::kzg_proofs::{g1_linear_combination, pairings_verify};
 use crate::types::fft_settings::CtFFTSettings;
 use crate::types::fr::CtFr;
@@ -21,8 +23,17 @@ use super::g1::CtG1Affine;
 pub struct CtKZGSettings {
     pub fs: CtFFTSettings,
     pub secret_g1: Vec<CtG1>,
+    /// `[τ^i]` in G1 when `secret_g1` are Lagrange-form points, as for loaded EIP-4844 setups,
+    /// derived from them on first use unless given. `None` when `secret_g1` are the monomial
+    /// powers themselves.
+    pub secret_g1_monomial: Option<OnceCell<Vec<CtG1>>>,
+    /// `[τ^i]` in G2
     pub secret_g2: Vec<CtG2>,
     pub precomputation: Option<PrecomputationTable<CtFr, CtG1, CtFp, CtG1Affine>>,
+    /// Pool commitments, proofs and batch verification run on, see
+    /// [`KZGSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for CtKZGSettings {
@@ -34,25 +45,78 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
     ) -> Result<Self, String> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
+            secret_g1_monomial: None,
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
             precomputation: precompute(secret_g1).ok().flatten(),
+            #[cfg(feature = "parallel")]
+            execution_context: fft_settings.execution_context.clone(),
+        })
+    }
+
+    fn new_with_monomial(
+        secret_g1: &[CtG1],
+        secret_g1_monomial: Option<&[CtG1]>,
+        secret_g2: &[CtG2],
+        length: usize,
+        fft_settings: &CtFFTSettings,
+    ) -> Result<Self, String> {
+        let monomial = match secret_g1_monomial {
+            Some(secret_g1_monomial) => {
+                if secret_g1_monomial.len() != secret_g1.len() {
+                    return Err(String::from(
+                        "Monomial and Lagrange-form G1 points must be as many",
+                    ));
+                }
+                OnceCell::from(secret_g1_monomial.to_vec())
+            }
+            None => {
+                // Checked here, deriving the monomial points later can't return an error
+                if !secret_g1.len().is_power_of_two() || secret_g1.len() > fft_settings.max_width {
+                    return Err(String::from(
+                        "Lagrange-form G1 points must be a power of two, up to the FFT width",
+                    ));
+                }
+                OnceCell::new()
+            }
+        };
+
+        Ok(Self {
+            secret_g1_monomial: Some(monomial),
+            ..Self::new(secret_g1, secret_g2, length, fft_settings)?
         })
     }
 
     fn commit_to_poly(&self, poly: &CtPoly) -> Result<CtG1, String> {
-        if poly.coeffs.len() > self.secret_g1.len() {
+        let secret_g1 = self.get_g1_monomial();
+        if poly.coeffs.len() > secret_g1.len() {
             return Err(String::from("Polynomial is longer than secret g1"));
         }
 
+        // The precomputation is over `secret_g1`, which isn't monomial for loaded setups. Those
+        // commit to coefficients with a plain MSM, rather than keep a second table as large
+        let precomputation = if self.secret_g1_monomial.is_none() {
+            self.get_precomputation()
+        } else {
+            None
+        };
+
         let mut out = CtG1::default();
-        g1_linear_combination(
-            &mut out,
-            &self.secret_g1,
-            &poly.coeffs,
-            poly.coeffs.len(),
-            self.get_precomputation(),
-        );
+        let lincomb = |out: &mut CtG1| {
+            g1_linear_combination(
+                out,
+                secret_g1,
+                &poly.coeffs,
+                poly.coeffs.len(),
+                precomputation,
+            )
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| lincomb(&mut out));
+
+        #[cfg(not(feature = "parallel"))]
+        lincomb(&mut out);
 
         Ok(out)
     }
@@ -62,18 +126,7 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
             return Err(String::from("Polynomial must not be empty"));
         }
 
-        // `-(x0^n)`, where `n` is `1`
-        let divisor_0 = x.negate();
-
-        // Calculate `q = p / (x^n - x0^n)` for our reduced case (see `compute_proof_multi` for
-        // generic implementation)
-        let mut out_coeffs = Vec::from(&p.coeffs[1..]);
-        for i in (1..out_coeffs.len()).rev() {
-            let tmp = out_coeffs[i].mul(&divisor_0);
-            out_coeffs[i - 1] = out_coeffs[i - 1].sub(&tmp);
-        }
-
-        let q = CtPoly { coeffs: out_coeffs };
+        let q = p.divide_by_linear(x);
 
         let ret = self.commit_to_poly(&q)?;
 
@@ -87,9 +140,9 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         x: &CtFr,
         y: &CtFr,
     ) -> Result<bool, String> {
-        let x_g2: CtG2 = G2_GENERATOR.mul(x);
+        let x_g2: CtG2 = CtG2::generator_mul(x);
         let s_minus_x: CtG2 = self.secret_g2[1].sub(&x_g2);
-        let y_g1 = G1_GENERATOR.mul(y);
+        let y_g1 = CtG1::generator_mul(y);
         let commitment_minus_y: CtG1 = com.sub(&y_g1);
 
         Ok(pairings_verify(
@@ -131,7 +184,13 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
 
         // Calculate q = p / (x^n - x0^n)
         // let q = p.div(&divisor).unwrap();
-        let q = new_polina.div(&divisor)?;
+        let divide = |dividend: &mut CtPoly| dividend.div(&divisor);
+
+        #[cfg(feature = "parallel")]
+        let q = self.execution_context.install(|| divide(&mut new_polina))?;
+
+        #[cfg(not(feature = "parallel"))]
+        let q = divide(&mut new_polina)?;
 
         let ret = self.commit_to_poly(&q)?;
 
@@ -151,21 +210,14 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         }
 
         // Interpolate at a coset.
-        let mut interp = CtPoly {
-            coeffs: self.fs.fft_fr(ys, true)?,
+        let interp = CtPoly {
+            coeffs: self.fs.coset_fft_fr(ys, x, true)?,
         };
 
-        let inv_x = x.inverse(); // Not euclidean?
-        let mut inv_x_pow = inv_x;
-        for i in 1..n {
-            interp.coeffs[i] = interp.coeffs[i].mul(&inv_x_pow);
-            inv_x_pow = inv_x_pow.mul(&inv_x);
-        }
-
         // [x^n]_2
-        let x_pow = inv_x_pow.inverse();
+        let x_pow = x.pow(n);
 
-        let xn2 = G2_GENERATOR.mul(&x_pow);
+        let xn2 = CtG2::generator_mul(&x_pow);
 
         // [s^n - x^n]_2
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
@@ -197,6 +249,24 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[CtG1] {
+        match &self.secret_g1_monomial {
+            None => &self.secret_g1,
+            // Only fails for settings built by hand with a wrong number of points
+            Some(monomial) => monomial.get_or_init(|| {
+                g1_monomial_from_lagrange_brp(&self.secret_g1, &self.fs).unwrap_or_default()
+            }),
+        }
+    }
+
+    fn get_g1_lagrange_brp(&self) -> &[CtG1] {
+        if self.secret_g1_monomial.is_some() {
+            &self.secret_g1
+        } else {
+            &[]
+        }
+    }
+
     fn get_g2_secret(&self) -> &[CtG2] {
         &self.secret_g2
     }
@@ -204,4 +274,9 @@ impl KZGSettings<CtFr, CtG1, CtG2, CtFFTSettings, CtPoly, CtFp, CtG1Affine> for
     fn get_precomputation(&self) -> Option<&PrecomputationTable<CtFr, CtG1, CtFp, CtG1Affine>> {
         self.precomputation.as_ref()
     }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
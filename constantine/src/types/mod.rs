@@ -1,9 +1,12 @@
 pub mod fft_settings;
+#[cfg(feature = "prover")]
 pub mod fk20_multi_settings;
+#[cfg(feature = "prover")]
 pub mod fk20_single_settings;
 pub mod fp;
 pub mod fr;
 pub mod g1;
 pub mod g2;
+#[cfg(feature = "prover")]
 pub mod kzg_settings;
 pub mod poly;
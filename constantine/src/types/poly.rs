@@ -23,6 +23,12 @@ impl Poly<CtFr> for CtPoly {
         }
     }
 
+    fn from_coeffs(coeffs: &[CtFr]) -> Self {
+        Self {
+            coeffs: coeffs.to_vec(),
+        }
+    }
+
     fn get_coeff_at(&self, i: usize) -> CtFr {
         self.coeffs[i]
     }
@@ -42,7 +48,7 @@ impl Poly<CtFr> for CtPoly {
     fn eval(&self, x: &CtFr) -> CtFr {
         if self.coeffs.is_empty() {
             return CtFr::zero();
-        } else if x.is_zero() {
+        } else if x.is_zero() || self.coeffs.len() == 1 {
             return self.coeffs[0];
         }
 
@@ -82,70 +88,8 @@ impl Poly<CtFr> for CtPoly {
         }
     }
 
-    // TODO: analyze how algo works
     fn inverse(&mut self, output_len: usize) -> Result<Self, String> {
-        if output_len == 0 {
-            return Err(String::from("Can't produce a zero-length result"));
-        } else if self.coeffs.is_empty() {
-            return Err(String::from("Can't inverse a zero-length poly"));
-        } else if self.coeffs[0].is_zero() {
-            return Err(String::from(
-                "First coefficient of polynomial mustn't be zero",
-            ));
-        }
-
-        let mut ret = CtPoly {
-            coeffs: vec![CtFr::zero(); output_len],
-        };
-        // If the input polynomial is constant, the remainder of the series is zero
-        if self.coeffs.len() == 1 {
-            ret.coeffs[0] = self.coeffs[0].eucl_inverse();
-
-            return Ok(ret);
-        }
-
-        let maxd = output_len - 1;
-
-        // Max space for multiplications is (2 * length - 1)
-        // Don't need the following as its recalculated inside
-        // let scale: usize = log2_pow2(next_pow_of_2(2 * output_len - 1));
-        // let fft_settings = FsFFTSettings::new(scale).unwrap();
-
-        // To store intermediate results
-
-        // Base case for d == 0
-        ret.coeffs[0] = self.coeffs[0].eucl_inverse();
-        let mut d: usize = 0;
-        let mut mask: usize = 1 << log2_u64(maxd);
-        while mask != 0 {
-            d = 2 * d + usize::from((maxd & mask) != 0);
-            mask >>= 1;
-
-            // b.c -> tmp0 (we're using out for c)
-            // tmp0.length = min_u64(d + 1, b->length + output->length - 1);
-            let len_temp = (d + 1).min(self.len() + output_len - 1);
-            let mut tmp0 = self.mul(&ret, len_temp).unwrap();
-
-            // 2 - b.c -> tmp0
-            for i in 0..tmp0.len() {
-                tmp0.coeffs[i] = tmp0.coeffs[i].negate();
-            }
-            let fr_two = Fr::from_u64(2);
-            tmp0.coeffs[0] = tmp0.coeffs[0].add(&fr_two);
-
-            // c.(2 - b.c) -> tmp1;
-            let tmp1 = ret.mul(&tmp0, d + 1).unwrap();
-
-            for i in 0..tmp1.len() {
-                ret.coeffs[i] = tmp1.coeffs[i];
-            }
-        }
-
-        if d + 1 != output_len {
-            return Err(String::from("D + 1 must be equal to output_len"));
-        }
-
-        Ok(ret)
+        self.inverse_with_settings(output_len, None)
     }
 
     fn div(&mut self, divisor: &Self) -> Result<Self, String> {
@@ -242,8 +186,10 @@ impl Poly<CtFr> for CtPoly {
         let mut a_flip = self.flip().unwrap();
         let mut b_flip = divisor.flip().unwrap();
 
-        let inv_b_flip = b_flip.inverse(m - n + 1).unwrap();
-        let q_flip = a_flip.mul(&inv_b_flip, m - n + 1).unwrap();
+        let out_len = m - n + 1;
+        let fs = CtFFTSettings::new(log2_pow2(next_pow_of_2(2 * out_len - 1)))?;
+        let inv_b_flip = b_flip.inverse_with_settings(out_len, Some(&fs)).unwrap();
+        let q_flip = a_flip.mul(&inv_b_flip, out_len, Some(&fs)).unwrap();
 
         let out = q_flip.flip().unwrap();
         Ok(out)
@@ -275,6 +221,33 @@ impl Poly<CtFr> for CtPoly {
 
         Ok(ret)
     }
+
+    fn compose(&self, other: &Self) -> Result<Self, String> {
+        let Some((last, rest)) = self.coeffs.split_last() else {
+            return Ok(Self::new(0));
+        };
+        if other.coeffs.is_empty() {
+            return Ok(Self {
+                coeffs: vec![self.coeffs[0]],
+            });
+        }
+
+        // Every intermediate product is shorter than the last one, so settings sized for it can
+        // be used throughout
+        let out_len = rest.len() * (other.len() - 1) + 1;
+        let fs = CtFFTSettings::new(log2_pow2(next_pow_of_2(out_len)))?;
+
+        let mut out = Self {
+            coeffs: vec![*last],
+        };
+        for coeff in rest.iter().rev() {
+            let len = out.len() + other.len() - 1;
+            out = out.mul(other, len, Some(&fs))?;
+            out.coeffs[0] = out.coeffs[0].add(coeff);
+        }
+
+        Ok(out)
+    }
 }
 
 impl FFTSettingsPoly<CtFr, CtPoly, CtFFTSettings> for CtFFTSettings {
@@ -282,9 +255,9 @@ impl FFTSettingsPoly<CtFr, CtPoly, CtFFTSettings> for CtFFTSettings {
         a: &CtPoly,
         b: &CtPoly,
         len: usize,
-        _fs: Option<&CtFFTSettings>,
+        fs: Option<&CtFFTSettings>,
     ) -> Result<CtPoly, String> {
-        b.mul_fft(a, len)
+        b.mul_fft(a, len, fs)
     }
 }
 
@@ -337,57 +310,156 @@ impl CtPoly {
         Ok(ret)
     }
 
-    pub fn mul_fft(&self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
-        let length = next_pow_of_2(self.len() + multiplier.len() - 1);
+    // TODO: analyze how algo works
+    /// Same as [`Poly::inverse`], but reuses `fs` for the multiplications when given.
+    pub fn inverse_with_settings(
+        &mut self,
+        output_len: usize,
+        fs: Option<&CtFFTSettings>,
+    ) -> Result<Self, String> {
+        if output_len == 0 {
+            return Err(String::from("Can't produce a zero-length result"));
+        } else if self.coeffs.is_empty() {
+            return Err(String::from("Can't inverse a zero-length poly"));
+        } else if self.coeffs[0].is_zero() {
+            return Err(String::from(
+                "First coefficient of polynomial mustn't be zero",
+            ));
+        }
+
+        let mut ret = CtPoly {
+            coeffs: vec![CtFr::zero(); output_len],
+        };
+        // If the input polynomial is constant, the remainder of the series is zero
+        if self.coeffs.len() == 1 {
+            ret.coeffs[0] = self.coeffs[0].eucl_inverse();
+
+            return Ok(ret);
+        }
+
+        let maxd = output_len - 1;
+
+        // Every product below is truncated to at most output_len coefficients, so the largest
+        // transform is next_pow_of_2(2 * output_len - 1) long. Build settings for it once
+        // rather than once per multiplication
+        let local_fs;
+        let fs = match fs {
+            Some(fs) => fs,
+            None => {
+                local_fs = CtFFTSettings::new(log2_pow2(next_pow_of_2(2 * output_len - 1)))?;
+                &local_fs
+            }
+        };
+
+        // To store intermediate results
+
+        // Base case for d == 0
+        ret.coeffs[0] = self.coeffs[0].eucl_inverse();
+        let mut d: usize = 0;
+        let mut mask: usize = 1 << log2_u64(maxd);
+        while mask != 0 {
+            d = 2 * d + usize::from((maxd & mask) != 0);
+            mask >>= 1;
+
+            // b.c -> tmp0 (we're using out for c)
+            // tmp0.length = min_u64(d + 1, b->length + output->length - 1);
+            let len_temp = (d + 1).min(self.len() + output_len - 1);
+            let mut tmp0 = self.mul(&ret, len_temp, Some(fs)).unwrap();
+
+            // 2 - b.c -> tmp0
+            for i in 0..tmp0.len() {
+                tmp0.coeffs[i] = tmp0.coeffs[i].negate();
+            }
+            let fr_two = Fr::from_u64(2);
+            tmp0.coeffs[0] = tmp0.coeffs[0].add(&fr_two);
+
+            // c.(2 - b.c) -> tmp1;
+            let tmp1 = ret.mul(&tmp0, d + 1, Some(fs)).unwrap();
+
+            for i in 0..tmp1.len() {
+                ret.coeffs[i] = tmp1.coeffs[i];
+            }
+        }
+
+        if d + 1 != output_len {
+            return Err(String::from("D + 1 must be equal to output_len"));
+        }
+
+        Ok(ret)
+    }
+
+    /// Multiplies via FFT. The transforms run over `fs` when given, which has to be at least
+    /// `next_pow_of_2` of the product length wide, and over freshly built settings otherwise.
+    pub fn mul_fft(
+        &self,
+        multiplier: &Self,
+        output_len: usize,
+        fs: Option<&CtFFTSettings>,
+    ) -> Result<Self, String> {
+        // Coefficients past output_len can't affect the output, so leave them out of the transform
+        let a = &self.coeffs[..self.len().min(output_len)];
+        let b = &multiplier.coeffs[..multiplier.len().min(output_len)];
 
-        let scale = log2_pow2(length);
-        let fft_settings = CtFFTSettings::new(scale).unwrap();
+        let mut ret = CtPoly {
+            coeffs: vec![CtFr::zero(); output_len],
+        };
+        if a.is_empty() || b.is_empty() {
+            return Ok(ret);
+        }
 
-        let a_pad = self.pad(length);
-        let b_pad = multiplier.pad(length);
+        let length = next_pow_of_2(a.len() + b.len() - 1);
+
+        let local_fs;
+        let fft_settings = match fs {
+            Some(fs) => {
+                if length > fs.max_width {
+                    return Err(String::from(
+                        "length should be equals or less than FFTSettings max width",
+                    ));
+                }
+                fs
+            }
+            None => {
+                local_fs = CtFFTSettings::new(log2_pow2(length))?;
+                &local_fs
+            }
+        };
 
-        let a_fft: Vec<CtFr>;
-        let b_fft: Vec<CtFr>;
+        let mut a_fft = a.to_vec();
+        a_fft.resize(length, CtFr::zero());
+        let mut b_fft = b.to_vec();
+        b_fft.resize(length, CtFr::zero());
 
         #[cfg(feature = "parallel")]
         {
             if length > 1024 {
-                let mut a_fft_temp = vec![];
-                let mut b_fft_temp = vec![];
-
-                rayon::join(
-                    || a_fft_temp = fft_settings.fft_fr(&a_pad.coeffs, false).unwrap(),
-                    || b_fft_temp = fft_settings.fft_fr(&b_pad.coeffs, false).unwrap(),
+                let (a_res, b_res) = rayon::join(
+                    || fft_settings.fft_fr_inplace(&mut a_fft, false),
+                    || fft_settings.fft_fr_inplace(&mut b_fft, false),
                 );
-
-                a_fft = a_fft_temp;
-                b_fft = b_fft_temp;
+                a_res?;
+                b_res?;
             } else {
-                a_fft = fft_settings.fft_fr(&a_pad.coeffs, false).unwrap();
-                b_fft = fft_settings.fft_fr(&b_pad.coeffs, false).unwrap();
+                fft_settings.fft_fr_inplace(&mut a_fft, false)?;
+                fft_settings.fft_fr_inplace(&mut b_fft, false)?;
             }
         }
 
         #[cfg(not(feature = "parallel"))]
         {
             // Convert Poly to values
-            a_fft = fft_settings.fft_fr(&a_pad.coeffs, false).unwrap();
-            b_fft = fft_settings.fft_fr(&b_pad.coeffs, false).unwrap();
+            fft_settings.fft_fr_inplace(&mut a_fft, false)?;
+            fft_settings.fft_fr_inplace(&mut b_fft, false)?;
         }
 
         // Multiply two value ranges
-        let mut ab_fft = a_fft;
-        ab_fft.iter_mut().zip(b_fft).for_each(|(a, b)| {
+        let mut ab = a_fft;
+        ab.iter_mut().zip(b_fft).for_each(|(a, b)| {
             *a = a.mul(&b);
         });
 
         // Convert value range multiplication to a resulting polynomial
-        let ab = fft_settings.fft_fr(&ab_fft, true).unwrap();
-        drop(ab_fft);
-
-        let mut ret = CtPoly {
-            coeffs: vec![CtFr::zero(); output_len],
-        };
+        fft_settings.fft_fr_inplace(&mut ab, true)?;
 
         let range = ..output_len.min(length);
         ret.coeffs[range].clone_from_slice(&ab[range]);
@@ -395,12 +467,17 @@ impl CtPoly {
         Ok(ret)
     }
 
-    pub fn mul(&mut self, multiplier: &Self, output_len: usize) -> Result<Self, String> {
+    pub fn mul(
+        &mut self,
+        multiplier: &Self,
+        output_len: usize,
+        fs: Option<&CtFFTSettings>,
+    ) -> Result<Self, String> {
         if self.len() < 64 || multiplier.len() < 64 || output_len < 128 {
             // Tunable parameter
             self.mul_direct(multiplier, output_len)
         } else {
-            self.mul_fft(multiplier, output_len)
+            self.mul_fft(multiplier, output_len, fs)
         }
     }
 }
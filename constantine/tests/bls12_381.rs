@@ -4,9 +4,11 @@ mod tests {
     use kzg_bench::tests::bls12_381::{
         fr_div_by_zero, fr_div_works, fr_equal_works, fr_from_uint64_works, fr_is_null_works,
         fr_is_one_works, fr_is_zero_works, fr_negate_works, fr_pow_works, fr_uint64s_roundtrip,
-        g1_identity_is_identity, g1_identity_is_infinity, g1_make_linear_combination,
-        g1_random_linear_combination, log_2_byte_works, p1_mul_works, p1_sub_works,
-        p2_add_or_dbl_works, p2_mul_works, p2_sub_works, pairings_work,
+        g1_generator_mul_works, g1_identity_is_identity, g1_identity_is_infinity,
+        g1_lincomb_ct_works, g1_make_linear_combination, g1_mul_ct_works,
+        g1_random_linear_combination, g1_sparse_linear_combination, g2_generator_mul_works,
+        log_2_byte_works, p1_mul_works, p1_sub_works, p2_add_or_dbl_works, p2_mul_works,
+        p2_sub_works, pairings_work,
     };
 
     use rust_kzg_constantine::kzg_proofs::{g1_linear_combination, pairings_verify};
@@ -75,6 +77,16 @@ mod tests {
         p1_mul_works::<CtFr, CtG1>()
     }
 
+    #[test]
+    fn g1_generator_mul_works_() {
+        g1_generator_mul_works::<CtFr, CtG1>()
+    }
+
+    #[test]
+    fn g1_mul_ct_works_() {
+        g1_mul_ct_works::<CtFr, CtG1>()
+    }
+
     #[test]
     fn p1_sub_works_() {
         p1_sub_works::<CtG1>()
@@ -90,6 +102,11 @@ mod tests {
         p2_mul_works::<CtFr, CtG2>()
     }
 
+    #[test]
+    fn g2_generator_mul_works_() {
+        g2_generator_mul_works::<CtFr, CtG2>()
+    }
+
     #[test]
     fn p2_sub_works_() {
         p2_sub_works::<CtG2>()
@@ -115,8 +132,18 @@ mod tests {
         g1_random_linear_combination::<CtFr, CtG1, CtFp, CtG1Affine>(&g1_linear_combination)
     }
 
+    #[test]
+    fn g1_sparse_linear_combination_() {
+        g1_sparse_linear_combination::<CtFr, CtG1, CtFp, CtG1Affine>(&g1_linear_combination)
+    }
+
     #[test]
     fn pairings_work_() {
         pairings_work::<CtFr, CtG1, CtG2>(&pairings_verify)
     }
+
+    #[test]
+    fn g1_lincomb_ct_works_() {
+        g1_lincomb_ct_works::<CtFr, CtG1, CtFp, CtG1Affine>()
+    }
 }
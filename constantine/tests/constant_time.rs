@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::constant_time::{
+        g1_lincomb_ct_is_constant_time, g1_mul_ct_is_constant_time,
+    };
+    use rust_kzg_constantine::types::fp::CtFp;
+    use rust_kzg_constantine::types::fr::CtFr;
+    use rust_kzg_constantine::types::g1::{CtG1, CtG1Affine};
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    fn g1_mul_ct_is_constant_time_() {
+        g1_mul_ct_is_constant_time::<CtFr, CtG1>()
+    }
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    fn g1_lincomb_ct_is_constant_time_() {
+        g1_lincomb_ct_is_constant_time::<CtFr, CtG1, CtFp, CtG1Affine>()
+    }
+}
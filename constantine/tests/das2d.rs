@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::das2d::{das_2d_commitments, das_2d_extend_and_recover};
+    use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
+    use rust_kzg_constantine::types::fp::CtFp;
+    use rust_kzg_constantine::types::fr::CtFr;
+    use rust_kzg_constantine::types::g1::{CtG1, CtG1Affine};
+    use rust_kzg_constantine::types::g2::CtG2;
+    use rust_kzg_constantine::types::kzg_settings::CtKZGSettings;
+    use rust_kzg_constantine::types::poly::CtPoly;
+    use rust_kzg_constantine::utils::generate_trusted_setup;
+
+    #[test]
+    fn das_2d_extend_and_recover_() {
+        das_2d_extend_and_recover::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
+    }
+
+    #[test]
+    fn das_2d_commitments_() {
+        das_2d_commitments::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+}
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use kzg::G1;
-    use kzg_bench::tests::fft_g1::{compare_ft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{
+        compare_ft_fft, coset_fft, inplace_fft, roundtrip_fft, stride_fft,
+    };
     use rust_kzg_constantine::consts::G1_GENERATOR;
     use rust_kzg_constantine::fft_g1::{fft_g1_fast, fft_g1_slow};
     use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
@@ -35,4 +37,14 @@ mod tests {
     fn compare_sft_fft_() {
         compare_ft_fft::<CtFr, CtG1, CtFFTSettings>(&fft_g1_slow, &fft_g1_fast, &make_data);
     }
+
+    #[test]
+    fn inplace_fft_() {
+        inplace_fft::<CtFr, CtG1, CtFFTSettings>(&make_data);
+    }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<CtFr, CtG1, CtFFTSettings>(&make_data);
+    }
 }
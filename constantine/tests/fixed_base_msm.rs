@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::msm::fixed_base_msm::{
+        fixed_base_msm_respects_params, fixed_base_msm_works,
+    };
+    use rust_kzg_constantine::types::fp::CtFp;
+    use rust_kzg_constantine::types::fr::CtFr;
+    use rust_kzg_constantine::types::g1::{CtG1, CtG1Affine};
+
+    #[test]
+    fn fixed_base_msm_works_() {
+        fixed_base_msm_works::<CtFr, CtG1, CtFp, CtG1Affine>()
+    }
+
+    #[test]
+    fn fixed_base_msm_respects_params_() {
+        fixed_base_msm_respects_params::<CtFr, CtG1, CtFp, CtG1Affine>()
+    }
+}
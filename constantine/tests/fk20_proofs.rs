@@ -42,6 +42,21 @@ mod tests {
         >(&generate_trusted_setup);
     }
 
+    #[test]
+    fn test_fk_single_with_params() {
+        fk_single_with_params::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFK20SingleSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup, &CtFK20SingleSettings::with_params);
+    }
+
     #[test]
     fn test_fk_multi_settings() {
         fk_multi_settings::<
@@ -101,4 +116,34 @@ mod tests {
             CtG1Affine,
         >(&generate_trusted_setup);
     }
+
+    #[test]
+    fn test_fk_multi_with_params() {
+        fk_multi_with_params::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFK20MultiSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup, &CtFK20MultiSettings::with_params);
+    }
+
+    #[test]
+    fn test_fk_multi_commitment_scheme() {
+        fk_multi_commitment_scheme::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFK20MultiSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -2,7 +2,9 @@
 mod tests {
 
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly_returns_err, commitment_schemes,
+        degree_bound_proofs, hiding_commitments, proof_aggregation, proof_batch_same_point,
+        proof_multi, proof_shplonk, proof_single,
     };
 
     use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
@@ -55,4 +57,74 @@ mod tests {
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    pub fn test_proof_batch_same_point() {
+        proof_batch_same_point::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_proof_shplonk() {
+        proof_shplonk::<CtFr, CtG1, CtG2, CtPoly, CtFFTSettings, CtKZGSettings, CtFp, CtG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn test_proof_aggregation() {
+        proof_aggregation::<CtFr, CtG1, CtG2, CtPoly, CtFFTSettings, CtKZGSettings, CtFp, CtG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    pub fn test_hiding_commitments() {
+        hiding_commitments::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_degree_bound_proofs() {
+        degree_bound_proofs::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
+
+    #[test]
+    pub fn test_commitment_schemes() {
+        commitment_schemes::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup);
+    }
 }
@@ -22,6 +22,11 @@ mod tests {
         recover_random::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
     }
 
+    #[test]
+    fn recoverer_incremental_() {
+        recoverer_incremental::<CtFr, CtFFTSettings, CtPoly>();
+    }
+
     #[test]
     fn more_than_half_missing_() {
         more_than_half_missing::<CtFr, CtFFTSettings, CtPoly, CtPoly>();
@@ -0,0 +1,58 @@
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use kzg::msm::thread_pool::ExecutionContext;
+    use kzg::FFTSettings;
+    use kzg_bench::tests::msm::thread_pool::{
+        fft_and_fk20_stay_in_execution_context, g1_lincomb_is_pool_independent,
+    };
+    use rust_kzg_constantine::types::fft_settings::CtFFTSettings;
+    use rust_kzg_constantine::types::fk20_multi_settings::CtFK20MultiSettings;
+    use rust_kzg_constantine::types::fk20_single_settings::CtFK20SingleSettings;
+    use rust_kzg_constantine::types::fp::CtFp;
+    use rust_kzg_constantine::types::fr::CtFr;
+    use rust_kzg_constantine::types::g1::{CtG1, CtG1Affine};
+    use rust_kzg_constantine::types::g2::CtG2;
+    use rust_kzg_constantine::types::kzg_settings::CtKZGSettings;
+    use rust_kzg_constantine::types::poly::CtPoly;
+    use rust_kzg_constantine::utils::generate_trusted_setup;
+
+    #[test]
+    fn g1_lincomb_is_pool_independent_() {
+        g1_lincomb_is_pool_independent::<CtFr, CtG1, CtFp, CtG1Affine>()
+    }
+
+    #[test]
+    fn fft_and_fk20_stay_in_execution_context_() {
+        let fs = CtFFTSettings {
+            execution_context: ExecutionContext::with_threads(1).unwrap(),
+            ..CtFFTSettings::new(12).unwrap()
+        };
+
+        fft_and_fk20_stay_in_execution_context::<
+            CtFr,
+            CtG1,
+            CtG2,
+            CtPoly,
+            CtFFTSettings,
+            CtKZGSettings,
+            CtFK20SingleSettings,
+            CtFK20MultiSettings,
+            CtFp,
+            CtG1Affine,
+        >(&generate_trusted_setup, &fs)
+    }
+
+    #[test]
+    fn execution_context_has_requested_threads() {
+        for threads in [1, 2, 3, 8] {
+            let context = ExecutionContext::with_threads(threads).unwrap();
+            assert_eq!(context.num_threads(), threads);
+            assert_eq!(context.install(rayon::current_num_threads), threads);
+        }
+    }
+
+    #[test]
+    fn execution_context_rejects_zero_threads() {
+        assert!(ExecutionContext::with_threads(0).is_err());
+    }
+}
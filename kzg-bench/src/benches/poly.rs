@@ -1,7 +1,9 @@
 use criterion::Criterion;
-use kzg::{Fr, Poly};
+use kzg::multipoint::SubproductTree;
+use kzg::{FFTSettings, FFTSettingsPoly, Fr, Poly};
 
 const BENCH_SCALE: usize = 15;
+const MULTIPOINT_BENCH_SCALE: usize = 12;
 
 pub fn bench_new_poly_div<TFr: Fr, TPoly: Poly<TFr>>(c: &mut Criterion) {
     let dividend_length = 1 << BENCH_SCALE;
@@ -33,3 +35,53 @@ pub fn bench_new_poly_div<TFr: Fr, TPoly: Poly<TFr>>(c: &mut Criterion) {
         })
     });
 }
+
+pub fn bench_multipoint_evaluate<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+>(
+    c: &mut Criterion,
+) {
+    let n = 1 << MULTIPOINT_BENCH_SCALE;
+    let xs = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let coeffs = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let poly = TPoly::from_coeffs(&coeffs);
+    let tree = SubproductTree::<TFr, TPoly, TFFTSettings>::new(&xs).unwrap();
+
+    let id = format!(
+        "bench_multipoint_evaluate scale: '{}'",
+        MULTIPOINT_BENCH_SCALE
+    );
+    c.bench_function(&id, |b| {
+        b.iter(|| {
+            tree.evaluate(&poly).unwrap();
+        })
+    });
+}
+
+pub fn bench_multipoint_interpolate<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+>(
+    c: &mut Criterion,
+) {
+    let n = 1 << MULTIPOINT_BENCH_SCALE;
+    let xs = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let ys = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    // Includes building the tree, as interpolating over a fresh sample set would
+    let id = format!(
+        "bench_multipoint_interpolate scale: '{}'",
+        MULTIPOINT_BENCH_SCALE
+    );
+    c.bench_function(&id, |b| {
+        b.iter(|| {
+            SubproductTree::<TFr, TPoly, TFFTSettings>::new(&xs)
+                .unwrap()
+                .interpolate(&ys)
+                .unwrap();
+        })
+    });
+}
@@ -1,8 +1,11 @@
 use kzg::{
-    msm::precompute::PrecomputationTable, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G2Mul, G1, G2,
+    msm::precompute::{precompute, PrecomputationTable},
+    Fr, G1Affine, G1ConstantTime, G1Fp, G1GetFp, G1LinComb, G1Mul, G2Mul, G1, G2,
 };
 use std::convert::TryInto;
 
+use crate::tests::utils::generate_test_scalars;
+
 pub fn log_2_byte_works(log_2_byte: &dyn Fn(u8) -> usize) {
     assert_eq!(0, log_2_byte(0x01));
     assert_eq!(7, log_2_byte(0x80));
@@ -125,6 +128,24 @@ pub fn p1_mul_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
     assert!(res.equals(&TG1::negative_generator()));
 }
 
+pub fn g1_generator_mul_works<TFr: Fr, TG1: G1 + G1Mul<TFr>>() {
+    let scalars = generate_test_scalars::<TFr>();
+
+    for scalar in &scalars {
+        let expected = TG1::generator().mul(scalar);
+        assert!(TG1::generator_mul(scalar).equals(&expected));
+    }
+}
+
+pub fn g1_mul_ct_works<TFr: Fr, TG1: G1 + G1Mul<TFr> + G1ConstantTime>() {
+    let scalars = generate_test_scalars::<TFr>();
+
+    let point = TG1::rand();
+    for scalar in &scalars {
+        assert!(point.mul_ct(scalar).equals(&point.mul(scalar)));
+    }
+}
+
 pub fn p1_sub_works<TG1: G1>() {
     let tmp = TG1::generator().dbl();
     let res = TG1::generator().sub(&TG1::negative_generator());
@@ -150,6 +171,15 @@ pub fn p2_mul_works<TFr: Fr, TG2: G2 + G2Mul<TFr>>() {
     assert!(res.equals(&TG2::negative_generator()));
 }
 
+pub fn g2_generator_mul_works<TFr: Fr, TG2: G2 + G2Mul<TFr>>() {
+    let scalars = generate_test_scalars::<TFr>();
+
+    for scalar in &scalars {
+        let expected = TG2::generator().mul(scalar);
+        assert!(TG2::generator_mul(scalar).equals(&expected));
+    }
+}
+
 pub fn p2_sub_works<TG2: G2>() {
     let tmp = TG2::generator().dbl();
     let res = TG2::generator().sub(&TG2::negative_generator());
@@ -237,6 +267,65 @@ pub fn g1_random_linear_combination<
     assert!(exp.equals(&res));
 }
 
+/// Blobs are often padded with zeros or hold small integers, which the MSM handles in separate
+/// passes. Check every mix of those with full-width scalars, with and without a precomputed table.
+#[allow(clippy::type_complexity)]
+pub fn g1_sparse_linear_combination<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + Copy,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    g1_linear_combination: &dyn Fn(
+        &mut TG1,
+        &[TG1],
+        &[TFr],
+        usize,
+        Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
+    ),
+) {
+    let len: usize = 1024;
+    let p = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let table = precompute::<TFr, TG1, TG1Fp, TG1Affine>(&p).unwrap();
+
+    let zero = |_: usize| TFr::zero();
+    let small = |i: usize| match i % 3 {
+        0 => TFr::one(),
+        1 => TFr::from_u64(u64::MAX),
+        _ => TFr::from_u64(rand::random::<u64>() >> (i % 64)),
+    };
+    let large = |_: usize| TFr::rand();
+    let mixes: [&dyn Fn(usize) -> TFr; 6] = [
+        &zero,
+        &small,
+        &|i| if i % 2 == 0 { zero(i) } else { small(i) },
+        &|i| if i % 2 == 0 { zero(i) } else { large(i) },
+        &|i| if i < len - 5 { zero(i) } else { large(i) },
+        &|i| match i % 3 {
+            0 => zero(i),
+            1 => small(i),
+            _ => large(i),
+        },
+    ];
+
+    for mix in mixes {
+        let coeffs = (0..len).map(mix).collect::<Vec<_>>();
+
+        let mut exp = TG1::identity();
+        for i in 0..len {
+            exp = exp.add_or_dbl(&p[i].mul(&coeffs[i]));
+        }
+
+        let mut res = TG1::default();
+        g1_linear_combination(&mut res, &p, &coeffs, len, None);
+        assert!(exp.equals(&res));
+
+        let mut res = TG1::default();
+        g1_linear_combination(&mut res, &p, &coeffs, len, table.as_ref());
+        assert!(exp.equals(&res));
+    }
+}
+
 pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     pairings_verify: &dyn Fn(&TG1, &TG2, &TG1, &TG2) -> bool,
 ) {
@@ -254,3 +343,23 @@ pub fn pairings_work<TFr: Fr, TG1: G1 + G1Mul<TFr>, TG2: G2 + G2Mul<TFr>>(
     assert!(pairings_verify(&g1_3, &g2_5, &g1_5, &g2_3));
     assert!(!pairings_verify(&g1_3, &g2_3, &g1_5, &g2_5));
 }
+
+pub fn g1_lincomb_ct_works<
+    TFr: Fr,
+    TG1: G1LinComb<TFr, TG1Fp, TG1Affine> + G1ConstantTime,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let len = 64;
+    let points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let mut scalars = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+    scalars[0] = TFr::zero();
+    scalars[1] = TFr::one().negate();
+
+    let expected = TG1::g1_lincomb(&points, &scalars, len, None);
+    assert!(TG1::g1_lincomb_ct(&points, &scalars, len).equals(&expected));
+
+    // Only the first `len` elements are used
+    let expected = TG1::g1_lincomb(&points[..len / 2], &scalars[..len / 2], len / 2, None);
+    assert!(TG1::g1_lincomb_ct(&points, &scalars, len / 2).equals(&expected));
+}
@@ -2,7 +2,6 @@ use std::{
     ffi::{CStr, CString},
     fs::File,
     io::Read,
-    path::PathBuf,
     ptr::null_mut,
 };
 
@@ -15,22 +14,9 @@ use libc::FILE;
 
 use crate::tests::{
     eip_4844::generate_random_blob_bytes,
-    utils::{get_manifest_dir, get_trusted_setup_path},
+    utils::{get_trusted_setup_fixture_path, get_trusted_setup_path},
 };
 
-fn get_trusted_setup_fixture_path(fixture: &str) -> String {
-    let filename = "trusted_setup_fixture.txt";
-
-    PathBuf::from(get_manifest_dir())
-        .join("src/tests/fixtures")
-        .join(fixture)
-        .join(filename)
-        .as_os_str()
-        .to_str()
-        .unwrap()
-        .to_string()
-}
-
 fn get_ckzg_settings(
     load_trusted_setup_file: unsafe extern "C" fn(
         out: *mut CKZGSettings,
@@ -382,7 +368,11 @@ pub fn load_trusted_setup_file_valid_format_test(
         Fixture {
             name: "valid_short_hex".to_string(),
             message: "Valid format, because first character of hex can be omitted, if it is zero (e.g. 07 -> 7)".to_string()
-        }
+        },
+        Fixture {
+            name: "with_monomial".to_string(),
+            message: "Valid format, because monomial G1 points after the G2 points must be ignored".to_string(),
+        },
     ];
 
     for fixture in fixtures {
@@ -0,0 +1,91 @@
+//! dudect-style timing checks. They are too sensitive to load to run alongside other tests, so
+//! backends mark them `#[ignore]`; run them on an idle machine with
+//! `cargo test --release --test constant_time -- --ignored --test-threads=1`.
+
+use kzg::{Fr, G1Affine, G1ConstantTime, G1Fp, G1LinComb, G1Mul, G1};
+use std::hint::black_box;
+use std::time::Instant;
+
+const MEASUREMENTS: usize = 4000;
+
+/// dudect reports a definite leak once |t| goes over 10
+const T_THRESHOLD: f64 = 10.0;
+
+/// Welch's t-statistic between the timings of the two input classes. Measurements above the
+/// pooled 90th percentile are dropped first, as those are dominated by interrupts and
+/// context switches rather than by the code under test.
+fn welch_t(samples: &[(bool, u128)]) -> f64 {
+    let mut sorted = samples.iter().map(|(_, t)| *t).collect::<Vec<_>>();
+    sorted.sort_unstable();
+    let cutoff = sorted[sorted.len() * 9 / 10];
+
+    let stats = |class: bool| {
+        let xs = samples
+            .iter()
+            .filter(|(c, t)| *c == class && *t <= cutoff)
+            .map(|(_, t)| *t as f64)
+            .collect::<Vec<_>>();
+        let n = xs.len() as f64;
+        let mean = xs.iter().sum::<f64>() / n;
+        let var = xs.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (n, mean, var)
+    };
+
+    let (n0, mean0, var0) = stats(false);
+    let (n1, mean1, var1) = stats(true);
+    (mean0 - mean1) / (var0 / n0 + var1 / n1).sqrt()
+}
+
+/// dudect-style fixed-vs-random test. Time `f` on a fixed input and on random inputs, with the
+/// two classes interleaved at random so that drift in the machine state affects both equally,
+/// and check that the two timing distributions cannot be told apart.
+fn assert_constant_time<T: Clone, R>(fixed: T, random: impl Fn() -> T, f: impl Fn(&T) -> R) {
+    let inputs = (0..MEASUREMENTS)
+        .map(|_| {
+            let class = rand::random::<bool>();
+            (class, if class { random() } else { fixed.clone() })
+        })
+        .collect::<Vec<_>>();
+
+    // Warm up caches and branch predictors
+    for (_, input) in inputs.iter().take(MEASUREMENTS / 10) {
+        black_box(f(input));
+    }
+
+    let samples = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = Instant::now();
+            black_box(f(black_box(input)));
+            (*class, start.elapsed().as_nanos())
+        })
+        .collect::<Vec<_>>();
+
+    let t = welch_t(&samples);
+    assert!(
+        t.abs() < T_THRESHOLD,
+        "Timing depends on the input class, t = {t}"
+    );
+}
+
+/// The fixed class is the zero scalar, which picks the identity from every window table and is
+/// where a variable-time implementation would return early.
+pub fn g1_mul_ct_is_constant_time<TFr: Fr, TG1: G1 + G1Mul<TFr> + G1ConstantTime>() {
+    let point = TG1::rand();
+    assert_constant_time(TFr::zero(), TFr::rand, |scalar| point.mul_ct(scalar));
+}
+
+pub fn g1_lincomb_ct_is_constant_time<
+    TFr: Fr,
+    TG1: G1LinComb<TFr, TG1Fp, TG1Affine> + G1ConstantTime,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let len = 4;
+    let points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+    assert_constant_time(
+        vec![TFr::zero(); len],
+        || (0..len).map(|_| TFr::rand()).collect::<Vec<_>>(),
+        |scalars| TG1::g1_lincomb_ct(&points, scalars, len),
+    );
+}
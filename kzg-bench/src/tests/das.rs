@@ -1,3 +1,4 @@
+use kzg::common_utils::reverse_bit_order;
 use kzg::{FFTFr, FFTSettings, Fr, DAS};
 
 /// Check if DAS FFT creates odds that match precomputed values
@@ -67,3 +68,47 @@ pub fn das_extension_test_random<
         }
     }
 }
+
+/// Check that extension by `k` starts with the data in bit-reversed order, agrees with the
+/// doubling extension for `k = 2`, and is accepted by the verifier only while uncorrupted.
+pub fn das_extension_k_test_random<
+    TFr: Fr,
+    TFFTSettings: FFTSettings<TFr> + DAS<TFr> + FFTFr<TFr>,
+>() {
+    let fft_settings = TFFTSettings::new(8).unwrap();
+    let width = 16;
+
+    for k in [1, 2, 3, 4, 6, 8, 12] {
+        let data = (0..width).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let mut samples = fft_settings.das_fft_extension_k(&data, k).unwrap();
+        assert_eq!(samples.len(), k * width);
+
+        let mut systematic = data.clone();
+        reverse_bit_order(&mut systematic).unwrap();
+        assert!(samples[..width] == systematic[..]);
+
+        if k == 2 {
+            let odds = fft_settings.das_fft_extension(&data).unwrap();
+            let mut natural = data
+                .iter()
+                .zip(&odds)
+                .flat_map(|(even, odd)| [even.clone(), odd.clone()])
+                .collect::<Vec<_>>();
+            reverse_bit_order(&mut natural).unwrap();
+            assert!(samples == natural);
+        }
+
+        assert!(fft_settings.das_verify_extension_k(&samples, k).unwrap());
+
+        if k > 1 {
+            samples[width + 1] = samples[width + 1].add(&TFr::one());
+            assert!(!fft_settings.das_verify_extension_k(&samples, k).unwrap());
+        }
+    }
+
+    let data = (0..width).map(|_| TFr::rand()).collect::<Vec<_>>();
+    assert!(fft_settings.das_fft_extension_k(&data, 5).is_err());
+    assert!(fft_settings.das_fft_extension_k(&data[..12], 2).is_err());
+    assert!(fft_settings.das_verify_extension_k(&data[..15], 2).is_err());
+    assert!(fft_settings.das_verify_extension_k(&data, 0).is_err());
+}
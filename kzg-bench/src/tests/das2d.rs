@@ -0,0 +1,127 @@
+use kzg::das2d::{extend_2d, extend_commitments, recover_2d, row_commitment_at};
+use kzg::{
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, Poly,
+    PolyRecover, FFTG1, G1, G2,
+};
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+
+use crate::tests::kzg_proofs::SECRET;
+
+const ROWS: usize = 4;
+const COLUMNS: usize = 8;
+
+fn random_matrix<TFr: Fr>(rows: usize, columns: usize) -> Vec<Vec<TFr>> {
+    (0..rows)
+        .map(|_| (0..columns).map(|_| TFr::rand()).collect())
+        .collect()
+}
+
+/// Check that every row and column of the extended matrix is a codeword of rate 1/2 containing
+/// the original values, and that the matrix is recovered from any 75% of it but not from a
+/// missing `(R + 1) x (C + 1)` block.
+pub fn das_2d_extend_and_recover<
+    TFr: Fr,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFFTSettings>,
+>() {
+    let fs = TFFTSettings::new(4).unwrap();
+    let rows = random_matrix::<TFr>(ROWS, COLUMNS);
+    let extended = extend_2d(&fs, &rows).unwrap();
+
+    assert_eq!(extended.len(), 2 * ROWS);
+    for (r, row) in rows.iter().enumerate() {
+        for (c, value) in row.iter().enumerate() {
+            assert!(extended[2 * r][2 * c].equals(value));
+        }
+    }
+
+    for row in &extended {
+        assert_eq!(row.len(), 2 * COLUMNS);
+        let coeffs = fs.fft_fr(row, true).unwrap();
+        assert!(coeffs[COLUMNS..].iter().all(Fr::is_zero));
+    }
+    for c in 0..2 * COLUMNS {
+        let column = extended
+            .iter()
+            .map(|row| row[c].clone())
+            .collect::<Vec<_>>();
+        let coeffs = fs.fft_fr(&column, true).unwrap();
+        assert!(coeffs[ROWS..].iter().all(Fr::is_zero));
+    }
+
+    let total = 4 * ROWS * COLUMNS;
+    let mut indices = (0..total).collect::<Vec<_>>();
+    for _ in 0..8 {
+        indices.shuffle(&mut thread_rng());
+        let mut samples = extended
+            .iter()
+            .map(|row| row.iter().cloned().map(Some).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for &i in &indices[..total / 4] {
+            samples[i / (2 * COLUMNS)][i % (2 * COLUMNS)] = None;
+        }
+
+        let recovered = recover_2d::<TFr, TPoly, TFFTSettings, TPolyRecover>(&fs, &samples);
+        assert!(recovered.unwrap() == extended);
+    }
+
+    let samples = extended
+        .iter()
+        .enumerate()
+        .map(|(r, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(c, value)| (r > ROWS || c > COLUMNS).then(|| value.clone()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let recovered = recover_2d::<TFr, TPoly, TFFTSettings, TPolyRecover>(&fs, &samples);
+    assert!(matches!(recovered, Err(err) if err.starts_with("Unrecoverable")));
+
+    assert!(extend_2d(&fs, &random_matrix::<TFr>(3, COLUMNS)).is_err());
+    assert!(extend_2d(&fs, &random_matrix::<TFr>(ROWS, 16)).is_err());
+    assert!(extend_2d::<TFr, TFFTSettings>(&fs, &[]).is_err());
+}
+
+/// Check that the extended commitments, and the commitment to a single extended row, commit to
+/// the rows of the extended matrix.
+pub fn das_2d_commitments<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let fs = TFFTSettings::new(4).unwrap();
+    let (s1, s2) = generate_trusted_setup(COLUMNS + 1, SECRET);
+    let ks = TKZGSettings::new(&s1, &s2, COLUMNS + 1, &fs).unwrap();
+
+    let commit = |values: &[TFr]| {
+        let coeffs = fs.fft_fr(values, true).unwrap();
+        ks.commit_to_poly(&TPoly::from_coeffs(&coeffs[..COLUMNS]))
+            .unwrap()
+    };
+
+    let rows = random_matrix::<TFr>(ROWS, COLUMNS);
+    let extended = extend_2d(&fs, &rows).unwrap();
+    let commitments = rows.iter().map(|row| commit(row)).collect::<Vec<_>>();
+
+    let extended_commitments = extend_commitments(&fs, &commitments).unwrap();
+    assert_eq!(extended_commitments.len(), 2 * ROWS);
+
+    let stride = fs.get_max_width() / (2 * ROWS);
+    for (r, (row, commitment)) in extended.iter().zip(&extended_commitments).enumerate() {
+        assert!(commit(row).equals(commitment));
+
+        let x = fs.get_expanded_roots_of_unity_at(r * stride);
+        let single = row_commitment_at(&fs, &commitments, &x).unwrap();
+        assert!(single.equals(commitment));
+    }
+}
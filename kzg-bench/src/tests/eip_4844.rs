@@ -4,12 +4,24 @@ use crate::test_vectors::{
     blob_to_kzg_commitment, compute_blob_kzg_proof, compute_kzg_proof, verify_blob_kzg_proof,
     verify_blob_kzg_proof_batch, verify_kzg_proof,
 };
-use crate::tests::utils::{get_manifest_dir, get_trusted_setup_path};
+use crate::tests::utils::{
+    get_manifest_dir, get_trusted_setup_fixture_path, get_trusted_setup_path,
+};
+use kzg::common_utils::reverse_bit_order;
 use kzg::eip_4844::{
-    BYTES_PER_BLOB, BYTES_PER_COMMITMENT, BYTES_PER_FIELD_ELEMENT, BYTES_PER_PROOF,
-    FIELD_ELEMENTS_PER_BLOB, TRUSTED_SETUP_PATH,
+    blob_to_kzg_commitment_rust, compute_all_kzg_proofs_for_blob_rust, compute_blob_kzg_proof_rust,
+    compute_kzg_proof_rust, hash, hash_to_bls_field, load_trusted_setup_string,
+    load_trusted_setup_string_with_monomial, load_trusted_setup_with_monomial_rust,
+    new_fk20_settings_for_blob_rust, verify_blob_kzg_proof_with_key_rust, verify_kzg_proof_rust,
+    verify_kzg_proof_with_key_rust, VerifyingKey, BYTES_PER_BLOB, BYTES_PER_COMMITMENT,
+    BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_PROOF, FIELD_ELEMENTS_PER_BLOB,
+    TRUSTED_SETUP_PATH,
+};
+use kzg::transcript::{Sha256Transcript, Transcript};
+use kzg::{
+    FFTFr, FFTSettings, FK20SingleSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul,
+    KZGSettings, PairingVerify, Poly, FFTG1, G1, G2,
 };
-use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
 use pathdiff::diff_paths;
 use rand::rngs::{OsRng, ThreadRng};
 use rand::Rng;
@@ -79,6 +91,83 @@ pub fn compute_powers_test<TFr: Fr>(compute_powers: &dyn Fn(&TFr, usize) -> Vec<
     }
 }
 
+pub fn transcript_test<TFr: Fr, TG1: G1>() {
+    let domain = b"TRANSCRIPT_TEST_";
+    let commitment = TG1::rand();
+    let scalar = TFr::rand();
+
+    let frame = |bytes: &mut Vec<u8>, message: &[u8]| {
+        bytes.extend_from_slice(&(message.len() as u64).to_be_bytes());
+        bytes.extend_from_slice(message);
+    };
+
+    let mut transcript = Sha256Transcript::new(domain);
+    transcript.append_u64(b"count", 0x0102);
+    transcript.append_commitment(b"commitment", &commitment);
+    transcript.append_scalar(b"scalar", &scalar);
+
+    // Domain, labels and messages all go in preceded by their length
+    let mut bytes = Vec::new();
+    frame(&mut bytes, domain);
+    frame(&mut bytes, b"count");
+    frame(&mut bytes, &[0, 0, 0, 0, 0, 0, 1, 2]);
+    frame(&mut bytes, b"commitment");
+    frame(&mut bytes, &commitment.to_bytes());
+    frame(&mut bytes, b"scalar");
+    frame(&mut bytes, &scalar.to_bytes());
+    assert_eq!(transcript.bytes(), &bytes[..]);
+
+    let challenge: TFr = transcript.challenge_scalar(b"challenge");
+    frame(&mut bytes, b"challenge");
+    frame(&mut bytes, &[]);
+    assert!(challenge.equals(&hash_to_bls_field(&hash(&bytes))));
+
+    // The next challenge binds the previous one, and differs from it
+    let mut bytes = Vec::new();
+    frame(&mut bytes, domain);
+    frame(&mut bytes, b"challenge");
+    frame(&mut bytes, &challenge.to_bytes());
+    assert_eq!(transcript.bytes(), &bytes[..]);
+    let next: TFr = transcript.challenge_scalar(b"challenge");
+    assert!(!next.equals(&challenge));
+
+    // Challenges depend on the labels, and on where messages split
+    let challenge_of = |messages: &[(&'static [u8], &[u8])], label: &'static [u8]| {
+        let mut transcript = Sha256Transcript::new(domain);
+        for (label, message) in messages {
+            transcript.append_bytes(label, message);
+        }
+        transcript.challenge_scalar::<TFr>(label)
+    };
+    let challenge = challenge_of(&[(b"a", b"xy"), (b"b", b"z")], b"c");
+    assert!(challenge.equals(&challenge_of(&[(b"a", b"xy"), (b"b", b"z")], b"c")));
+    assert!(!challenge.equals(&challenge_of(&[(b"a", b"x"), (b"b", b"yz")], b"c")));
+    assert!(!challenge.equals(&challenge_of(&[(b"b", b"xy"), (b"b", b"z")], b"c")));
+    assert!(!challenge.equals(&challenge_of(&[(b"a", b"xy"), (b"b", b"z")], b"d")));
+
+    // Legacy transcripts concatenate the messages, labels left out
+    let mut transcript = Sha256Transcript::legacy(domain, 0);
+    transcript.append_u64(b"count", 0x0102);
+    transcript.append_commitment(b"commitment", &commitment);
+    transcript.append_scalar(b"scalar", &scalar);
+
+    let mut bytes = domain.to_vec();
+    bytes.extend_from_slice(&[0, 0, 0, 0, 0, 0, 1, 2]);
+    bytes.extend_from_slice(&commitment.to_bytes());
+    bytes.extend_from_slice(&scalar.to_bytes());
+    assert_eq!(transcript.bytes(), &bytes[..]);
+
+    let challenge: TFr = transcript.challenge_scalar(b"challenge");
+    assert!(challenge.equals(&hash_to_bls_field(&hash(&bytes))));
+
+    let mut bytes = domain.to_vec();
+    bytes.extend_from_slice(&challenge.to_bytes());
+    assert_eq!(transcript.bytes(), &bytes[..]);
+    let next: TFr = transcript.challenge_scalar(b"challenge");
+    assert!(next.equals(&hash_to_bls_field(&hash(&bytes))));
+    assert!(!next.equals(&challenge));
+}
+
 #[allow(clippy::type_complexity)]
 pub fn blob_to_kzg_commitment_test<
     TFr: Fr + Copy,
@@ -373,6 +462,331 @@ pub fn compute_and_verify_blob_kzg_proof_test<
     assert!(result);
 }
 
+#[allow(clippy::type_complexity)]
+pub fn compute_all_kzg_proofs_for_blob_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20SingleSettings: FK20SingleSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    let mut rng = rand::thread_rng();
+
+    let fk = new_fk20_settings_for_blob_rust::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TFK20SingleSettings,
+        TG1Fp,
+        TG1Affine,
+    >(&ts)
+    .unwrap();
+
+    // The same FK20 settings serve every blob
+    let fs = ts.get_fft_settings();
+    for _ in 0..2 {
+        let blob = {
+            let blob_bytes = generate_random_blob_bytes(&mut rng);
+            bytes_to_blob(&blob_bytes).unwrap()
+        };
+        let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+
+        let proofs = compute_all_kzg_proofs_for_blob_rust(&blob, &ts, &fk).unwrap();
+        assert_eq!(proofs.len(), FIELD_ELEMENTS_PER_BLOB);
+
+        // Opening every point one at a time takes too long, so check a few of them
+        for _ in 0..8 {
+            let i = rng.gen_range(0..FIELD_ELEMENTS_PER_BLOB);
+            let z = fs.get_roots_of_unity_at(i);
+
+            let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+            assert!(y.equals(&blob[i]));
+            assert!(proofs[i].equals(&proof));
+            assert!(verify_kzg_proof_rust(&commitment, &z, &blob[i], &proofs[i], &ts).unwrap());
+        }
+    }
+
+    let short_blob = [TFr::zero(); FIELD_ELEMENTS_PER_BLOB - 1];
+    assert!(compute_all_kzg_proofs_for_blob_rust(&short_blob, &ts, &fk).is_err());
+}
+
+#[allow(clippy::type_complexity)]
+pub fn commit_to_poly_monomial_and_lagrange_test<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+    assert_eq!(ts.get_g1_monomial().len(), FIELD_ELEMENTS_PER_BLOB);
+    assert_eq!(ts.get_g1_lagrange_brp().len(), FIELD_ELEMENTS_PER_BLOB);
+
+    let mut rng = rand::thread_rng();
+    let blob = {
+        let blob_bytes = generate_random_blob_bytes(&mut rng);
+        bytes_to_blob(&blob_bytes).unwrap()
+    };
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+
+    let mut evals = blob.clone();
+    reverse_bit_order(&mut evals).unwrap();
+    let poly = TPoly::from_coeffs(&ts.get_fft_settings().fft_fr(&evals, true).unwrap());
+
+    // The same polynomial, committed to from its evaluations and from its coefficients
+    assert!(ts
+        .commit_to_poly_lagrange(&blob)
+        .unwrap()
+        .equals(&commitment));
+    assert!(ts
+        .commit_to_poly_monomial(&poly)
+        .unwrap()
+        .equals(&commitment));
+    assert!(ts.commit_to_poly_lagrange(&blob[1..]).is_err());
+
+    // Proofs from the generic API open the EIP-4844 commitment
+    let z = TFr::rand();
+    let proof = ts.compute_proof_single(&poly, &z).unwrap();
+    assert!(verify_kzg_proof_rust(&commitment, &z, &poly.eval(&z), &proof, &ts).unwrap());
+
+    // Monomial points given in the file are those derived from the Lagrange-form ones
+    let contents = fs::read_to_string(get_trusted_setup_fixture_path("with_monomial")).unwrap();
+    let (g1_bytes, g2_bytes, g1_monomial_bytes) =
+        load_trusted_setup_string_with_monomial(&contents).unwrap();
+    assert_eq!(g1_monomial_bytes.len(), g1_bytes.len());
+    assert_eq!(
+        load_trusted_setup_string(&contents).unwrap(),
+        (g1_bytes.clone(), g2_bytes.clone())
+    );
+
+    let given: TKZGSettings =
+        load_trusted_setup_with_monomial_rust(&g1_bytes, &g2_bytes, &g1_monomial_bytes).unwrap();
+    assert_eq!(given.get_g1_monomial().len(), ts.get_g1_monomial().len());
+    assert!(given
+        .get_g1_monomial()
+        .iter()
+        .zip(ts.get_g1_monomial())
+        .all(|(a, b)| a.equals(b)));
+
+    // Monomial points not matching the Lagrange-form ones are rejected
+    let mut swapped = g1_monomial_bytes.clone();
+    let (head, tail) = swapped.split_at_mut(2 * BYTES_PER_G1);
+    head[BYTES_PER_G1..].swap_with_slice(&mut tail[..BYTES_PER_G1]);
+    for g1_monomial_bytes in [&g1_bytes, &swapped] {
+        assert!(load_trusted_setup_with_monomial_rust::<
+            TFr,
+            TG1,
+            TG2,
+            TFFTSettings,
+            TPoly,
+            TKZGSettings,
+            TG1Fp,
+            TG1Affine,
+        >(&g1_bytes, &g2_bytes, g1_monomial_bytes)
+        .is_err());
+    }
+
+    // Settings from monomial points alone can't commit to evaluations
+    let monomial_only = TKZGSettings::new(
+        ts.get_g1_monomial(),
+        ts.get_g2_secret(),
+        FIELD_ELEMENTS_PER_BLOB,
+        ts.get_fft_settings(),
+    )
+    .unwrap();
+    assert!(monomial_only.get_g1_lagrange_brp().is_empty());
+    assert!(monomial_only.commit_to_poly_lagrange(&blob).is_err());
+    assert!(monomial_only
+        .commit_to_poly(&poly)
+        .unwrap()
+        .equals(&commitment));
+}
+
+#[allow(clippy::type_complexity)]
+pub fn verifying_key_test<
+    TFr: Fr + Copy,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    load_trusted_setup: &dyn Fn(&str) -> Result<TKZGSettings, String>,
+    bytes_to_blob: &dyn Fn(&[u8]) -> Result<Vec<TFr>, String>,
+) {
+    let ts = load_trusted_setup(get_trusted_setup_path().as_str()).unwrap();
+
+    // The embedded constants are those of the setup file
+    let vk = VerifyingKey::<TFr, TG1, TG2>::embedded().unwrap();
+    let from_settings = VerifyingKey::from_settings(&ts).unwrap();
+    assert!(vk.kzg.g1.equals(&from_settings.kzg.g1));
+    assert!(vk.kzg.g2.equals(&from_settings.kzg.g2));
+    assert!(vk.kzg.tau_g2.equals(&from_settings.kzg.tau_g2));
+    assert!(vk.root_of_unity.equals(&from_settings.root_of_unity));
+    assert!(vk
+        .roots_of_unity()
+        .unwrap()
+        .iter()
+        .zip(ts.get_fft_settings().get_roots_of_unity())
+        .all(|(a, b)| a.equals(b)));
+
+    // The domain is computed once
+    let roots = vk.roots_of_unity().unwrap();
+    assert!(core::ptr::eq(
+        roots.as_ref(),
+        vk.roots_of_unity().unwrap().as_ref()
+    ));
+
+    // Roots of unity of a smaller domain are rejected
+    assert!(VerifyingKey::<TFr, TG1, TG2>::new(vk.kzg.clone(), vk.root_of_unity.sqr()).is_err());
+
+    let mut rng = rand::thread_rng();
+    let blob = {
+        let blob_bytes = generate_random_blob_bytes(&mut rng);
+        bytes_to_blob(&blob_bytes).unwrap()
+    };
+    let commitment = blob_to_kzg_commitment_rust(&blob, &ts).unwrap();
+
+    // Blob proofs made with the settings verify with the key alone
+    let proof = compute_blob_kzg_proof_rust(&blob, &commitment, &ts).unwrap();
+    assert!(verify_blob_kzg_proof_with_key_rust(&blob, &commitment, &proof, &vk).unwrap());
+    let wrong_proof = proof.add(&TG1::generator());
+    assert!(!verify_blob_kzg_proof_with_key_rust(&blob, &commitment, &wrong_proof, &vk).unwrap());
+    assert!(verify_blob_kzg_proof_with_key_rust(&blob[1..], &commitment, &proof, &vk).is_err());
+
+    // Single point proofs verify the same as with the settings
+    let z = TFr::rand();
+    let (proof, y) = compute_kzg_proof_rust(&blob, &z, &ts).unwrap();
+    assert!(verify_kzg_proof_with_key_rust(&commitment, &z, &y, &proof, &vk).unwrap());
+    let wrong_y = y.add(&TFr::one());
+    assert!(!verify_kzg_proof_with_key_rust(&commitment, &z, &wrong_y, &proof, &vk).unwrap());
+    assert!(!verify_kzg_proof_rust(&commitment, &z, &wrong_y, &proof, &ts).unwrap());
+}
+
+/// The verification test vectors, checked with [`VerifyingKey::embedded`] alone, which is all a
+/// backend built without its `prover` feature has.
+pub fn test_vectors_verify_with_embedded_key<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+>() {
+    let vk = VerifyingKey::<TFr, TG1, TG2>::embedded().unwrap();
+
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        VERIFY_KZG_PROOF_TESTS
+    ))
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: verify_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let input = (
+            match TG1::from_bytes(&test.input.get_commitment_bytes()) {
+                Ok(commitment) => commitment,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+            match TFr::from_bytes(&test.input.get_z_bytes()) {
+                Ok(z) => z,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+            match TFr::from_bytes(&test.input.get_y_bytes()) {
+                Ok(y) => y,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+            match TG1::from_bytes(&test.input.get_proof_bytes()) {
+                Ok(proof) => proof,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+        );
+
+        match verify_kzg_proof_with_key_rust(&input.0, &input.1, &input.2, &input.3, &vk) {
+            Ok(result) => assert_eq!(result, test.get_output().unwrap()),
+            Err(_) => assert!(test.get_output().is_none()),
+        };
+    }
+
+    let test_files: Vec<PathBuf> = glob::glob(&format!(
+        "{}/{}",
+        get_manifest_dir(),
+        VERIFY_BLOB_KZG_PROOF_TESTS
+    ))
+    .unwrap()
+    .map(Result::unwrap)
+    .collect();
+    assert!(!test_files.is_empty());
+
+    for test_file in test_files {
+        let yaml_data = fs::read_to_string(test_file).unwrap();
+        let test: verify_blob_kzg_proof::Test = serde_yaml::from_str(&yaml_data).unwrap();
+
+        let input = (
+            match kzg::eip_4844::bytes_to_blob::<TFr>(&test.input.get_blob_bytes()) {
+                Ok(blob) => blob,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+            match TG1::from_bytes(&test.input.get_commitment_bytes()) {
+                Ok(commitment) => commitment,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+            match TG1::from_bytes(&test.input.get_proof_bytes()) {
+                Ok(proof) => proof,
+                Err(_) => {
+                    assert!(test.get_output().is_none());
+                    continue;
+                }
+            },
+        );
+
+        match verify_blob_kzg_proof_with_key_rust(&input.0, &input.1, &input.2, &vk) {
+            Ok(result) => assert_eq!(result, test.get_output().unwrap()),
+            Err(_) => assert!(test.get_output().is_none()),
+        };
+    }
+}
+
 #[allow(clippy::type_complexity)]
 pub fn compute_and_verify_blob_kzg_proof_fails_with_incorrect_proof_test<
     TFr: Fr,
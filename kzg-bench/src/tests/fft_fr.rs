@@ -1,3 +1,5 @@
+use kzg::common_utils::reverse_bit_order;
+use kzg::fft::{fft_fr_dif, fft_fr_dit, rs_extension};
 use kzg::{FFTFr, FFTSettings, Fr};
 
 /// Check that both FFT implementations produce the same results
@@ -116,3 +118,161 @@ pub fn stride_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
         assert!(r1.equals(&result2[i]));
     }
 }
+
+/// Check that the in-place FFT matches `fft_fr` in both directions, at every width
+pub fn inplace_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(12).unwrap();
+
+    for scale in 0..=12 {
+        let data = (0..1 << scale).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+        for inverse in [false, true] {
+            let expected = fft_settings.fft_fr(&data, inverse).unwrap();
+            let mut result = data.clone();
+            fft_settings.fft_fr_inplace(&mut result, inverse).unwrap();
+
+            for (e, r) in expected.iter().zip(&result) {
+                assert!(e.equals(r));
+            }
+        }
+    }
+
+    let mut not_pow2 = vec![TFr::one(); 3];
+    assert!(fft_settings.fft_fr_inplace(&mut not_pow2, false).is_err());
+    let mut too_long = vec![TFr::one(); 2 * fft_settings.get_max_width()];
+    assert!(fft_settings.fft_fr_inplace(&mut too_long, false).is_err());
+}
+
+/// Check that the DIF transform gives the FFT in bit-reversed order, and that the DIT transform
+/// undoes it without any permutation in between
+pub fn dif_dit_roundtrip<TFr: Fr + Send, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(10).unwrap();
+    let width = 256;
+    let stride = fft_settings.get_max_width() / width;
+
+    let data = (0..width).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let mut expected = fft_settings.fft_fr(&data, false).unwrap();
+    reverse_bit_order(&mut expected).unwrap();
+
+    let mut result = data.clone();
+    fft_fr_dif(
+        &mut result,
+        fft_settings.get_expanded_roots_of_unity(),
+        stride,
+    );
+    for (e, r) in expected.iter().zip(&result) {
+        assert!(e.equals(r));
+    }
+
+    fft_fr_dit(
+        &mut result,
+        fft_settings.get_reversed_roots_of_unity(),
+        stride,
+    );
+    let inv_width = TFr::from_u64(width as u64).inverse();
+    for (d, r) in data.iter().zip(&result) {
+        assert!(d.equals(&r.mul(&inv_width)));
+    }
+}
+
+/// Check that the coset FFT evaluates over `shift * w^i`, that its inverse interpolates back, and
+/// that a zero shift is rejected
+pub fn coset_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(6).unwrap();
+    let width = 16;
+    let stride = fft_settings.get_max_width() / width;
+
+    let coeffs = (0..width).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let shift = TFr::rand();
+
+    let evals = fft_settings.coset_fft_fr(&coeffs, &shift, false).unwrap();
+    for (i, eval) in evals.iter().enumerate() {
+        let x = shift.mul(&fft_settings.get_expanded_roots_of_unity_at(i * stride));
+        let expected = coeffs
+            .iter()
+            .rev()
+            .fold(TFr::zero(), |acc, coeff| acc.mul(&x).add(coeff));
+        assert!(eval.equals(&expected));
+    }
+
+    // The second time round, the shift powers come from the settings' cache
+    for _ in 0..2 {
+        let interpolated = fft_settings.coset_fft_fr(&evals, &shift, true).unwrap();
+        for (c, i) in coeffs.iter().zip(&interpolated) {
+            assert!(c.equals(i));
+        }
+    }
+
+    assert!(fft_settings
+        .coset_fft_fr(&coeffs, &TFr::zero(), false)
+        .is_err());
+}
+
+/// Transforms over domains of size 3 * 2^k must match a direct evaluation over the domain and
+/// invert back to the input
+pub fn mixed_radix_fft<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(5).unwrap();
+
+    for size in [3, 6, 48, 96, 32] {
+        let domain = fft_settings.get_domain(size).unwrap();
+        assert_eq!(domain.len(), size);
+        // The domain is a group of exactly this order
+        assert!(domain[1].pow(size).is_one());
+        if size % 3 == 0 {
+            assert!(!domain[1].pow(size / 3).is_one());
+        }
+        assert!(!domain[1].pow(size / 2).is_one());
+
+        let data = (0..size).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let evals = fft_settings.fft_fr_mixed(&data, false).unwrap();
+        for (point, eval) in domain.iter().zip(&evals) {
+            let expected = data
+                .iter()
+                .rev()
+                .fold(TFr::zero(), |acc, coeff| acc.mul(point).add(coeff));
+            assert!(eval.equals(&expected));
+        }
+
+        let back = fft_settings.fft_fr_mixed(&evals, true).unwrap();
+        for (a, b) in data.iter().zip(&back) {
+            assert!(a.equals(b));
+        }
+    }
+
+    // Power-of-two subgroups of a mixed domain are the settings' own domains
+    let mixed = fft_settings.get_domain(96).unwrap();
+    let own = fft_settings.get_domain(32).unwrap();
+    for (i, point) in own.iter().enumerate() {
+        assert!(point.equals(&mixed[3 * i]));
+    }
+
+    assert!(fft_settings
+        .fft_fr_mixed(&(0..5).map(|_| TFr::one()).collect::<Vec<_>>(), false)
+        .is_err());
+    assert!(fft_settings.get_domain(192).is_err());
+    assert!(fft_settings.get_domain(64).is_err());
+}
+
+pub fn rs_extension_by_factor<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>() {
+    let fft_settings = TFFTSettings::new(6).unwrap();
+    let n = 16;
+
+    for factor in [1, 2, 3, 4, 6, 12] {
+        let data = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let extended = rs_extension(&fft_settings, &data, factor).unwrap();
+        assert_eq!(extended.len(), factor * n);
+
+        for (i, value) in data.iter().enumerate() {
+            assert!(extended[factor * i].equals(value));
+        }
+
+        // The extension is still a polynomial of degree below n
+        let coeffs = fft_settings.fft_fr_mixed(&extended, true).unwrap();
+        assert!(coeffs[n..].iter().all(|coeff| coeff.is_zero()));
+    }
+
+    let data = vec![TFr::one(); n];
+    assert!(rs_extension(&fft_settings, &data, 5).is_err());
+    assert!(rs_extension(&fft_settings, &data, 0).is_err());
+}
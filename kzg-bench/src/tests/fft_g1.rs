@@ -1,4 +1,5 @@
-use kzg::{FFTFr, FFTSettings, Fr, FFTG1, G1};
+use kzg::fft::{fft_g1_dif, fft_g1_dit};
+use kzg::{FFTFr, FFTSettings, Fr, G1Mul, FFTG1, G1};
 
 #[allow(clippy::type_complexity)]
 pub fn compare_ft_fft<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
@@ -120,3 +121,70 @@ pub fn compare_sft_fft<TFr: Fr, TG1: G1, TFFTSettings: FFTSettings<TFr> + FFTFr<
         assert!(slow[i].equals(&fast[i]));
     }
 }
+
+/// Check the in-place FFT against `fft_g1` in both directions, and the DIF/DIT pair against
+/// each other
+pub fn inplace_fft<TFr: Fr, TG1: G1 + G1Mul<TFr>, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    make_data: &dyn Fn(usize) -> Vec<TG1>,
+) {
+    let fs = TFFTSettings::new(6).unwrap();
+
+    for scale in 0..=6 {
+        let data = make_data(1 << scale);
+
+        for inverse in [false, true] {
+            let expected = fs.fft_g1(&data, inverse).unwrap();
+            let mut result = data.clone();
+            fs.fft_g1_inplace(&mut result, inverse).unwrap();
+
+            for (e, r) in expected.iter().zip(&result) {
+                assert!(e.equals(r));
+            }
+        }
+    }
+
+    let width = 32;
+    let stride = fs.get_max_width() / width;
+    let data = make_data(width);
+
+    let mut result = data.clone();
+    fft_g1_dif(&mut result, fs.get_expanded_roots_of_unity(), stride);
+    fft_g1_dit(&mut result, fs.get_reversed_roots_of_unity(), stride);
+
+    let width_fr = TFr::from_u64(width as u64);
+    for (d, r) in data.iter().zip(&result) {
+        assert!(d.mul(&width_fr).equals(r));
+    }
+}
+
+/// Check the coset FFT against the plain one over shifted points, and its inverse against the
+/// original data
+pub fn coset_fft<TFr: Fr, TG1: G1 + G1Mul<TFr>, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    make_data: &dyn Fn(usize) -> Vec<TG1>,
+) {
+    let fs = TFFTSettings::new(6).unwrap();
+    let width = 16;
+    let data = make_data(width);
+    let shift = TFr::rand();
+
+    let mut shift_power = TFr::one();
+    let shifted = data
+        .iter()
+        .map(|point| {
+            let shifted = point.mul(&shift_power);
+            shift_power = shift_power.mul(&shift);
+            shifted
+        })
+        .collect::<Vec<_>>();
+
+    let expected = fs.fft_g1(&shifted, false).unwrap();
+    let evals = fs.coset_fft_g1(&data, &shift, false).unwrap();
+    for (e, r) in expected.iter().zip(&evals) {
+        assert!(e.equals(r));
+    }
+
+    let interpolated = fs.coset_fft_g1(&evals, &shift, true).unwrap();
+    for (d, i) in data.iter().zip(&interpolated) {
+        assert!(d.equals(i));
+    }
+}
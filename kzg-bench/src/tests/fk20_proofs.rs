@@ -1,7 +1,9 @@
+use crate::tests::kzg_proofs::commitment_scheme_round_trip;
+use kzg::pcs::{FK20Multi, FK20MultiProverKey, PolynomialCommitmentScheme};
 use kzg::{
     common_utils::{is_power_of_two, log2_pow2, reverse_bit_order, reverse_bits_limited},
-    FFTFr, FFTSettings, FK20MultiSettings, FK20SingleSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul,
-    KZGSettings, Poly, G1, G2,
+    FFTFr, FFTSettings, FK20MultiSettings, FK20SingleSettings, Fr, G1Affine, G1Fp, G1GetFp,
+    G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly, G1, G2,
 };
 
 pub const SECRET: [u8; 32usize] = [
@@ -320,3 +322,211 @@ pub fn fk_multi_chunk_len_16_16<
         TG1Affine,
     >(16, 16, &generate_trusted_setup);
 }
+
+/// The fixed-base table parameters of the single settings only trade memory for speed, the proofs
+/// stay the same. `with_params` is the backend's constructor taking `(ks, n2, window,
+/// max_table_size)`.
+#[allow(clippy::type_complexity)]
+pub fn fk_single_with_params<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20SingleSettings: FK20SingleSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+    with_params: &dyn Fn(
+        &TKZGSettings,
+        usize,
+        Option<usize>,
+        Option<usize>,
+    ) -> Result<TFK20SingleSettings, String>,
+) {
+    let n = 64;
+
+    let (s1, s2) = generate_trusted_setup(2 * n, SECRET);
+    let fs = TFFTSettings::new(log2_pow2(2 * n)).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 2 * n, &fs).unwrap();
+
+    let mut p = TPoly::new(n);
+    for i in 0..n {
+        p.set_coeff_at(i, &TFr::rand());
+    }
+
+    let expected = TFK20SingleSettings::new(&ks, 2 * n)
+        .unwrap()
+        .data_availability(&p)
+        .unwrap();
+
+    // A single point is stored as two in the table, and 32 precomputed points per base allow
+    // windows of 8 bits and up
+    for (window, max_table_size) in [
+        (Some(2), None),
+        (Some(9), None),
+        (None, Some(2 * n * 2 * 32)),
+    ] {
+        let fk = with_params(&ks, 2 * n, window, max_table_size).unwrap();
+        let proofs = fk.data_availability(&p).unwrap();
+
+        assert_eq!(proofs.len(), expected.len());
+        for (proof, expected) in proofs.iter().zip(&expected) {
+            assert!(proof.equals(expected));
+        }
+    }
+
+    // Too small a budget to hold a single point per base
+    assert!(with_params(&ks, 2 * n, None, Some(2 * n)).is_err());
+}
+
+/// The fixed-base table parameters of the multi settings only trade memory for speed, the proofs
+/// stay the same. `with_params` is the backend's constructor taking `(ks, n2, chunk_len, window,
+/// max_table_size)`.
+#[allow(clippy::type_complexity)]
+pub fn fk_multi_with_params<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+    with_params: &dyn Fn(
+        &TKZGSettings,
+        usize,
+        usize,
+        Option<usize>,
+        Option<usize>,
+    ) -> Result<TFK20MultiSettings, String>,
+) {
+    let n = 64;
+    let chunk_len = 4;
+    let tables = 2 * n / chunk_len;
+
+    let (s1, s2) = generate_trusted_setup(2 * n, SECRET);
+    let fs = TFFTSettings::new(log2_pow2(2 * n)).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 2 * n, &fs).unwrap();
+
+    let mut p = TPoly::new(n);
+    for i in 0..n {
+        p.set_coeff_at(i, &TFr::rand());
+    }
+
+    let expected = TFK20MultiSettings::new(&ks, 2 * n, chunk_len)
+        .unwrap()
+        .data_availability(&p)
+        .unwrap();
+
+    // 32 precomputed points per base allow windows of 8 bits and up
+    for (window, max_table_size) in [
+        (Some(2), None),
+        (Some(9), None),
+        (None, Some(tables * chunk_len * 32)),
+    ] {
+        let fk = with_params(&ks, 2 * n, chunk_len, window, max_table_size).unwrap();
+        let proofs = fk.data_availability(&p).unwrap();
+
+        assert_eq!(proofs.len(), expected.len());
+        for (proof, expected) in proofs.iter().zip(&expected) {
+            assert!(proof.equals(expected));
+        }
+    }
+
+    // Too small a budget to hold a single point per base
+    assert!(with_params(&ks, 2 * n, chunk_len, None, Some(tables)).is_err());
+}
+
+/// Check FK20 multi-proofs through [`PolynomialCommitmentScheme`]: every coset's values are the
+/// polynomial's, and the proofs verify with the verifier key alone
+pub fn fk_multi_commitment_scheme<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let n = 64;
+    let chunk_len = 16;
+
+    let (s1, s2) = generate_trusted_setup(2 * n, SECRET);
+    let fs = TFFTSettings::new(log2_pow2(2 * n)).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 2 * n, &fs).unwrap();
+    let pk = FK20MultiProverKey::<TFFTSettings, TKZGSettings, TFK20MultiSettings>::new(
+        ks,
+        2 * n,
+        chunk_len,
+    )
+    .unwrap();
+
+    let vk = FK20Multi::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TFK20MultiSettings,
+        TG1Fp,
+        TG1Affine,
+    >::verifier_key(&pk)
+    .unwrap();
+    assert_eq!(vk.g1_powers.len(), chunk_len);
+    assert!(vk.tau_pow_g2.equals(&s2[chunk_len]));
+
+    let coeffs = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let poly = TPoly::from_coeffs(&coeffs);
+
+    let (_, opening) = commitment_scheme_round_trip::<
+        FK20Multi<
+            TFr,
+            TG1,
+            TG2,
+            TFFTSettings,
+            TPoly,
+            TKZGSettings,
+            TFK20MultiSettings,
+            TG1Fp,
+            TG1Affine,
+        >,
+    >(&pk, &poly, &(), &|opening| {
+        opening.ys[3][5] = opening.ys[3][5].add(&TFr::one())
+    });
+
+    assert_eq!(opening.ys.len(), 2 * n / chunk_len);
+    let stride = fs.get_max_width() / chunk_len;
+    for (i, ys) in opening.ys.iter().enumerate() {
+        let x = fs.get_roots_of_unity_at(i * chunk_len);
+        for (j, y) in ys.iter().enumerate() {
+            let z = x.mul(&fs.get_expanded_roots_of_unity_at(j * stride));
+            assert!(y.equals(&poly.eval(&z)));
+        }
+    }
+
+    let too_long =
+        TPoly::from_coeffs(&[coeffs.as_slice(), coeffs.as_slice(), &[TFr::one()]].concat());
+    assert!(FK20Multi::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TFK20MultiSettings,
+        TG1Fp,
+        TG1Affine,
+    >::open(&pk, &too_long, &TG1::identity(), &())
+    .is_err());
+}
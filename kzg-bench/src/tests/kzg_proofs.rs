@@ -1,4 +1,15 @@
-use kzg::{FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, KZGSettings, Poly, G1, G2};
+use kzg::aggregation::{
+    aggregate_proofs, aggregation_transcript, verify_aggregate_proof, AggregateProof,
+    AGGREGATION_DOMAIN,
+};
+use kzg::eip_4844::hash_to_bls_field;
+use kzg::hiding::HidingKZGSettings;
+use kzg::pcs::{BatchKzg, Kzg, PolynomialCommitmentScheme};
+use kzg::shplonk::{compute_shplonk_proof, verify_shplonk_proof};
+use kzg::{
+    FFTSettings, Fr, G1Affine, G1ConstantTime, G1Fp, G1GetFp, G1LinComb, G1Mul, G2Mul, KZGSettings,
+    PairingVerify, Poly, G1, G2,
+};
 
 pub const SECRET: [u8; 32usize] = [
     0xa4, 0x73, 0x31, 0x95, 0x28, 0xc8, 0xb6, 0xea, 0x4d, 0x08, 0xcc, 0x53, 0x18, 0x00, 0x00, 0x00,
@@ -201,3 +212,446 @@ pub fn proof_multi<
         .unwrap();
     assert!(!result);
 }
+
+/// Check that one proof opens several polynomials at the same point, and that it doesn't verify
+/// against a changed value or commitment
+pub fn proof_batch_same_point<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(17, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 17, &fs).unwrap();
+
+    // Polynomials of different lengths, the longest with 16 coefficients
+    let polys = [16, 3, 1, 9, 16]
+        .iter()
+        .map(|&len| {
+            let coeffs = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+            TPoly::from_coeffs(&coeffs)
+        })
+        .collect::<Vec<_>>();
+    let commitments = polys
+        .iter()
+        .map(|p| ks.commit_to_poly(p).unwrap())
+        .collect::<Vec<_>>();
+
+    let z = TFr::rand();
+    let mut values = polys.iter().map(|p| p.eval(&z)).collect::<Vec<_>>();
+    let proof = ks
+        .compute_proof_batch_same_point(&polys, &commitments, &z)
+        .unwrap();
+
+    assert!(ks
+        .check_proof_batch_same_point(&commitments, &proof, &z, &values)
+        .unwrap());
+
+    // A single polynomial works the same
+    let single = ks
+        .compute_proof_batch_same_point(&polys[1..2], &commitments[1..2], &z)
+        .unwrap();
+    assert!(ks
+        .check_proof_batch_same_point(&commitments[1..2], &single, &z, &values[1..2])
+        .unwrap());
+
+    let mut swapped = commitments.clone();
+    swapped.swap(0, 1);
+    assert!(!ks
+        .check_proof_batch_same_point(&swapped, &proof, &z, &values)
+        .unwrap());
+
+    values[2] = values[2].add(&TFr::one());
+    assert!(!ks
+        .check_proof_batch_same_point(&commitments, &proof, &z, &values)
+        .unwrap());
+
+    assert!(ks
+        .compute_proof_batch_same_point(&polys, &commitments[1..], &z)
+        .is_err());
+    assert!(ks
+        .check_proof_batch_same_point(&commitments, &proof, &z, &values[1..])
+        .is_err());
+    assert!(ks
+        .check_proof_batch_same_point(&[], &proof, &z, &[])
+        .is_err());
+}
+
+/// Check a SHPLONK opening of polynomials at overlapping sets of points of different sizes, and
+/// that it fails for other values or points
+pub fn proof_shplonk<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(33, SECRET);
+    let fs = TFFTSettings::new(5).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 33, &fs).unwrap();
+
+    let polys = [32, 5, 1, 17]
+        .iter()
+        .map(|&len| {
+            let coeffs = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+            TPoly::from_coeffs(&coeffs)
+        })
+        .collect::<Vec<_>>();
+    let commitments = polys
+        .iter()
+        .map(|p| ks.commit_to_poly(p).unwrap())
+        .collect::<Vec<_>>();
+
+    let shared = TFr::rand();
+    let mut points = vec![
+        vec![TFr::rand(), shared.clone(), TFr::rand()],
+        vec![TFr::rand()],
+        vec![shared, TFr::rand()],
+        (0..5)
+            .map(|i| fs.get_expanded_roots_of_unity_at(i))
+            .collect(),
+    ];
+    let mut values = polys
+        .iter()
+        .zip(&points)
+        .map(|(p, points)| points.iter().map(|x| p.eval(x)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let proof = compute_shplonk_proof(&polys, &commitments, &points, &ks).unwrap();
+    assert!(verify_shplonk_proof(&commitments, &points, &values, &proof, &ks).unwrap());
+
+    // A single polynomial at a single point works the same
+    let single = compute_shplonk_proof(&polys[..1], &commitments[..1], &points[1..2], &ks).unwrap();
+    let value = vec![vec![polys[0].eval(&points[1][0])]];
+    assert!(verify_shplonk_proof(&commitments[..1], &points[1..2], &value, &single, &ks).unwrap());
+
+    values[3][4] = values[3][4].add(&TFr::one());
+    assert!(!verify_shplonk_proof(&commitments, &points, &values, &proof, &ks).unwrap());
+    values[3][4] = values[3][4].sub(&TFr::one());
+
+    points[0][0] = TFr::rand();
+    assert!(!verify_shplonk_proof(&commitments, &points, &values, &proof, &ks).unwrap());
+
+    assert!(verify_shplonk_proof(&commitments, &points[1..], &values[1..], &proof, &ks).is_err());
+    assert!(verify_shplonk_proof(&commitments, &points, &values[1..], &proof, &ks).is_err());
+    assert!(compute_shplonk_proof(&polys, &commitments[1..], &points, &ks).is_err());
+}
+
+/// Check that single-point proofs aggregate into one that verifies, and that it binds every
+/// commitment, point, value and proof
+pub fn proof_aggregation<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(33, SECRET);
+    let fs = TFFTSettings::new(5).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 33, &fs).unwrap();
+
+    let polys = [32, 5, 1, 17, 32]
+        .iter()
+        .map(|&len| {
+            let coeffs = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+            TPoly::from_coeffs(&coeffs)
+        })
+        .collect::<Vec<_>>();
+    let commitments = polys
+        .iter()
+        .map(|p| ks.commit_to_poly(p).unwrap())
+        .collect::<Vec<_>>();
+
+    // Two of the openings share a point
+    let mut zs = (0..polys.len()).map(|_| TFr::rand()).collect::<Vec<_>>();
+    zs[4] = zs[0].clone();
+    let mut ys = polys
+        .iter()
+        .zip(&zs)
+        .map(|(p, z)| p.eval(z))
+        .collect::<Vec<_>>();
+    let mut proofs = polys
+        .iter()
+        .zip(&zs)
+        .map(|(p, z)| ks.compute_proof_single(p, z).unwrap())
+        .collect::<Vec<_>>();
+
+    let transcript = aggregation_transcript(&commitments, &zs, &ys).unwrap();
+    let bytes = transcript.bytes();
+    // The framed domain and count, then every framed commitment, point and value
+    assert_eq!(bytes.len(), 24 + 29 + polys.len() * (74 + 49 + 49));
+    assert_eq!(bytes[..8], 16u64.to_be_bytes());
+    assert_eq!(bytes[8..24], AGGREGATION_DOMAIN);
+    assert_eq!(bytes[52], polys.len() as u8);
+
+    let proof = aggregate_proofs(&polys, &commitments, &zs, &ys, &proofs, &ks).unwrap();
+    assert!(verify_aggregate_proof(&commitments, &zs, &ys, &proof, &ks).unwrap());
+
+    ys[2] = ys[2].add(&TFr::one());
+    assert!(!verify_aggregate_proof(&commitments, &zs, &ys, &proof, &ks).unwrap());
+    ys[2] = ys[2].sub(&TFr::one());
+
+    let z = core::mem::replace(&mut zs[1], TFr::rand());
+    assert!(!verify_aggregate_proof(&commitments, &zs, &ys, &proof, &ks).unwrap());
+    zs[1] = z;
+
+    // A wrong proof among the ones aggregated spoils the aggregate
+    proofs.swap(1, 3);
+    let aggregated = aggregate_proofs(&polys, &commitments, &zs, &ys, &proofs, &ks).unwrap();
+    assert!(!verify_aggregate_proof(&commitments, &zs, &ys, &aggregated, &ks).unwrap());
+
+    let forged = AggregateProof {
+        w: proof.w.clone(),
+        w_prime: proof.w_prime.add(&TG1::generator()),
+    };
+    assert!(!verify_aggregate_proof(&commitments, &zs, &ys, &forged, &ks).unwrap());
+
+    assert!(verify_aggregate_proof(&commitments, &zs[1..], &ys, &proof, &ks).is_err());
+    assert!(verify_aggregate_proof::<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+    >(&[], &[], &[], &proof, &ks)
+    .is_err());
+    assert!(aggregate_proofs(&polys, &commitments, &zs, &ys, &proofs[1..], &ks).is_err());
+}
+
+/// Check that hiding commitments to the same polynomial are unlinkable, as are their openings,
+/// and that with the setup trapdoors a commitment opens to any polynomial at all
+pub fn hiding_commitments<
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1ConstantTime
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>
+        + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(17, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2, 17, &fs).unwrap();
+
+    // h = [beta], which only the test knows
+    let tau = hash_to_bls_field::<TFr>(&SECRET);
+    let beta = TFr::rand();
+    let h = TG1::generator().mul(&beta);
+    let h_powers = s1[..4].iter().map(|s| s.mul(&beta)).collect::<Vec<_>>();
+    assert!(HidingKZGSettings::new(ks.clone(), h_powers[..1].to_vec()).is_err());
+    assert!(HidingKZGSettings::from_secret(ks.clone(), &h, &beta, 4).is_err());
+    assert!(HidingKZGSettings::from_secret(ks.clone(), &h, &tau, 18).is_err());
+    let hs = HidingKZGSettings::from_secret(ks, &h, &tau, 4).unwrap();
+    assert!(hs
+        .get_h_powers()
+        .iter()
+        .zip(&h_powers)
+        .all(|(a, b)| a.equals(b)));
+
+    let coeffs = (0..16).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let p = TPoly::from_coeffs(&coeffs);
+    let blindings = [hs.random_blinding(), hs.random_blinding()];
+    let commitments = blindings
+        .iter()
+        .map(|blinding| hs.commit(&p, blinding).unwrap())
+        .collect::<Vec<_>>();
+    assert!(!commitments[0].equals(&commitments[1]));
+    assert!(!commitments[0].equals(&hs.get_kzg_settings().commit_to_poly(&p).unwrap()));
+
+    let z = TFr::rand();
+    let y = p.eval(&z);
+    let proofs = blindings
+        .iter()
+        .map(|blinding| hs.compute_proof(&p, blinding, &z).unwrap())
+        .collect::<Vec<_>>();
+    assert!(!proofs[0].witness.equals(&proofs[1].witness));
+    for (commitment, proof) in commitments.iter().zip(&proofs) {
+        assert!(hs.check_proof(commitment, proof, &z, &y).unwrap());
+        assert!(!hs
+            .check_proof(commitment, proof, &z, &y.add(&TFr::one()))
+            .unwrap());
+    }
+    assert!(!hs.check_proof(&commitments[0], &proofs[1], &z, &y).unwrap());
+
+    // Knowing tau and beta, shift the blinding so the commitment to p is one to q
+    let q = TPoly::from_coeffs(&(0..16).map(|_| TFr::rand()).collect::<Vec<_>>());
+    let shift = p.eval(&tau).sub(&q.eval(&tau)).div(&beta).unwrap();
+    let mut blinding = blindings[0].clone();
+    blinding.set_coeff_at(0, &blinding.get_coeff_at(0).add(&shift));
+    assert!(hs.commit(&q, &blinding).unwrap().equals(&commitments[0]));
+
+    let too_long = TPoly::from_coeffs(&(0..5).map(|_| TFr::rand()).collect::<Vec<_>>());
+    assert!(hs.commit(&p, &too_long).is_err());
+}
+
+/// Check degree-bound proofs at the edges of what a setup with few G2 points supports, and that
+/// a proof for a looser bound doesn't pass for a tighter one
+pub fn degree_bound_proofs<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    // 17 G1 points and 5 G2 points support bounds from 13 to 17
+    let (s1, s2) = generate_trusted_setup(17, SECRET);
+    let fs = TFFTSettings::new(4).unwrap();
+    let ks = TKZGSettings::new(&s1, &s2[..5], 17, &fs).unwrap();
+    assert_eq!(ks.get_degree_bound_range(), 13..=17);
+
+    let coeffs = (0..13).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let p = TPoly::from_coeffs(&coeffs);
+    let commitment = ks.commit_to_poly(&p).unwrap();
+
+    for d in 13..=17 {
+        let proof = ks.compute_degree_bound_proof(&p, d).unwrap();
+        assert!(ks.check_degree_bound_proof(&commitment, &proof, d).unwrap());
+    }
+
+    let loose = ks.compute_degree_bound_proof(&p, 14).unwrap();
+    assert!(!ks
+        .check_degree_bound_proof(&commitment, &loose, 13)
+        .unwrap());
+    assert!(!ks
+        .check_degree_bound_proof(&commitment, &loose, 15)
+        .unwrap());
+
+    // High zero coefficients don't count towards the degree
+    let mut padded = coeffs.clone();
+    padded.extend([TFr::zero(), TFr::zero()]);
+    let proof = ks
+        .compute_degree_bound_proof(&TPoly::from_coeffs(&padded), 13)
+        .unwrap();
+    assert!(ks
+        .check_degree_bound_proof(&commitment, &proof, 13)
+        .unwrap());
+
+    let mut longer = coeffs;
+    longer.push(TFr::one());
+    assert!(ks
+        .compute_degree_bound_proof(&TPoly::from_coeffs(&longer), 13)
+        .is_err());
+    assert!(ks.compute_degree_bound_proof(&p, 12).is_err());
+    assert!(ks.compute_degree_bound_proof(&p, 18).is_err());
+    assert!(ks
+        .check_degree_bound_proof(&commitment, &loose, 12)
+        .is_err());
+}
+
+/// Commit to `poly`, open it at `point` and check that the opening verifies with the verifier
+/// key, and stops verifying once `tamper` has changed it
+pub fn commitment_scheme_round_trip<S: PolynomialCommitmentScheme>(
+    pk: &S::ProverKey,
+    poly: &S::Polynomial,
+    point: &S::Point,
+    tamper: &dyn Fn(&mut S::Opening),
+) -> (S::Commitment, S::Opening)
+where
+    S::Opening: Clone,
+{
+    let vk = S::verifier_key(pk).unwrap();
+    let commitment = S::commit(pk, poly).unwrap();
+    let (opening, proof) = S::open(pk, poly, &commitment, point).unwrap();
+    assert!(S::verify(&vk, &commitment, &opening, &proof).unwrap());
+
+    let mut tampered = opening.clone();
+    tamper(&mut tampered);
+    assert!(!S::verify(&vk, &commitment, &tampered, &proof).unwrap());
+
+    (commitment, opening)
+}
+
+/// Check plain and batched KZG through [`PolynomialCommitmentScheme`], verifying with nothing
+/// but the verifier key
+pub fn commitment_schemes<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+) {
+    let (s1, s2) = generate_trusted_setup(32, SECRET);
+    let fs = TFFTSettings::new(5).unwrap();
+    let pk = TKZGSettings::new(&s1, &s2, 32, &fs).unwrap();
+
+    // One G1 and two G2 points is all a verifier holds
+    let vk =
+        Kzg::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::verifier_key(
+            &pk,
+        )
+        .unwrap();
+    assert!(vk.g1.equals(&s1[0]));
+    assert!(vk.g2.equals(&s2[0]));
+    assert!(vk.tau_g2.equals(&s2[1]));
+
+    let short = TKZGSettings::new(&s1, &s2[..1], 32, &fs).unwrap();
+    assert!(
+        Kzg::<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>::verifier_key(
+            &short
+        )
+        .is_err()
+    );
+
+    let polys = [32, 7, 1]
+        .iter()
+        .map(|&len| {
+            let coeffs = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+            TPoly::from_coeffs(&coeffs)
+        })
+        .collect::<Vec<_>>();
+    let z = TFr::rand();
+
+    let (commitment, opening) = commitment_scheme_round_trip::<
+        Kzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    >(&pk, &polys[0], &z, &|opening| {
+        opening.y = opening.y.add(&TFr::one())
+    });
+    assert!(opening.z.equals(&z));
+    assert!(opening.y.equals(&polys[0].eval(&z)));
+
+    let (commitments, opening) = commitment_scheme_round_trip::<
+        BatchKzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    >(&pk, &polys, &z, &|opening| {
+        opening.ys[2] = opening.ys[2].add(&TFr::one())
+    });
+    assert!(commitments[0].equals(&commitment));
+    assert!(opening.ys[1].equals(&polys[1].eval(&z)));
+}
@@ -1,7 +1,9 @@
 pub mod bls12_381;
 pub mod c_bindings;
+pub mod constant_time;
 pub mod consts;
 pub mod das;
+pub mod das2d;
 pub mod eip_4844;
 pub mod fft_fr;
 pub mod fft_g1;
@@ -0,0 +1,85 @@
+use kzg::{msm::fixed_base::FixedBaseMsm, Fr, G1Affine, G1Fp, G1GetFp, G1Mul, G1};
+
+fn naive_msm<TFr: Fr, TG1: G1 + G1Mul<TFr>>(points: &[TG1], scalars: &[TFr]) -> TG1 {
+    points
+        .iter()
+        .zip(scalars)
+        .fold(TG1::identity(), |acc, (point, scalar)| {
+            acc.add_or_dbl(&point.mul(scalar))
+        })
+}
+
+pub fn fixed_base_msm_works<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    for len in [1, 2, 3, 17, 64] {
+        let points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+        let table = FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::new(&points).unwrap();
+        assert_eq!(table.len(), len);
+
+        // The table is reused for several sets of scalars
+        for _ in 0..3 {
+            let mut scalars = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+            scalars[0] = TFr::one().negate();
+            if len > 1 {
+                scalars[1] = TFr::zero();
+            }
+
+            let expected = naive_msm(&points, &scalars);
+            assert!(table.multiply(&scalars).unwrap().equals(&expected));
+        }
+
+        let zeros = vec![TFr::zero(); len];
+        assert!(table.multiply(&zeros).unwrap().is_inf());
+    }
+}
+
+pub fn fixed_base_msm_respects_params<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let len = 32;
+    let points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let expected = naive_msm(&points, &scalars);
+
+    let default = FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::new(&points).unwrap();
+
+    // A cap below the default table size widens the window
+    let cap = default.table_size() / 2;
+    let capped =
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(&points, None, Some(cap)).unwrap();
+    assert!(capped.table_size() <= cap);
+    assert!(capped.window() > default.window());
+    assert!(capped.multiply(&scalars).unwrap().equals(&expected));
+
+    let windowed =
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(&points, Some(3), None).unwrap();
+    assert_eq!(windowed.window(), 3);
+    assert!(windowed.multiply(&scalars).unwrap().equals(&expected));
+
+    // An explicit window is never changed to satisfy the cap
+    assert!(FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(
+        &points,
+        Some(3),
+        Some(windowed.table_size() - 1)
+    )
+    .is_err());
+    assert!(
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(&points, None, Some(len)).is_err()
+    );
+    assert!(
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(&points, Some(1), None).is_err()
+    );
+    assert!(
+        FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::with_params(&points, Some(17), None).is_err()
+    );
+    assert!(FixedBaseMsm::<TFr, TG1, TG1Fp, TG1Affine>::new(&[]).is_err());
+
+    assert!(default.multiply(&scalars[1..]).is_err());
+}
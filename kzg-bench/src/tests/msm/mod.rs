@@ -1,3 +1,5 @@
 pub mod batch_adder;
 pub mod bucket_msm;
+pub mod fixed_base_msm;
 pub mod msm_slice;
+pub mod thread_pool;
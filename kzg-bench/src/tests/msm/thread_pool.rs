@@ -0,0 +1,175 @@
+use std::sync::{mpsc, Barrier};
+use std::thread;
+use std::time::Duration;
+
+use crate::tests::fk20_proofs::SECRET;
+use kzg::common_utils::log2_pow2;
+use kzg::{
+    msm::precompute::precompute, FFTFr, FFTSettings, FK20MultiSettings, FK20SingleSettings, Fr,
+    G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, Poly, PolyRecover, FFTG1, G1, G2,
+};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+
+fn pool(num_threads: usize) -> ThreadPool {
+    ThreadPoolBuilder::new()
+        .num_threads(num_threads)
+        .build()
+        .unwrap()
+}
+
+/// Parallel MSMs split their work by the size of the pool they run on, so the result must be
+/// the same whichever pool that is. 1024 points is enough to take every parallel code path.
+pub fn g1_lincomb_is_pool_independent<
+    TFr: Fr + Send,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>() {
+    let len = 1024;
+    let points = (0..len).map(|_| TG1::rand()).collect::<Vec<_>>();
+    let scalars = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    // The precomputed table picks its tiling from the pool it is built on, but has to work on
+    // any other
+    let table = pool(8)
+        .install(|| precompute::<TFr, TG1, TG1Fp, TG1Affine>(&points))
+        .unwrap();
+
+    let expected = pool(1).install(|| TG1::g1_lincomb(&points, &scalars, len, None));
+
+    for threads in [1, 2, 3, 8] {
+        let pool = pool(threads);
+
+        let plain = pool.install(|| TG1::g1_lincomb(&points, &scalars, len, None));
+        assert!(plain.equals(&expected));
+
+        let precomputed = pool.install(|| TG1::g1_lincomb(&points, &scalars, len, table.as_ref()));
+        assert!(precomputed.equals(&expected));
+    }
+
+    let global = TG1::g1_lincomb(&points, &scalars, len, None);
+    assert!(global.equals(&expected));
+}
+
+/// Runs `op` while every thread of rayon's global pool is held. Work `op` queues on the global
+/// pool can't make progress until it is released, so `op` only finishes in time if all of its
+/// parallel work runs on some other pool.
+fn with_global_pool_held<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    let threads = rayon::current_num_threads();
+    let entered = Barrier::new(threads + 1);
+    let release = Barrier::new(threads + 1);
+
+    thread::scope(|s| {
+        s.spawn(|| {
+            rayon::broadcast(|_| {
+                entered.wait();
+                release.wait();
+            })
+        });
+        entered.wait();
+
+        let (sender, receiver) = mpsc::channel();
+        s.spawn(move || sender.send(op()).unwrap());
+        let result = receiver.recv_timeout(Duration::from_secs(60));
+        release.wait();
+
+        result.expect("Parallel work ran on the global pool instead of the execution context")
+    })
+}
+
+fn assert_fr_eq<TFr: Fr>(a: &[TFr], b: &[TFr]) {
+    assert_eq!(a.len(), b.len());
+    assert!(a.iter().zip(b).all(|(a, b)| a.equals(b)));
+}
+
+fn assert_g1_eq<TG1: G1>(a: &[TG1], b: &[TG1]) {
+    assert_eq!(a.len(), b.len());
+    assert!(a.iter().zip(b).all(|(a, b)| a.equals(b)));
+}
+
+/// FFTs, recovery, commitments, multi-proofs and FK20 install the execution context of their
+/// settings, and `KZGSettings` take theirs from the `FFTSettings` they are built from. With `fs`
+/// on a 1-thread pool, all of them must finish while the global pool is held, with the same
+/// results as on the global pool. `fs` should be at least 4096 wide, so that the FFTs and
+/// recovery take their parallel code paths.
+pub fn fft_and_fk20_stay_in_execution_context<
+    TFr: Fr + Send,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TPoly: Poly<TFr> + PolyRecover<TFr, TPoly, TFFTSettings> + Sync,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + FFTG1<TG1> + Sync,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine> + Sync,
+    TFK20SingleSettings: FK20SingleSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> + Sync,
+    TFK20MultiSettings: FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> + Sync,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    generate_trusted_setup: &dyn Fn(usize, [u8; 32usize]) -> (Vec<TG1>, Vec<TG2>),
+    fs: &TFFTSettings,
+) {
+    let width = fs.get_max_width();
+    let n2 = 512;
+    let (s1, s2) = generate_trusted_setup(n2 + 1, SECRET);
+
+    let data = (0..width).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let points = s1[..n2].to_vec();
+    let p = TPoly::from_coeffs(&data[..n2 / 2]);
+    let x = TFr::rand();
+
+    // Evaluations of a polynomial of half the width, with every other pair of them missing
+    let mut coeffs = data.clone();
+    coeffs[width / 2..].fill(TFr::zero());
+    let samples = fs
+        .fft_fr(&coeffs, false)
+        .unwrap()
+        .into_iter()
+        .enumerate()
+        .map(|(i, sample)| if i / 2 % 2 == 0 { None } else { Some(sample) })
+        .collect::<Vec<_>>();
+
+    // Building the settings may precompute in parallel, so that happens before the global pool
+    // is held
+    let settings = |fs: &TFFTSettings| {
+        let ks = TKZGSettings::new(&s1, &s2, n2 + 1, fs).unwrap();
+        let fk_single = TFK20SingleSettings::new(&ks, n2).unwrap();
+        let fk_multi = TFK20MultiSettings::new(&ks, n2, 16).unwrap();
+        (ks, fk_single, fk_multi)
+    };
+    let run = |fs: &TFFTSettings,
+               (ks, fk_single, fk_multi): &(
+        TKZGSettings,
+        TFK20SingleSettings,
+        TFK20MultiSettings,
+    )| {
+        let mut inverse = data.clone();
+        fs.fft_fr_inplace(&mut inverse, true).unwrap();
+        let recovered = TPoly::recover_poly_coeffs_from_samples(&samples, fs).unwrap();
+
+        (
+            fs.fft_fr(&data, false).unwrap(),
+            inverse,
+            fs.fft_g1(&points, false).unwrap(),
+            recovered.get_coeffs().to_vec(),
+            vec![
+                ks.commit_to_poly(&p).unwrap(),
+                ks.compute_proof_multi(&p, &x, 16).unwrap(),
+            ],
+            fk_single.data_availability(&p).unwrap(),
+            fk_multi.data_availability(&p).unwrap(),
+        )
+    };
+
+    let global_fs = TFFTSettings::new(log2_pow2(width)).unwrap();
+    let expected = run(&global_fs, &settings(&global_fs));
+
+    let fs_settings = settings(fs);
+    let actual = with_global_pool_held(|| run(fs, &fs_settings));
+
+    assert_fr_eq(&actual.0, &expected.0);
+    assert_fr_eq(&actual.1, &expected.1);
+    assert_g1_eq(&actual.2, &expected.2);
+    assert_fr_eq(&actual.3, &expected.3);
+    assert_g1_eq(&actual.4, &expected.4);
+    assert_g1_eq(&actual.5, &expected.5);
+    assert_g1_eq(&actual.6, &expected.6);
+}
@@ -1,4 +1,7 @@
-use kzg::{FFTSettings, FFTSettingsPoly, Fr, Poly};
+use kzg::common_utils::reverse_bit_order;
+use kzg::multipoint::{evaluate_multipoint, interpolate_multipoint};
+use kzg::poly_eval::{domain, PolyEval};
+use kzg::{FFTFr, FFTSettings, FFTSettingsPoly, Fr, Poly};
 use rand::rngs::StdRng;
 use rand::{RngCore, SeedableRng};
 
@@ -281,6 +284,46 @@ pub fn poly_mul_random<
     }
 }
 
+/// Products computed over shared settings, which may be wider than the product, must match
+/// those computed over settings built for the exact size.
+pub fn poly_mul_fft_with_settings<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFTTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFTTSettings>,
+>() {
+    let mut rng = StdRng::seed_from_u64(0);
+    let fs = TFTTSettings::new(11).unwrap();
+    for _k in 0..32 {
+        let multiplicand_length: usize = (1 + (rng.next_u64() % 1000)) as usize;
+        let multiplier_length: usize = (1 + (rng.next_u64() % 1000)) as usize;
+        let out_length: usize = (1 + (rng.next_u64() % 1000)) as usize;
+
+        let mut multiplicand = TPoly::new(multiplicand_length);
+        for i in 0..multiplicand_length {
+            multiplicand.set_coeff_at(i, &TFr::rand());
+        }
+        let mut multiplier = TPoly::new(multiplier_length);
+        for i in 0..multiplier_length {
+            multiplier.set_coeff_at(i, &TFr::rand());
+        }
+
+        let expected =
+            TFTTSettings::poly_mul_fft(&multiplicand, &multiplier, out_length, None).unwrap();
+        let actual =
+            TFTTSettings::poly_mul_fft(&multiplicand, &multiplier, out_length, Some(&fs)).unwrap();
+
+        assert_eq!(expected.len(), actual.len());
+        for i in 0..expected.len() {
+            assert!(expected.get_coeff_at(i).equals(&actual.get_coeff_at(i)));
+        }
+    }
+
+    // Settings too narrow for the product are rejected rather than silently wrapping around
+    let narrow = TFTTSettings::new(4).unwrap();
+    let poly = TPoly::new(32);
+    assert!(TFTTSettings::poly_mul_fft(&poly, &poly, 32, Some(&narrow)).is_err());
+}
+
 pub fn poly_div_random<TFr: Fr, TPoly: Poly<TFr>>() {
     let mut rng = StdRng::seed_from_u64(0);
     for _k in 0..256 {
@@ -372,3 +415,250 @@ pub fn poly_div_fast_test<TFr: Fr, TPoly: Poly<TFr>>() {
         }
     }
 }
+
+fn rand_poly<TFr: Fr, TPoly: Poly<TFr>>(len: usize) -> TPoly {
+    let coeffs = (0..len).map(|_| TFr::rand()).collect::<Vec<_>>();
+    TPoly::from_coeffs(&coeffs)
+}
+
+pub fn poly_add_sub_mul_scalar_random<TFr: Fr, TPoly: Poly<TFr>>() {
+    let a: TPoly = rand_poly(17);
+    let b: TPoly = rand_poly(40);
+    let scalar = TFr::rand();
+    let x = TFr::rand();
+
+    let (a_x, b_x) = (a.eval(&x), b.eval(&x));
+
+    let sum = a.add(&b);
+    assert_eq!(sum.len(), 40);
+    assert!(sum.eval(&x).equals(&a_x.add(&b_x)));
+    assert!(b.add(&a).eval(&x).equals(&a_x.add(&b_x)));
+
+    let diff = a.sub(&b);
+    assert_eq!(diff.len(), 40);
+    assert!(diff.eval(&x).equals(&a_x.sub(&b_x)));
+    assert!(b.sub(&a).eval(&x).equals(&b_x.sub(&a_x)));
+
+    let scaled = a.mul_scalar(&scalar);
+    assert_eq!(scaled.len(), a.len());
+    assert!(scaled.eval(&x).equals(&a_x.mul(&scalar)));
+}
+
+pub fn poly_derivative_test<TFr: Fr, TPoly: Poly<TFr>>() {
+    // 1 + 2x + 3x^2 + 4x^3 -> 2 + 6x + 12x^2
+    let poly: TPoly = new_test_poly(&vec![1, 2, 3, 4]);
+    let expected: TPoly = new_test_poly(&vec![2, 6, 12]);
+
+    let actual = poly.derivative();
+    assert_eq!(actual.len(), expected.len());
+    for i in 0..expected.len() {
+        assert!(expected.get_coeff_at(i).equals(&actual.get_coeff_at(i)));
+    }
+
+    assert!(TPoly::new(1).derivative().is_empty());
+    assert!(TPoly::new(0).derivative().is_empty());
+}
+
+pub fn poly_compose_random<TFr: Fr, TPoly: Poly<TFr>>() {
+    // The last case is long enough for the intermediate products to go through FFT
+    for (outer_len, inner_len) in [(1, 5), (5, 1), (7, 3), (10, 70)] {
+        let outer: TPoly = rand_poly(outer_len);
+        let inner: TPoly = rand_poly(inner_len);
+        let x = TFr::rand();
+
+        let composed = outer.compose(&inner).unwrap();
+        assert_eq!(composed.len(), (outer_len - 1) * (inner_len - 1) + 1);
+        assert!(composed.eval(&x).equals(&outer.eval(&inner.eval(&x))));
+    }
+}
+
+pub fn poly_interpolate_random<TFr: Fr, TPoly: Poly<TFr>>() {
+    let n = 64;
+    let xs = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let ys = (0..n).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+    let poly = TPoly::interpolate(&xs, &ys).unwrap();
+    assert_eq!(poly.len(), n);
+    for (x, y) in xs.iter().zip(&ys) {
+        assert!(poly.eval(x).equals(y));
+    }
+
+    assert!(TPoly::interpolate(&xs, &ys[1..]).is_err());
+
+    let mut repeated = xs.clone();
+    repeated[1] = repeated[0].clone();
+    assert!(TPoly::interpolate(&repeated, &ys).is_err());
+}
+
+pub fn poly_divide_by_linear_random<TFr: Fr, TPoly: Poly<TFr>>() {
+    let poly: TPoly = rand_poly(100);
+    let z = TFr::rand();
+    let x = TFr::rand();
+
+    // poly(x) = q(x) * (x - z) + poly(z)
+    let quotient = poly.divide_by_linear(&z);
+    assert_eq!(quotient.len(), poly.len() - 1);
+    let expected = quotient.eval(&x).mul(&x.sub(&z)).add(&poly.eval(&z));
+    assert!(poly.eval(&x).equals(&expected));
+}
+
+pub fn multipoint_evaluate_random<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFTTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFTTSettings>,
+>() {
+    // Point counts that aren't powers of two leave unpaired nodes in the tree
+    for (num_points, poly_len) in [(1, 5), (3, 2), (300, 100), (300, 700)] {
+        let xs = (0..num_points).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let poly: TPoly = rand_poly(poly_len);
+
+        let ys = evaluate_multipoint::<TFr, TPoly, TFTTSettings>(&poly, &xs).unwrap();
+        assert_eq!(ys.len(), num_points);
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!(poly.eval(x).equals(y));
+        }
+    }
+
+    let poly: TPoly = rand_poly(5);
+    assert!(evaluate_multipoint::<TFr, TPoly, TFTTSettings>(&poly, &[])
+        .unwrap()
+        .is_empty());
+}
+
+pub fn multipoint_interpolate_random<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFTTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFTTSettings>,
+>() {
+    for num_points in [1, 2, 5, 300] {
+        let xs = (0..num_points).map(|_| TFr::rand()).collect::<Vec<_>>();
+        let ys = (0..num_points).map(|_| TFr::rand()).collect::<Vec<_>>();
+
+        let poly: TPoly = interpolate_multipoint::<TFr, TPoly, TFTTSettings>(&xs, &ys).unwrap();
+        assert_eq!(poly.len(), num_points);
+        for (x, y) in xs.iter().zip(&ys) {
+            assert!(poly.eval(x).equals(y));
+        }
+    }
+
+    let xs = (0..10).map(|_| TFr::rand()).collect::<Vec<_>>();
+    let ys = (0..10).map(|_| TFr::rand()).collect::<Vec<_>>();
+    assert!(interpolate_multipoint::<TFr, TPoly, TFTTSettings>(&xs, &ys[1..]).is_err());
+
+    let mut repeated = xs.clone();
+    repeated[7] = repeated[2].clone();
+    assert!(interpolate_multipoint::<TFr, TPoly, TFTTSettings>(&repeated, &ys).is_err());
+}
+
+pub fn poly_eval_roundtrip_and_ops<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>() {
+    let fs = TFFTSettings::new(6).unwrap();
+    let a: TPoly = rand_poly(20);
+    let b: TPoly = rand_poly(30);
+    let scalar = TFr::rand();
+
+    let a_eval = PolyEval::from_poly(&a, 64, &fs).unwrap();
+    let b_eval = PolyEval::from_poly(&b, 64, &fs).unwrap();
+    let a_back: TPoly = a_eval.to_poly(&fs).unwrap();
+    for i in 0..a_back.len() {
+        let expected = if i < a.len() {
+            a.get_coeff_at(i)
+        } else {
+            TFr::zero()
+        };
+        assert!(a_back.get_coeff_at(i).equals(&expected));
+    }
+
+    // Pointwise operations agree with the coefficient form ones as long as the result fits in
+    // the domain
+    let check = |actual: PolyEval<TFr>, expected: TPoly| {
+        let expected = PolyEval::from_poly(&expected, 64, &fs).unwrap();
+        assert_eq!(actual.len(), expected.len());
+        for (actual, expected) in actual.evals().iter().zip(expected.evals()) {
+            assert!(actual.equals(expected));
+        }
+    };
+    check(a_eval.add(&b_eval).unwrap(), a.add(&b));
+    check(a_eval.sub(&b_eval).unwrap(), a.sub(&b));
+    check(a_eval.mul_scalar(&scalar), a.mul_scalar(&scalar));
+    check(
+        a_eval.mul(&b_eval).unwrap(),
+        a.clone().mul_direct(&b, a.len() + b.len() - 1).unwrap(),
+    );
+
+    let short = PolyEval::from_poly(&a, 32, &fs).unwrap();
+    assert!(a_eval.add(&short).is_err());
+    assert!(PolyEval::from_poly(&b, 16, &fs).is_err());
+    assert!(PolyEval::new(vec![TFr::one(); 3]).is_err());
+}
+
+pub fn poly_eval_evaluate_random<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>() {
+    let fs = TFFTSettings::new(8).unwrap();
+    let len = 128;
+    let roots = domain(&fs, len).unwrap();
+    let poly: TPoly = rand_poly(100);
+    let evals = PolyEval::from_poly(&poly, len, &fs).unwrap();
+
+    let z = TFr::rand();
+    assert!(evals.evaluate(&roots, &z).unwrap().equals(&poly.eval(&z)));
+    assert!(evals
+        .evaluate(&roots, &roots[5])
+        .unwrap()
+        .equals(&poly.eval(&roots[5])));
+    assert!(evals.evaluate(&roots[1..], &z).is_err());
+
+    // The order of the domain doesn't matter as long as the evaluations follow it
+    let mut reversed_roots = roots.clone();
+    reverse_bit_order(&mut reversed_roots).unwrap();
+    let mut reversed = evals.clone();
+    reversed.reverse_bit_order().unwrap();
+    assert!(reversed
+        .evaluate(&reversed_roots, &z)
+        .unwrap()
+        .equals(&poly.eval(&z)));
+}
+
+pub fn poly_eval_quotient_by_linear<
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+>() {
+    let fs = TFFTSettings::new(8).unwrap();
+    let len = 256;
+    let roots = domain(&fs, len).unwrap();
+    let poly: TPoly = rand_poly(len);
+    let evals = PolyEval::from_poly(&poly, len, &fs).unwrap();
+
+    let mut reversed_roots = roots.clone();
+    reverse_bit_order(&mut reversed_roots).unwrap();
+    let mut reversed = evals.clone();
+    reversed.reverse_bit_order().unwrap();
+
+    // Both outside and inside the domain
+    for z in [TFr::rand(), roots[17].clone()] {
+        let expected = poly.divide_by_linear(&z);
+
+        let (quotient, y) = evals.quotient_by_linear(&roots, &z).unwrap();
+        assert!(y.equals(&poly.eval(&z)));
+        let quotient: TPoly = quotient.to_poly(&fs).unwrap();
+        for i in 0..expected.len() {
+            assert!(quotient.get_coeff_at(i).equals(&expected.get_coeff_at(i)));
+        }
+        assert!(quotient.get_coeff_at(len - 1).is_zero());
+
+        let (mut quotient, y) = reversed.quotient_by_linear(&reversed_roots, &z).unwrap();
+        assert!(y.equals(&poly.eval(&z)));
+        quotient.reverse_bit_order().unwrap();
+        let quotient: TPoly = quotient.to_poly(&fs).unwrap();
+        for i in 0..expected.len() {
+            assert!(quotient.get_coeff_at(i).equals(&expected.get_coeff_at(i)));
+        }
+    }
+}
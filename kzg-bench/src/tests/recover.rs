@@ -1,8 +1,10 @@
+use kzg::recovery::Recoverer;
 use kzg::FFTFr;
 use kzg::FFTSettings;
 use kzg::Fr;
 use kzg::Poly;
 use kzg::PolyRecover;
+use kzg::ZeroPoly;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use std::convert::TryInto;
@@ -128,6 +130,50 @@ pub fn more_than_half_missing<
     assert!(TPolyRecover::recover_poly_from_samples(&[None], &fs).is_err());
 }
 
+/// Feed samples one by one to a `Recoverer` over part of the FFT settings' domain, checking that
+/// it can recover exactly from the moment half of them arrived, and recovers the original data
+pub fn recoverer_incremental<
+    TFr: Fr,
+    TFTTSettings: FFTSettings<TFr> + FFTFr<TFr> + ZeroPoly<TFr, TPoly>,
+    TPoly: Poly<TFr>,
+>() {
+    let fs = TFTTSettings::new(10).unwrap();
+
+    for len in [4, 256] {
+        let mut poly = vec![TFr::zero(); len];
+        for p in poly.iter_mut().take(len / 2) {
+            *p = TFr::rand();
+        }
+        let data = fs.fft_fr(&poly, false).unwrap();
+
+        let mut order = (0..len).collect::<Vec<_>>();
+        order.shuffle(&mut thread_rng());
+
+        let mut recoverer = Recoverer::<TFr, TPoly, TFTTSettings>::new(&fs, len).unwrap();
+        for (received, &i) in order.iter().enumerate() {
+            assert_eq!(recoverer.can_recover(), 2 * received >= len);
+            if !recoverer.can_recover() {
+                assert!(recoverer.recover().is_err());
+            }
+
+            recoverer.add_sample(i, data[i].clone()).unwrap();
+            recoverer.add_sample(i, data[i].clone()).unwrap();
+            assert!(recoverer.add_sample(i, data[i].add(&TFr::one())).is_err());
+            assert_eq!(recoverer.received(), received + 1);
+
+            if received + 1 == len / 2 || received + 1 == 3 * len / 4 || received + 1 == len {
+                let recovered = recoverer.recover().unwrap();
+                assert!(recovered == data);
+            }
+        }
+
+        assert!(recoverer.add_sample(len, TFr::zero()).is_err());
+    }
+
+    assert!(Recoverer::<TFr, TPoly, TFTTSettings>::new(&fs, 12).is_err());
+    assert!(Recoverer::<TFr, TPoly, TFTTSettings>::new(&fs, 2048).is_err());
+}
+
 fn random_missing<TFr: Fr>(data: Vec<TFr>, len_data: usize, known: u64) -> Vec<Option<TFr>> {
     let mut missing_idx: Vec<usize> = vec![];
     let mut with_missing = data.into_iter().map(Some).collect::<Vec<_>>();
@@ -1,6 +1,7 @@
 use std::{env::current_dir, path::PathBuf};
 
 use kzg::eip_4844::TRUSTED_SETUP_PATH;
+use kzg::Fr;
 use pathdiff::diff_paths;
 
 pub fn get_manifest_dir() -> String {
@@ -18,3 +19,24 @@ pub fn get_trusted_setup_path() -> String {
         .into_string()
         .unwrap()
 }
+
+pub fn get_trusted_setup_fixture_path(fixture: &str) -> String {
+    let filename = "trusted_setup_fixture.txt";
+
+    PathBuf::from(get_manifest_dir())
+        .join("src/tests/fixtures")
+        .join(fixture)
+        .join(filename)
+        .as_os_str()
+        .to_str()
+        .unwrap()
+        .to_string()
+}
+
+/// Scalars to check a multiplication against a reference: zero, one and minus one, where
+/// windowed and table-based methods tend to go wrong, then a few random ones.
+pub fn generate_test_scalars<TFr: Fr>() -> Vec<TFr> {
+    let mut scalars = vec![TFr::zero(), TFr::one(), TFr::one().negate()];
+    scalars.extend((0..16).map(|_| TFr::rand()));
+    scalars
+}
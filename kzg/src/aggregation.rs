@@ -0,0 +1,168 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::batch_opening::challenge_powers;
+use crate::transcript::{Sha256Transcript, Transcript};
+use crate::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly,
+    G1, G2,
+};
+
+pub const AGGREGATION_DOMAIN: [u8; 16] = [
+    82, 67, 75, 90, 71, 65, 71, 71, 82, 69, 71, 65, 84, 69, 86, 49,
+]; // "RCKZGAGGREGATEV1"
+
+/// Single-point openings `(C_i, z_i, y_i, π_i)` folded into one proof.
+///
+/// `w` is `sum(γ^i * π_i)`, a commitment to `h = sum(γ^i * q_i)` for the quotients `q_i` the
+/// proofs commit to. With `z` a second challenge and `c_i = γ^i / (z - z_i)`, the polynomial
+/// `L = sum(c_i * (f_i - y_i)) - h` is zero at `z` exactly when every `q_i` is the quotient of
+/// `f_i - y_i` by `x - z_i`, and `w_prime` opens it there. The verifier gets `[L]` from the
+/// commitments and `w` alone, so `w` can't be chosen freely, and the whole check is a single
+/// MSM and a single pairing product.
+///
+/// Folding the proofs into `w` needs nothing but the proofs, but `w_prime` is a fresh opening,
+/// so the aggregator has to know the polynomials.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateProof<TG1> {
+    pub w: TG1,
+    pub w_prime: TG1,
+}
+
+/// Transcript the challenges are drawn from. `γ`, which the proofs are folded with, binds the
+/// number of openings and then every commitment `C_i`, point `z_i` and value `y_i`, and `z`, the
+/// point `L` is opened at, binds `γ` and `w`. The proofs themselves are left out, since the
+/// verifier never sees them.
+pub fn aggregation_transcript<TFr: Fr, TG1: G1>(
+    commitments: &[TG1],
+    zs: &[TFr],
+    ys: &[TFr],
+) -> Result<Sha256Transcript, String> {
+    let n = commitments.len();
+    if n == 0 || zs.len() != n || ys.len() != n {
+        return Err(String::from(
+            "Aggregation needs one point and value per commitment, and at least one commitment",
+        ));
+    }
+
+    let mut transcript = Sha256Transcript::new(&AGGREGATION_DOMAIN);
+    transcript.append_u64(b"count", n as u64);
+
+    for ((commitment, z), y) in commitments.iter().zip(zs).zip(ys) {
+        transcript.append_commitment(b"commitment", commitment);
+        transcript.append_scalar(b"z", z);
+        transcript.append_scalar(b"y", y);
+    }
+
+    Ok(transcript)
+}
+
+/// Folds the proofs that `polys[i]`, committed to as `commitments[i]`, takes `ys[i]` at `zs[i]`.
+/// The proofs are not checked here, a wrong one just makes the aggregate fail verification.
+pub fn aggregate_proofs<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    polys: &[TPoly],
+    commitments: &[TG1],
+    zs: &[TFr],
+    ys: &[TFr],
+    proofs: &[TG1],
+    s: &TKZGSettings,
+) -> Result<AggregateProof<TG1>, String> {
+    if polys.len() != commitments.len() || proofs.len() != commitments.len() {
+        return Err(String::from(
+            "Aggregation needs one polynomial and proof per commitment",
+        ));
+    }
+
+    let mut transcript = aggregation_transcript(commitments, zs, ys)?;
+    let gamma = transcript.challenge_scalar(b"gamma");
+    let gamma_powers = challenge_powers(&gamma, commitments.len());
+    let w = TG1::g1_lincomb(proofs, &gamma_powers, proofs.len(), None);
+
+    transcript.append_commitment(b"w", &w);
+    let z = transcript.challenge_scalar(b"z");
+    let scalars = opening_scalars(zs, &gamma_powers, &z)?;
+
+    let mut constant = TFr::zero();
+    let mut l = TPoly::new(0);
+    for ((((poly, point), value), gamma_power), scalar) in polys
+        .iter()
+        .zip(zs)
+        .zip(ys)
+        .zip(&gamma_powers)
+        .zip(&scalars)
+    {
+        let quotient = poly.divide_by_linear(point);
+        l = l
+            .add(&poly.mul_scalar(scalar))
+            .sub(&quotient.mul_scalar(gamma_power));
+        constant = constant.sub(&value.mul(scalar));
+    }
+    l = l.add(&TPoly::from_coeffs(&[constant]));
+
+    let w_prime = s.commit_to_poly(&l.divide_by_linear(&z))?;
+
+    Ok(AggregateProof { w, w_prime })
+}
+
+/// Checks that the polynomials of `commitments` take `ys[i]` at `zs[i]`, that is
+/// `e([L] + z * W', [1]) = e(W', [τ])`.
+pub fn verify_aggregate_proof<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments: &[TG1],
+    zs: &[TFr],
+    ys: &[TFr],
+    proof: &AggregateProof<TG1>,
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    let mut transcript = aggregation_transcript(commitments, zs, ys)?;
+    let gamma = transcript.challenge_scalar(b"gamma");
+    let gamma_powers = challenge_powers(&gamma, commitments.len());
+    transcript.append_commitment(b"w", &proof.w);
+    let z = transcript.challenge_scalar(b"z");
+    let mut scalars = opening_scalars(zs, &gamma_powers, &z)?;
+
+    // The commitments followed by the generator, W and W'
+    let constant = scalars
+        .iter()
+        .zip(ys)
+        .fold(TFr::zero(), |acc, (scalar, y)| acc.sub(&y.mul(scalar)));
+    scalars.extend([constant, TFr::one().negate(), z]);
+
+    let mut bases = commitments.to_vec();
+    bases.extend([TG1::generator(), proof.w.clone(), proof.w_prime.clone()]);
+    let lhs = TG1::g1_lincomb(&bases, &scalars, bases.len(), None);
+
+    Ok(TG1::verify(
+        &lhs,
+        &TG2::generator(),
+        &proof.w_prime,
+        &s.get_g2_secret()[1],
+    ))
+}
+
+/// `γ^i / (z - z_i)` for every point.
+fn opening_scalars<TFr: Fr>(zs: &[TFr], gamma_powers: &[TFr], z: &TFr) -> Result<Vec<TFr>, String> {
+    zs.iter()
+        .zip(gamma_powers)
+        .map(|(point, gamma_power)| gamma_power.div(&z.sub(point)))
+        .collect()
+}
@@ -0,0 +1,69 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::transcript::{Sha256Transcript, Transcript};
+use crate::{Fr, Poly, G1};
+
+pub const BATCH_SAME_POINT_DOMAIN: [u8; 16] = [
+    82, 67, 75, 90, 71, 83, 65, 77, 69, 80, 79, 73, 78, 84, 86, 49,
+]; // "RCKZGSAMEPOINTV1"
+
+/// Fiat–Shamir challenge for opening all of `commitments` at `z` to `values`. Hashes the domain
+/// separator, the number of commitments, then every commitment followed by `z` and the values.
+pub fn compute_batch_same_point_challenge<TFr: Fr, TG1: G1>(
+    commitments: &[TG1],
+    z: &TFr,
+    values: &[TFr],
+) -> Result<TFr, String> {
+    if commitments.is_empty() || commitments.len() != values.len() {
+        return Err(String::from(
+            "Batch must have one value per commitment, and at least one",
+        ));
+    }
+
+    let mut transcript = Sha256Transcript::new(&BATCH_SAME_POINT_DOMAIN);
+    transcript.append_u64(b"count", commitments.len() as u64);
+
+    for commitment in commitments {
+        transcript.append_commitment(b"commitment", commitment);
+    }
+    transcript.append_scalar(b"z", z);
+    for value in values {
+        transcript.append_scalar(b"y", value);
+    }
+
+    Ok(transcript.challenge_scalar(b"r"))
+}
+
+/// `1, r, r^2, ...`, `n` of them.
+pub fn challenge_powers<TFr: Fr>(r: &TFr, n: usize) -> Vec<TFr> {
+    let mut powers = Vec::with_capacity(n);
+    let mut power = TFr::one();
+    for _ in 0..n {
+        powers.push(power.clone());
+        power = power.mul(r);
+    }
+    powers
+}
+
+/// `sum(powers[i] * polys[i])`.
+pub fn combine_polys<TFr: Fr, TPoly: Poly<TFr>>(polys: &[TPoly], powers: &[TFr]) -> TPoly {
+    polys
+        .iter()
+        .zip(powers)
+        .fold(TPoly::new(0), |acc, (poly, power)| {
+            acc.add(&poly.mul_scalar(power))
+        })
+}
+
+/// `sum(powers[i] * values[i])`.
+pub fn combine_values<TFr: Fr>(values: &[TFr], powers: &[TFr]) -> TFr {
+    values
+        .iter()
+        .zip(powers)
+        .fold(TFr::zero(), |acc, (value, power)| {
+            acc.add(&value.mul(power))
+        })
+}
@@ -0,0 +1,201 @@
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::fft::rs_extension;
+use crate::poly_eval::{batch_inverse, domain};
+use crate::{
+    FFTFr, FFTSettings, Fr, G1Affine, G1Fp, G1LinComb, G1Mul, Poly, PolyRecover, FFTG1, G1,
+};
+
+// Two-dimensional erasure coding, as in full Danksharding.
+//
+// A blob matrix has one blob per row, each row being the values of its polynomial over the
+// roots of unity of the row length, in natural order. Every row is extended by 2 along the row,
+// then every column of the result by 2 across the rows, so that the `2R x 2C` extended matrix
+// has `extended[2 * r][2 * c] == rows[r][c]`. Each row and each column of it is then a codeword
+// of rate 1/2, which is what recovery relies on.
+
+/// Extends a matrix of `R` blobs of `C` values each to `2R x 2C`. Both dimensions must be powers
+/// of two, and twice each must fit in `fs`.
+pub fn extend_2d<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    rows: &[Vec<TFr>],
+) -> Result<Vec<Vec<TFr>>, String> {
+    let (_, width) = dimensions(rows)?;
+
+    let mut extended = Vec::with_capacity(2 * rows.len());
+    for row in rows {
+        extended.push(rs_extension(fs, row, 2)?);
+        extended.push(Vec::new());
+    }
+
+    let mut column = vec![TFr::zero(); rows.len()];
+    for c in 0..2 * width {
+        for (value, row) in column.iter_mut().zip(extended.iter().step_by(2)) {
+            *value = row[c].clone();
+        }
+
+        let column = rs_extension(fs, &column, 2)?;
+        for (r, value) in column.into_iter().enumerate().skip(1).step_by(2) {
+            extended[r].push(value);
+        }
+    }
+
+    Ok(extended)
+}
+
+/// Commitments to the `2R` rows of the extended matrix, from the commitments to the `R` original
+/// rows. Commitments are linear, so they extend across rows the same way the values do, with the
+/// FFT done in G1. As with the values, `result[2 * r]` is `commitments[r]`.
+pub fn extend_commitments<TFr, TG1, TFFTSettings>(
+    fs: &TFFTSettings,
+    commitments: &[TG1],
+) -> Result<Vec<TG1>, String>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>,
+{
+    let mut coeffs = fs.fft_g1(commitments, true)?;
+    coeffs.resize(2 * commitments.len(), TG1::identity());
+
+    fs.fft_g1(&coeffs, false)
+}
+
+/// Commitment to the row at `x` along the column axis, i.e. to the polynomial whose value at
+/// every column is the row-direction interpolation of `commitments` evaluated at `x`. For `x`
+/// one of the `2R`-th roots of unity this is a row of the extended matrix, so single extended
+/// rows can be checked without extending all commitments.
+pub fn row_commitment_at<TFr, TG1, TG1Fp, TG1Affine, TFFTSettings>(
+    fs: &TFFTSettings,
+    commitments: &[TG1],
+    x: &TFr,
+) -> Result<TG1, String>
+where
+    TFr: Fr,
+    TG1: G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+    TFFTSettings: FFTSettings<TFr>,
+{
+    let n = commitments.len();
+    let roots = domain(fs, n)?;
+    if let Some(m) = roots.iter().position(|root| root.equals(x)) {
+        return Ok(commitments[m].clone());
+    }
+
+    // Lagrange basis at x: L_i(x) = (x^n - 1) / n * ω_i / (x - ω_i)
+    let mut lagrange = roots.iter().map(|root| x.sub(root)).collect::<Vec<_>>();
+    batch_inverse(&mut lagrange)?;
+
+    let factor = x
+        .pow(n)
+        .sub(&TFr::one())
+        .mul(&TFr::from_u64(n as u64).inverse());
+    for (coeff, root) in lagrange.iter_mut().zip(&roots) {
+        *coeff = coeff.mul(root).mul(&factor);
+    }
+
+    Ok(TG1::g1_lincomb(commitments, &lagrange, n, None))
+}
+
+/// Recovers the full `2R x 2C` extended matrix from the samples available.
+///
+/// Any row or column missing at most half its samples is recovered with `TPolyRecover`, which
+/// may in turn complete enough of the crossing columns or rows to recover those. This repeats
+/// until nothing is missing, or until no row or column can make progress, in which case the
+/// pattern is unrecoverable. Every pattern with at least 75% of the samples is recoverable, as
+/// the smallest one stopping the process misses `(R + 1) x (C + 1)` samples.
+pub fn recover_2d<TFr, TPoly, TFFTSettings, TPolyRecover>(
+    fs: &TFFTSettings,
+    samples: &[Vec<Option<TFr>>],
+) -> Result<Vec<Vec<TFr>>, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFFTSettings>,
+{
+    let (height, width) = dimensions(samples)?;
+    let mut matrix = samples.to_vec();
+
+    loop {
+        let mut progress = false;
+
+        for row in matrix.iter_mut() {
+            progress |= recover_line::<TFr, TPoly, TFFTSettings, TPolyRecover>(fs, row)?;
+        }
+
+        let mut column = vec![None; height];
+        for c in 0..width {
+            for (value, row) in column.iter_mut().zip(&matrix) {
+                *value = row[c].clone();
+            }
+
+            if recover_line::<TFr, TPoly, TFFTSettings, TPolyRecover>(fs, &mut column)? {
+                for (row, value) in matrix.iter_mut().zip(&column) {
+                    row[c] = value.clone();
+                }
+                progress = true;
+            }
+        }
+
+        let missing = matrix.iter().flatten().filter(|x| x.is_none()).count();
+        if missing == 0 {
+            break;
+        }
+        if !progress {
+            return Err(format!(
+                "Unrecoverable: {} of {} samples missing, and every incomplete row and column misses more than half",
+                missing,
+                height * width
+            ));
+        }
+    }
+
+    Ok(matrix
+        .into_iter()
+        .map(|row| row.into_iter().flatten().collect())
+        .collect())
+}
+
+/// Fills in `line` if it is incomplete but has at least half its samples. Returns whether it did.
+fn recover_line<TFr, TPoly, TFFTSettings, TPolyRecover>(
+    fs: &TFFTSettings,
+    line: &mut [Option<TFr>],
+) -> Result<bool, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TPolyRecover: PolyRecover<TFr, TPoly, TFFTSettings>,
+{
+    let missing = line.iter().filter(|x| x.is_none()).count();
+    if missing == 0 || 2 * missing > line.len() {
+        return Ok(false);
+    }
+
+    let recovered = TPolyRecover::recover_poly_from_samples(line, fs)?;
+    for (sample, value) in line.iter_mut().zip(recovered.get_coeffs()) {
+        *sample = Some(value.clone());
+    }
+
+    Ok(true)
+}
+
+/// Height and width of a non-empty matrix with rows of equal length.
+fn dimensions<T>(rows: &[Vec<T>]) -> Result<(usize, usize), String> {
+    let width = rows.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(String::from("Matrix must not be empty"));
+    }
+    if rows.iter().any(|row| row.len() != width) {
+        return Err(String::from("Matrix rows must all have the same length"));
+    }
+
+    Ok((rows.len(), width))
+}
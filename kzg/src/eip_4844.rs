@@ -1,6 +1,7 @@
 #![allow(non_camel_case_types)]
 extern crate alloc;
 
+use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::String;
 use alloc::string::ToString;
@@ -12,11 +13,17 @@ use core::ffi::c_uint;
 use sha2::{Digest, Sha256};
 
 use crate::common_utils::reverse_bit_order;
+use crate::pcs::KzgVerifierKey;
+use crate::poly_eval::PolyEval;
+use crate::transcript::{Sha256Transcript, Transcript};
 use crate::G1Affine;
 use crate::G1Fp;
 use crate::G1GetFp;
 use crate::G1LinComb;
-use crate::{FFTSettings, Fr, G1Mul, KZGSettings, PairingVerify, Poly, G1, G2};
+use crate::{
+    FFTFr, FFTSettings, FK20SingleSettings, Fr, G1Mul, KZGSettings, PairingVerify, Poly, FFTG1, G1,
+    G2,
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -51,6 +58,24 @@ pub const RANDOM_CHALLENGE_KZG_BATCH_DOMAIN: [u8; 16] = [
     82, 67, 75, 90, 71, 66, 65, 84, 67, 72, 95, 95, 95, 86, 49, 95,
 ]; // "RCKZGBATCH___V1_"
 
+/// `[τ]` in G2 from the EIP-4844 ceremony, compressed. It's the second G2 point of the trusted
+/// setup file, and with the generators all that checking a proof needs from the setup.
+pub const TRUSTED_SETUP_TAU_G2: [u8; BYTES_PER_G2] = [
+    0xb5, 0xbf, 0xd7, 0xdd, 0x8c, 0xde, 0xb1, 0x28, 0x84, 0x3b, 0xc2, 0x87, 0x23, 0x0a, 0xf3, 0x89,
+    0x26, 0x18, 0x70, 0x75, 0xcb, 0xfb, 0xef, 0xa8, 0x10, 0x09, 0xa2, 0xce, 0x61, 0x5a, 0xc5, 0x3d,
+    0x29, 0x14, 0xe5, 0x87, 0x0c, 0xb4, 0x52, 0xd2, 0xaf, 0xaa, 0xab, 0x24, 0xf3, 0x49, 0x9f, 0x72,
+    0x18, 0x5c, 0xbf, 0xee, 0x53, 0x49, 0x27, 0x14, 0x73, 0x44, 0x29, 0xb7, 0xb3, 0x86, 0x08, 0xe2,
+    0x39, 0x26, 0xc9, 0x11, 0xcc, 0xec, 0xea, 0xc9, 0xa3, 0x68, 0x51, 0x47, 0x7b, 0xa4, 0xc6, 0x0b,
+    0x08, 0x70, 0x41, 0xde, 0x62, 0x10, 0x00, 0xed, 0xc9, 0x8e, 0xda, 0xda, 0x20, 0xc1, 0xde, 0xf2,
+];
+
+/// Big-endian bytes of the primitive `FIELD_ELEMENTS_PER_BLOB`-th root of unity whose powers, in
+/// bit-reversed order, are the points blobs are evaluations at.
+pub const BLOB_ROOT_OF_UNITY: [u8; BYTES_PER_FIELD_ELEMENT] = [
+    0x56, 0x4c, 0x0a, 0x11, 0xa0, 0xf7, 0x04, 0xf4, 0xfc, 0x3e, 0x8a, 0xcf, 0xe0, 0xf8, 0x24, 0x5f,
+    0x0a, 0xd1, 0x34, 0x7b, 0x37, 0x8f, 0xbf, 0x96, 0xe2, 0x06, 0xda, 0x11, 0xa5, 0xd3, 0x63, 0x06,
+];
+
 ////////////////////////////// C API for EIP-4844 //////////////////////////////
 
 pub type C_KZG_RET = c_uint;
@@ -101,6 +126,24 @@ pub struct CKZGSettings {
 ////////////////////////////// Utility functions for EIP-4844 //////////////////////////////
 
 pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), String> {
+    let (g1_bytes, g2_bytes, _) = parse_trusted_setup_string(contents, false)?;
+    Ok((g1_bytes, g2_bytes))
+}
+
+/// Same as [`load_trusted_setup_string`], also parsing the monomial G1 points newer setup files
+/// list after the G2 points. For older files they are empty.
+#[allow(clippy::type_complexity)]
+pub fn load_trusted_setup_string_with_monomial(
+    contents: &str,
+) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), String> {
+    parse_trusted_setup_string(contents, true)
+}
+
+#[allow(clippy::type_complexity)]
+fn parse_trusted_setup_string(
+    contents: &str,
+    with_monomial: bool,
+) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>), String> {
     let mut offset = 0;
 
     const TRUSTED_SETUP_ERROR: &str = "Incorrect trusted setup format";
@@ -171,7 +214,15 @@ pub fn load_trusted_setup_string(contents: &str) -> Result<(Vec<u8>, Vec<u8>), S
         *byte = scan_hex_byte(&mut offset, contents)?
     }
 
-    Ok((g1_bytes, g2_bytes))
+    let mut g1_monomial_bytes = Vec::new();
+    if with_monomial && !contents[offset..].trim().is_empty() {
+        g1_monomial_bytes.resize(g1_point_count * BYTES_PER_G1, 0);
+        for byte in &mut g1_monomial_bytes {
+            *byte = scan_hex_byte(&mut offset, contents)?
+        }
+    }
+
+    Ok((g1_bytes, g2_bytes, g1_monomial_bytes))
 }
 
 pub fn bytes_of_uint64(out: &mut [u8], mut n: u64) {
@@ -213,12 +264,31 @@ fn poly_to_kzg_commitment<
     p: &TPoly,
     s: &TKZGSettings,
 ) -> TG1 {
-    TG1::g1_lincomb(
-        s.get_g1_secret(),
-        p.get_coeffs(),
-        FIELD_ELEMENTS_PER_BLOB,
-        s.get_precomputation(),
-    )
+    g1_lincomb_in_context(s, p.get_coeffs())
+}
+
+/// `\sum coeffs[i] * secret_g1[i]` over the blob, on the settings' execution context.
+fn g1_lincomb_in_context<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    s: &TKZGSettings,
+    coeffs: &[TFr],
+) -> TG1 {
+    let (points, precomputation) = (s.get_g1_secret(), s.get_precomputation());
+    let lincomb = || TG1::g1_lincomb(points, coeffs, FIELD_ELEMENTS_PER_BLOB, precomputation);
+
+    #[cfg(feature = "parallel")]
+    return s.get_execution_context().install(lincomb);
+
+    #[cfg(not(feature = "parallel"))]
+    lincomb()
 }
 
 pub fn blob_to_kzg_commitment_rust<
@@ -258,47 +328,25 @@ fn compute_r_powers<TG1: G1, TFr: Fr>(
     proofs_g1: &[TG1],
 ) -> Result<Vec<TFr>, String> {
     let n = commitments_g1.len();
+    if zs_fr.len() != n || ys_fr.len() != n || proofs_g1.len() != n {
+        return Err(String::from("Error while copying commitments"));
+    }
+
     let input_size =
         32 + n * (BYTES_PER_COMMITMENT + 2 * BYTES_PER_FIELD_ELEMENT + BYTES_PER_PROOF);
-
-    let mut bytes: Vec<u8> = vec![0; input_size];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN);
-    bytes_of_uint64(&mut bytes[16..24], FIELD_ELEMENTS_PER_BLOB as u64);
-    bytes_of_uint64(&mut bytes[24..32], n as u64);
-    let mut offset = 32;
+    let mut transcript = Sha256Transcript::legacy(&RANDOM_CHALLENGE_KZG_BATCH_DOMAIN, input_size);
+    transcript.append_u64(b"degree", FIELD_ELEMENTS_PER_BLOB as u64);
+    transcript.append_u64(b"count", n as u64);
 
     for i in 0..n {
-        // Copy commitment
-        let v = commitments_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_COMMITMENT;
-
-        // Copy evaluation challenge
-        let v = zs_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy polynomial's evaluation value
-        let v = ys_fr[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_FIELD_ELEMENT;
-
-        // Copy proof
-        let v = proofs_g1[i].to_bytes();
-        bytes[offset..(v.len() + offset)].copy_from_slice(&v[..]);
-        offset += BYTES_PER_PROOF;
-    }
-
-    // Make sure we wrote the entire buffer
-    if offset != input_size {
-        return Err(String::from("Error while copying commitments"));
+        transcript.append_commitment(b"commitment", &commitments_g1[i]);
+        transcript.append_scalar(b"z", &zs_fr[i]);
+        transcript.append_scalar(b"y", &ys_fr[i]);
+        transcript.append_commitment(b"proof", &proofs_g1[i]);
     }
 
     // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    let r = hash_to_bls_field(&eval_challenge);
+    let r = transcript.challenge_scalar(b"r");
 
     Ok(compute_powers(&r, n))
 }
@@ -331,7 +379,7 @@ fn verify_kzg_proof_batch<
 
     for i in 0..n {
         // Get [y_i]
-        let ys_encrypted = TG1::generator().mul(&ys_fr[i]);
+        let ys_encrypted = TG1::generator_mul(&ys_fr[i]);
         // Get C_i - [y_i]
         c_minus_y.push(commitments_g1[i].sub(&ys_encrypted));
         // Get r^i * z_i
@@ -369,72 +417,13 @@ pub fn compute_kzg_proof_rust<
     z: &TFr,
     s: &TKZGSettings,
 ) -> Result<(TG1, TFr), String> {
-    let polynomial = blob_to_polynomial(blob)?;
-    let y = evaluate_polynomial_in_evaluation_form(&polynomial, z, s)?;
-
-    let mut tmp: TFr;
-
-    let mut m: usize = 0;
-    let mut q: TPoly = TPoly::new(FIELD_ELEMENTS_PER_BLOB);
-
-    let mut inverses_in: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-
-    let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
-    let poly_coeffs = polynomial.get_coeffs();
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if z.equals(&roots_of_unity[i]) {
-            // We are asked to compute a KZG proof inside the domain
-            m = i + 1;
-            inverses_in[i] = TFr::one();
-            continue;
-        }
-        // (p_i - y) / (ω_i - z)
-        q.set_coeff_at(i, &poly_coeffs[i].sub(&y));
-        inverses_in[i] = roots_of_unity[i].sub(z);
-    }
-
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
-
-    for (i, inverse) in inverses.iter().enumerate().take(FIELD_ELEMENTS_PER_BLOB) {
-        q.set_coeff_at(i, &q.get_coeff_at(i).mul(inverse));
-    }
+    let polynomial: TPoly = blob_to_polynomial(blob)?;
+    let roots_of_unity = &s.get_fft_settings().get_roots_of_unity()[..FIELD_ELEMENTS_PER_BLOB];
 
-    if m != 0 {
-        // ω_{m-1} == z
-        m -= 1;
-        q.set_coeff_at(m, &TFr::zero());
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build denominator: z * (z - ω_i)
-            tmp = z.sub(&roots_of_unity[i]);
-            inverses_in[i] = tmp.mul(z);
-        }
+    let (q, y) =
+        PolyEval::from_evals(polynomial.get_coeffs())?.quotient_by_linear(roots_of_unity, z)?;
 
-        fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
-
-        for i in 0..FIELD_ELEMENTS_PER_BLOB {
-            if i == m {
-                continue;
-            }
-            // Build numerator: ω_i * (p_i - y)
-            tmp = poly_coeffs[i].sub(&y);
-            tmp = tmp.mul(&roots_of_unity[i]);
-            // Do the division: (p_i - y) * ω_i / (z * (z - ω_i))
-            tmp = tmp.mul(&inverses[i]);
-            q.set_coeff_at(m, &q.get_coeff_at(m).add(&tmp))
-        }
-    }
-
-    let proof = TG1::g1_lincomb(
-        s.get_g1_secret(),
-        q.get_coeffs(),
-        FIELD_ELEMENTS_PER_BLOB,
-        s.get_precomputation(),
-    );
+    let proof = g1_lincomb_in_context(s, q.evals());
     Ok((proof, y))
 }
 
@@ -461,6 +450,97 @@ pub fn compute_blob_kzg_proof_rust<
     Ok(proof)
 }
 
+/// FK20 settings for [`compute_all_kzg_proofs_for_blob_rust`]. FK20 works from the setup's
+/// monomial points, with an FFT of twice the blob's width, and building the settings for that is
+/// far more work than the proofs of a single blob, so build them once per trusted setup and reuse
+/// them for every call.
+pub fn new_fk20_settings_for_blob_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20SingleSettings: FK20SingleSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    s: &TKZGSettings,
+) -> Result<TFK20SingleSettings, String> {
+    check_settings_for_blob(s)?;
+
+    let scale = FIELD_ELEMENTS_PER_BLOB.trailing_zeros() as usize;
+    let fs2 = TFFTSettings::new(scale + 1)?;
+    let ks = TKZGSettings::new(
+        s.get_g1_monomial(),
+        s.get_g2_secret(),
+        FIELD_ELEMENTS_PER_BLOB,
+        &fs2,
+    )?;
+
+    TFK20SingleSettings::new(&ks, 2 * FIELD_ELEMENTS_PER_BLOB)
+}
+
+/// Proofs of the blob's values at every point of its domain, in the order of the blob, i.e. the
+/// `i`-th proof opens the blob at the `i`-th of the bit-reversed roots of unity.
+///
+/// Runs FK20 in O(n log n), rather than opening the blob n times over in O(n^2). `fk` must come
+/// from [`new_fk20_settings_for_blob_rust`] over the same settings `s`.
+pub fn compute_all_kzg_proofs_for_blob_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20SingleSettings: FK20SingleSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    blob: &[TFr],
+    s: &TKZGSettings,
+    fk: &TFK20SingleSettings,
+) -> Result<Vec<TG1>, String> {
+    if blob.len() != FIELD_ELEMENTS_PER_BLOB {
+        return Err(String::from("Blob length must be FIELD_ELEMENTS_PER_BLOB"));
+    }
+    check_settings_for_blob(s)?;
+
+    let mut evals = blob.to_vec();
+    reverse_bit_order(&mut evals)?;
+    let polynomial = TPoly::from_coeffs(&s.get_fft_settings().fft_fr(&evals, true)?);
+
+    // Proofs over the domain twice the blob's width, bit-reversed. The first half of them are
+    // at the blob's own points, in the blob's order
+    let mut proofs = fk.data_availability(&polynomial)?;
+    proofs.truncate(FIELD_ELEMENTS_PER_BLOB);
+
+    Ok(proofs)
+}
+
+fn check_settings_for_blob<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    s: &TKZGSettings,
+) -> Result<(), String> {
+    if s.get_g1_monomial().len() < FIELD_ELEMENTS_PER_BLOB
+        || s.get_fft_settings().get_max_width() != FIELD_ELEMENTS_PER_BLOB
+    {
+        return Err(String::from(
+            "Settings must be over FIELD_ELEMENTS_PER_BLOB points",
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn verify_kzg_proof_rust<
     TFr: Fr,
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
@@ -590,9 +670,9 @@ pub fn verify_blob_kzg_proof_batch_rust<
     }
 
     #[cfg(feature = "parallel")]
-    {
+    return ts.get_execution_context().install(|| {
         let num_blobs = blobs.len();
-        let num_cores = num_cpus::get_physical();
+        let num_cores = rayon::current_num_threads();
 
         if num_blobs > num_cores {
             validate_batched_input(commitments_g1, proofs_g1)?;
@@ -635,7 +715,7 @@ pub fn verify_blob_kzg_proof_batch_rust<
                 })
                 .try_reduce(|| true, |a, b| Ok(a && b))
         }
-    }
+    });
 
     #[cfg(not(feature = "parallel"))]
     {
@@ -669,69 +749,29 @@ pub fn bytes_to_blob<TFr: Fr>(bytes: &[u8]) -> Result<Vec<TFr>, String> {
         .collect()
 }
 
-fn fr_batch_inv<TFr: Fr + PartialEq + Copy>(
-    out: &mut [TFr],
-    a: &[TFr],
-    len: usize,
-) -> Result<(), String> {
-    if len == 0 {
-        return Err(String::from("Length is less than 0."));
-    }
-
-    if a == out {
-        return Err(String::from("Destination is the same as source."));
-    }
-
-    let mut accumulator = TFr::one();
-
-    for i in 0..len {
-        out[i] = accumulator;
-        accumulator = accumulator.mul(&a[i]);
-    }
-
-    if accumulator.is_zero() {
-        return Err(String::from("Zero input"));
-    }
-
-    accumulator = accumulator.eucl_inverse();
-
-    for i in (0..len).rev() {
-        out[i] = out[i].mul(&accumulator);
-        accumulator = accumulator.mul(&a[i]);
-    }
-
-    Ok(())
-}
-
 pub fn hash_to_bls_field<TFr: Fr>(x: &[u8; BYTES_PER_FIELD_ELEMENT]) -> TFr {
     TFr::from_bytes_unchecked(x).unwrap()
 }
 
 fn compute_challenge<TFr: Fr, TG1: G1>(blob: &[TFr], commitment: &TG1) -> TFr {
-    let mut bytes: Vec<u8> = vec![0; CHALLENGE_INPUT_SIZE];
-
-    // Copy domain separator
-    bytes[..16].copy_from_slice(&FIAT_SHAMIR_PROTOCOL_DOMAIN);
-    // Set all other bytes of this 16-byte (big-endian) field to zero
-    bytes_of_uint64(&mut bytes[16..24], 0);
-    bytes_of_uint64(&mut bytes[24..32], FIELD_ELEMENTS_PER_BLOB as u64);
-
-    for (i, field) in blob.iter().enumerate() {
-        let v = field.to_bytes();
-        let size = (32 + i * BYTES_PER_FIELD_ELEMENT)..(32 + (i + 1) * BYTES_PER_FIELD_ELEMENT);
-
-        bytes[size].copy_from_slice(&v);
+    let mut transcript =
+        Sha256Transcript::legacy(&FIAT_SHAMIR_PROTOCOL_DOMAIN, CHALLENGE_INPUT_SIZE);
+    // The degree is a 16-byte big-endian field, the upper half of it zero
+    transcript.append_u64(b"degree_high", 0);
+    transcript.append_u64(b"degree", FIELD_ELEMENTS_PER_BLOB as u64);
+
+    for field in blob {
+        transcript.append_scalar(b"blob", field);
     }
-
-    // Copy commitment
-    let v = commitment.to_bytes();
-    for i in 0..v.len() {
-        bytes[32 + BYTES_PER_BLOB + i] = v[i];
+    // Blobs of the wrong length get rejected later, short ones are hashed padded with zeroes
+    for _ in blob.len()..FIELD_ELEMENTS_PER_BLOB {
+        transcript.append_scalar(b"blob", &TFr::zero());
     }
 
+    transcript.append_commitment(b"commitment", commitment);
+
     // Now let's create the challenge!
-    let eval_challenge = hash(&bytes);
-    hash_to_bls_field(&eval_challenge)
+    transcript.challenge_scalar(b"z")
 }
 
 pub fn blob_to_polynomial<TFr: Fr, TPoly: Poly<TFr>>(blob: &[TFr]) -> Result<TPoly, String> {
@@ -759,39 +799,9 @@ pub fn evaluate_polynomial_in_evaluation_form<
         return Err(String::from("Incorrect field elements count."));
     }
 
-    let mut inverses_in: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-    let mut inverses: Vec<TFr> = vec![TFr::default(); FIELD_ELEMENTS_PER_BLOB];
-
-    let roots_of_unity = s.get_fft_settings().get_roots_of_unity();
-    let poly_coeffs = p.get_coeffs();
+    let roots_of_unity = &s.get_fft_settings().get_roots_of_unity()[..FIELD_ELEMENTS_PER_BLOB];
 
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        if x == &roots_of_unity[i] {
-            return Ok(poly_coeffs[i]);
-        }
-        inverses_in[i] = x.sub(&roots_of_unity[i]);
-    }
-
-    fr_batch_inv(&mut inverses, &inverses_in, FIELD_ELEMENTS_PER_BLOB)?;
-
-    let mut tmp: TFr;
-    let mut out = TFr::zero();
-
-    for i in 0..FIELD_ELEMENTS_PER_BLOB {
-        tmp = inverses[i].mul(&roots_of_unity[i]);
-        tmp = tmp.mul(&poly_coeffs[i]);
-        out = out.add(&tmp);
-    }
-
-    tmp = TFr::from_u64(FIELD_ELEMENTS_PER_BLOB as u64);
-    out = match out.div(&tmp) {
-        Ok(value) => value,
-        Err(err) => return Err(err),
-    };
-    tmp = x.pow(FIELD_ELEMENTS_PER_BLOB);
-    tmp = tmp.sub(&TFr::one());
-    out = out.mul(&tmp);
-    Ok(out)
+    PolyEval::from_evals(p.get_coeffs())?.evaluate(roots_of_unity, x)
 }
 
 fn is_trusted_setup_in_lagrange_form<TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2>(
@@ -807,20 +817,10 @@ fn is_trusted_setup_in_lagrange_form<TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2>
     !is_monotomial_form
 }
 
-#[allow(clippy::useless_conversion)]
-pub fn load_trusted_setup_rust<
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
-    TG2: G2,
-    TFFTSettings: FFTSettings<TFr>,
-    TPoly: Poly<TFr>,
-    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
-    TG1Fp: G1Fp,
-    TG1Affine: G1Affine<TG1, TG1Fp>,
->(
+fn parse_trusted_setup_points<TG1: G1 + PairingVerify<TG1, TG2>, TG2: G2>(
     g1_bytes: &[u8],
     g2_bytes: &[u8],
-) -> Result<TKZGSettings, String> {
+) -> Result<(Vec<TG1>, Vec<TG2>), String> {
     let num_g1_points = g1_bytes.len() / BYTES_PER_G1;
     if num_g1_points != FIELD_ELEMENTS_PER_BLOB {
         return Err(String::from("Invalid number of G1 points"));
@@ -831,7 +831,7 @@ pub fn load_trusted_setup_rust<
         return Err(String::from("Invalid number of G2 points"));
     }
 
-    let mut g1_values = g1_bytes
+    let g1_values = g1_bytes
         .chunks(BYTES_PER_G1)
         .map(TG1::from_bytes)
         .collect::<Result<Vec<TG1>, String>>()?;
@@ -846,12 +846,300 @@ pub fn load_trusted_setup_rust<
         return Err(String::from("Trusted setup is not in Lagrange form"));
     }
 
+    Ok((g1_values, g2_values))
+}
+
+fn trusted_setup_max_scale(num_g1_points: usize) -> usize {
     let mut max_scale: usize = 0;
     while (1 << max_scale) < num_g1_points {
         max_scale += 1;
     }
+    max_scale
+}
+
+/// Settings for the setup with Lagrange-form G1 points `g1_bytes`, in natural order, and G2
+/// points `g2_bytes`. The monomial G1 points are derived from the Lagrange-form ones the first
+/// time they are needed.
+#[allow(clippy::useless_conversion)]
+pub fn load_trusted_setup_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+) -> Result<TKZGSettings, String> {
+    let (mut g1_values, g2_values) = parse_trusted_setup_points::<TG1, TG2>(g1_bytes, g2_bytes)?;
+
+    let max_scale = trusted_setup_max_scale(g1_values.len());
+    let fs = TFFTSettings::new(max_scale)?;
+
+    reverse_bit_order(&mut g1_values)?;
+    TKZGSettings::new_with_monomial(
+        g1_values.as_slice(),
+        None,
+        g2_values.as_slice(),
+        max_scale,
+        &fs,
+    )
+}
+
+/// Same as [`load_trusted_setup_rust`], with the monomial G1 points `g1_monomial_bytes` newer
+/// setup files list after the G2 points. They are checked to match the Lagrange-form points. If
+/// empty, the monomial points are derived as for [`load_trusted_setup_rust`].
+#[allow(clippy::useless_conversion)]
+pub fn load_trusted_setup_with_monomial_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    g1_bytes: &[u8],
+    g2_bytes: &[u8],
+    g1_monomial_bytes: &[u8],
+) -> Result<TKZGSettings, String> {
+    if g1_monomial_bytes.is_empty() {
+        return load_trusted_setup_rust::<
+            TFr,
+            TG1,
+            TG2,
+            TFFTSettings,
+            TPoly,
+            TKZGSettings,
+            TG1Fp,
+            TG1Affine,
+        >(g1_bytes, g2_bytes);
+    }
+
+    let (mut g1_values, g2_values) = parse_trusted_setup_points::<TG1, TG2>(g1_bytes, g2_bytes)?;
+
+    if g1_monomial_bytes.len() != g1_bytes.len() {
+        return Err(String::from("Invalid number of monomial G1 points"));
+    }
+
+    let g1_monomial_values = g1_monomial_bytes
+        .chunks(BYTES_PER_G1)
+        .map(TG1::from_bytes)
+        .collect::<Result<Vec<TG1>, String>>()?;
 
+    let max_scale = trusted_setup_max_scale(g1_values.len());
     let fs = TFFTSettings::new(max_scale)?;
+
+    let mut transcript = Vec::with_capacity(g1_bytes.len() + g1_monomial_bytes.len());
+    transcript.extend_from_slice(g1_bytes);
+    transcript.extend_from_slice(g1_monomial_bytes);
+    let x = hash_to_bls_field::<TFr>(&hash(&transcript));
+    if !is_monomial_form_of::<TFr, TG1, TFFTSettings, TG1Fp, TG1Affine>(
+        &g1_monomial_values,
+        &g1_values,
+        &x,
+        &fs,
+    )? {
+        return Err(String::from(
+            "Monomial G1 points do not match the Lagrange-form ones",
+        ));
+    }
+
     reverse_bit_order(&mut g1_values)?;
-    TKZGSettings::new(g1_values.as_slice(), g2_values.as_slice(), max_scale, &fs)
+    TKZGSettings::new_with_monomial(
+        g1_values.as_slice(),
+        Some(g1_monomial_values.as_slice()),
+        g2_values.as_slice(),
+        max_scale,
+        &fs,
+    )
+}
+
+/// Whether `monomial` are the FFT of `lagrange`, i.e. `[τ^i] = sum(ω^(ij) * [L_j(τ)])`. Rather
+/// than an FFT in G1, both sides are combined with the powers `r` of `x` and compared, as
+/// `sum(r_i * [τ^i]) = sum(FFT(r)_j * [L_j(τ)])`. Unless they match, that holds for at most
+/// `monomial.len() - 1` values of `x`, so `x` must be derived from the points.
+fn is_monomial_form_of<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + G1LinComb<TFr, TG1Fp, TG1Affine>,
+    TFFTSettings: FFTFr<TFr>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    monomial: &[TG1],
+    lagrange: &[TG1],
+    x: &TFr,
+    fs: &TFFTSettings,
+) -> Result<bool, String> {
+    if monomial.len() != lagrange.len() {
+        return Ok(false);
+    }
+
+    let powers = compute_powers(x, monomial.len());
+    let powers_fft = fs.fft_fr(&powers, false)?;
+
+    let lhs = TG1::g1_lincomb(monomial, &powers, powers.len(), None);
+    let rhs = TG1::g1_lincomb(lagrange, &powers_fft, powers_fft.len(), None);
+    Ok(lhs.equals(&rhs))
+}
+
+/// `[τ^i]` from the Lagrange-form points `lagrange_brp` of the same setup, in bit-reversed order,
+/// as `[τ^i] = sum(ω^(ij) * [L_j(τ)])`. This is what settings made without monomial points
+/// derive them with.
+pub fn g1_monomial_from_lagrange_brp<TG1: G1, TFFTSettings: FFTG1<TG1>>(
+    lagrange_brp: &[TG1],
+    fs: &TFFTSettings,
+) -> Result<Vec<TG1>, String> {
+    let mut lagrange = lagrange_brp.to_vec();
+    reverse_bit_order(&mut lagrange)?;
+    fs.fft_g1(&lagrange, false)
+}
+
+////////////////////////////// Verification with a verifying key //////////////////////////////
+
+/// Everything verifying EIP-4844 proofs takes: the generators and `[τ]` in G2, as a
+/// [`KzgVerifierKey`], and the root of unity generating the blob's domain. Unlike
+/// [`KZGSettings`], it holds none of the setup's G1 points, and [`Self::embedded`] makes it from
+/// constants, without a trusted setup file.
+#[derive(Debug, Clone)]
+pub struct VerifyingKey<TFr, TG1, TG2> {
+    pub kzg: KzgVerifierKey<TG1, TG2>,
+    pub root_of_unity: TFr,
+    /// The blob's domain, computed on first use. Without `std` there is no cell to keep it in,
+    /// and every call computes it afresh.
+    #[cfg(feature = "std")]
+    roots_of_unity: std::sync::OnceLock<Vec<TFr>>,
+}
+
+impl<TFr: PartialEq, TG1: PartialEq, TG2: PartialEq> PartialEq for VerifyingKey<TFr, TG1, TG2> {
+    fn eq(&self, other: &Self) -> bool {
+        self.kzg == other.kzg && self.root_of_unity == other.root_of_unity
+    }
+}
+
+impl<TFr: Fr, TG1: G1, TG2: G2> VerifyingKey<TFr, TG1, TG2> {
+    pub fn new(kzg: KzgVerifierKey<TG1, TG2>, root_of_unity: TFr) -> Result<Self, String> {
+        let half = root_of_unity.pow(FIELD_ELEMENTS_PER_BLOB / 2);
+        if !half.sqr().is_one() || half.is_one() {
+            return Err(String::from(
+                "Root of unity must be of order FIELD_ELEMENTS_PER_BLOB",
+            ));
+        }
+
+        Ok(Self {
+            kzg,
+            root_of_unity,
+            #[cfg(feature = "std")]
+            roots_of_unity: Default::default(),
+        })
+    }
+
+    /// The key for the EIP-4844 ceremony, from [`TRUSTED_SETUP_TAU_G2`] and
+    /// [`BLOB_ROOT_OF_UNITY`].
+    pub fn embedded() -> Result<Self, String> {
+        let kzg = KzgVerifierKey {
+            g1: TG1::generator(),
+            g2: TG2::generator(),
+            tau_g2: TG2::from_bytes(&TRUSTED_SETUP_TAU_G2)?,
+        };
+
+        Self::new(kzg, TFr::from_bytes(&BLOB_ROOT_OF_UNITY)?)
+    }
+
+    /// The key for the setup `s` was loaded from.
+    pub fn from_settings<
+        TFFTSettings: FFTSettings<TFr>,
+        TPoly: Poly<TFr>,
+        TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+    >(
+        s: &TKZGSettings,
+    ) -> Result<Self, String>
+    where
+        TG1: G1Mul<TFr> + G1GetFp<TG1Fp>,
+    {
+        let fs = s.get_fft_settings();
+        if fs.get_max_width() != FIELD_ELEMENTS_PER_BLOB {
+            return Err(String::from(
+                "Settings must be over FIELD_ELEMENTS_PER_BLOB points",
+            ));
+        }
+
+        // Not the first G1 point, as settings loaded through the C API only have Lagrange ones
+        let g2 = s.get_g2_secret();
+        let kzg = KzgVerifierKey::from_setup(&[TG1::generator()], g2)?;
+
+        Self::new(kzg, fs.get_expanded_roots_of_unity_at(1))
+    }
+
+    /// The points of the blob's domain in bit-reversed order, computed on the first call.
+    pub fn roots_of_unity(&self) -> Result<Cow<'_, [TFr]>, String> {
+        #[cfg(feature = "std")]
+        if let Some(roots) = self.roots_of_unity.get() {
+            return Ok(Cow::Borrowed(roots));
+        }
+
+        let mut roots = compute_powers(&self.root_of_unity, FIELD_ELEMENTS_PER_BLOB);
+        reverse_bit_order(&mut roots)?;
+
+        #[cfg(feature = "std")]
+        return Ok(Cow::Borrowed(self.roots_of_unity.get_or_init(|| roots)));
+
+        #[cfg(not(feature = "std"))]
+        Ok(Cow::Owned(roots))
+    }
+}
+
+/// Same as [`verify_kzg_proof_rust`], with a [`VerifyingKey`] in place of the settings.
+pub fn verify_kzg_proof_with_key_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+>(
+    commitment: &TG1,
+    z: &TFr,
+    y: &TFr,
+    proof: &TG1,
+    vk: &VerifyingKey<TFr, TG1, TG2>,
+) -> Result<bool, String> {
+    if !commitment.is_inf() && !commitment.is_valid() {
+        return Err("Invalid commitment".to_string());
+    }
+    if !proof.is_inf() && !proof.is_valid() {
+        return Err("Invalid proof".to_string());
+    }
+
+    Ok(vk.kzg.check_proof_single(commitment, proof, z, y))
+}
+
+/// Same as [`verify_blob_kzg_proof_rust`], with a [`VerifyingKey`] in place of the settings.
+pub fn verify_blob_kzg_proof_with_key_rust<
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+>(
+    blob: &[TFr],
+    commitment_g1: &TG1,
+    proof_g1: &TG1,
+    vk: &VerifyingKey<TFr, TG1, TG2>,
+) -> Result<bool, String> {
+    if !commitment_g1.is_inf() && !commitment_g1.is_valid() {
+        return Err("Invalid commitment".to_string());
+    }
+    if !proof_g1.is_inf() && !proof_g1.is_valid() {
+        return Err("Invalid proof".to_string());
+    }
+    if blob.len() != FIELD_ELEMENTS_PER_BLOB {
+        return Err(String::from("Blob length must be FIELD_ELEMENTS_PER_BLOB"));
+    }
+
+    let evaluation_challenge_fr = compute_challenge(blob, commitment_g1);
+    let y_fr =
+        PolyEval::from_evals(blob)?.evaluate(&vk.roots_of_unity()?, &evaluation_challenge_fr)?;
+    verify_kzg_proof_with_key_rust(commitment_g1, &evaluation_challenge_fr, &y_fr, proof_g1, vk)
 }
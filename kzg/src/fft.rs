@@ -0,0 +1,763 @@
+//! In-place iterative FFTs.
+//!
+//! [`fft_fr_dif`] takes its input in natural order and leaves the output in bit-reversed order,
+//! [`fft_fr_dit`] takes bit-reversed input and produces natural order. Running a transform with
+//! one and its inverse with the other therefore needs no permutation at all, as long as whatever
+//! happens in between (pointwise products, for example) does not care about the order. The
+//! `_inplace` functions combine them with a bit-reversal for callers that want natural order on
+//! both sides.
+//!
+//! The `coset_` functions transform over a coset `shift * w^i` of the usual domain instead, by
+//! scaling the coefficients with powers of the shift, which the settings cache.
+//!
+//! Both pass over `data` in contiguous blocks, which keeps large transforms far friendlier to the
+//! cache than strided recursion. Stages are done two at a time as radix-4 passes, with a single
+//! radix-2 stage to make up odd sizes, so the data is only walked half as often.
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::common_utils::reverse_bit_order;
+use crate::{FFTFr, FFTSettings, Fr, G1Mul, FFTG1, G1};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Stages with fewer butterflies than this are not worth splitting across threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1 << 10;
+
+/// Run one stage of butterflies over blocks of `2 * half` elements, calling
+/// `butterfly(lo, hi, twiddle)` for the `j`-th element of each half with `roots[j * roots_stride]`
+#[allow(unused_variables)]
+fn radix2_stage<T: Send, TFr: Fr>(
+    data: &mut [T],
+    half: usize,
+    roots: &[TFr],
+    roots_stride: usize,
+    butterfly: &(impl Fn(&mut T, &mut T, &TFr) + Sync),
+) {
+    let block = |block: &mut [T]| {
+        let (lo, hi) = block.split_at_mut(half);
+        for (j, (lo, hi)) in lo.iter_mut().zip(hi.iter_mut()).enumerate() {
+            butterfly(lo, hi, &roots[j * roots_stride]);
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        if data.len() >= PARALLEL_THRESHOLD {
+            if half >= PARALLEL_THRESHOLD {
+                // Few large blocks, so split each block instead
+                for block in data.chunks_mut(2 * half) {
+                    let (lo, hi) = block.split_at_mut(half);
+                    lo.par_iter_mut()
+                        .zip(hi.par_iter_mut())
+                        .enumerate()
+                        .for_each(|(j, (lo, hi))| butterfly(lo, hi, &roots[j * roots_stride]));
+                }
+            } else {
+                data.par_chunks_mut(2 * half).for_each(block);
+            }
+            return;
+        }
+    }
+
+    data.chunks_mut(2 * half).for_each(block);
+}
+
+/// Run two consecutive stages in one pass over blocks of `4 * quarter` elements, i.e. the stage
+/// with `2 * quarter` sized halves and `roots_stride`, and the one with `quarter` sized halves and
+/// twice the stride. With `dif` the larger stage goes first, otherwise the smaller one does.
+#[allow(unused_variables)]
+fn radix4_stage<T: Send, TFr: Fr>(
+    data: &mut [T],
+    quarter: usize,
+    roots: &[TFr],
+    roots_stride: usize,
+    butterfly: &(impl Fn(&mut T, &mut T, &TFr) + Sync),
+    dif: bool,
+) {
+    let quad = |j: usize, a: &mut T, b: &mut T, c: &mut T, d: &mut T| {
+        let outer_lo = &roots[j * roots_stride];
+        let outer_hi = &roots[(j + quarter) * roots_stride];
+        let inner = &roots[2 * j * roots_stride];
+
+        if dif {
+            butterfly(a, c, outer_lo);
+            butterfly(b, d, outer_hi);
+            butterfly(a, b, inner);
+            butterfly(c, d, inner);
+        } else {
+            butterfly(a, b, inner);
+            butterfly(c, d, inner);
+            butterfly(a, c, outer_lo);
+            butterfly(b, d, outer_hi);
+        }
+    };
+
+    let block = |block: &mut [T]| {
+        let (ab, cd) = block.split_at_mut(2 * quarter);
+        let (a, b) = ab.split_at_mut(quarter);
+        let (c, d) = cd.split_at_mut(quarter);
+        for (j, (((a, b), c), d)) in a
+            .iter_mut()
+            .zip(b.iter_mut())
+            .zip(c.iter_mut())
+            .zip(d.iter_mut())
+            .enumerate()
+        {
+            quad(j, a, b, c, d);
+        }
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        if data.len() >= PARALLEL_THRESHOLD {
+            if quarter >= PARALLEL_THRESHOLD {
+                // Few large blocks, so split each block instead
+                for block in data.chunks_mut(4 * quarter) {
+                    let (ab, cd) = block.split_at_mut(2 * quarter);
+                    let (a, b) = ab.split_at_mut(quarter);
+                    let (c, d) = cd.split_at_mut(quarter);
+                    a.par_iter_mut()
+                        .zip(b.par_iter_mut())
+                        .zip(c.par_iter_mut())
+                        .zip(d.par_iter_mut())
+                        .enumerate()
+                        .for_each(|(j, (((a, b), c), d))| quad(j, a, b, c, d));
+                }
+            } else {
+                data.par_chunks_mut(4 * quarter).for_each(block);
+            }
+            return;
+        }
+    }
+
+    data.chunks_mut(4 * quarter).for_each(block);
+}
+
+/// Decimation in frequency: natural order in, bit-reversed order out
+fn dif<T: Send, TFr: Fr>(
+    data: &mut [T],
+    roots: &[TFr],
+    roots_stride: usize,
+    butterfly: impl Fn(&mut T, &mut T, &TFr) + Sync,
+) {
+    let n = data.len();
+    let mut half = n / 2;
+    while half >= 2 {
+        let stride = roots_stride * (n / (2 * half));
+        radix4_stage(data, half / 2, roots, stride, &butterfly, true);
+        half /= 4;
+    }
+    if half == 1 {
+        radix2_stage(data, 1, roots, roots_stride * (n / 2), &butterfly);
+    }
+}
+
+/// Decimation in time: bit-reversed order in, natural order out
+fn dit<T: Send, TFr: Fr>(
+    data: &mut [T],
+    roots: &[TFr],
+    roots_stride: usize,
+    butterfly: impl Fn(&mut T, &mut T, &TFr) + Sync,
+) {
+    let n = data.len();
+    let mut half = 1;
+    if n.trailing_zeros() % 2 == 1 {
+        radix2_stage(data, 1, roots, roots_stride * (n / 2), &butterfly);
+        half = 2;
+    }
+    while half < n {
+        let stride = roots_stride * (n / (4 * half));
+        radix4_stage(data, half, roots, stride, &butterfly, false);
+        half *= 4;
+    }
+}
+
+/// Unscaled transform of `data` over `roots[i * roots_stride]`, where `roots` holds the powers of
+/// a `data.len() * roots_stride`-th root of unity. Output is in bit-reversed order.
+pub fn fft_fr_dif<TFr: Fr + Send>(data: &mut [TFr], roots: &[TFr], roots_stride: usize) {
+    dif(data, roots, roots_stride, |lo, hi, root| {
+        let diff = lo.sub(hi);
+        *lo = lo.add(hi);
+        *hi = diff.mul(root);
+    });
+}
+
+/// Counterpart of [`fft_fr_dif`] for bit-reversed input. Output is in natural order.
+pub fn fft_fr_dit<TFr: Fr + Send>(data: &mut [TFr], roots: &[TFr], roots_stride: usize) {
+    dit(data, roots, roots_stride, |lo, hi, root| {
+        let hi_times_root = hi.mul(root);
+        *hi = lo.sub(&hi_times_root);
+        *lo = lo.add(&hi_times_root);
+    });
+}
+
+/// [`fft_fr_dif`] over G1
+pub fn fft_g1_dif<TFr: Fr, TG1: G1 + G1Mul<TFr>>(
+    data: &mut [TG1],
+    roots: &[TFr],
+    roots_stride: usize,
+) {
+    dif(data, roots, roots_stride, |lo, hi, root| {
+        let diff = lo.sub(hi);
+        *lo = lo.add_or_dbl(hi);
+        *hi = diff.mul(root);
+    });
+}
+
+/// [`fft_fr_dit`] over G1
+pub fn fft_g1_dit<TFr: Fr, TG1: G1 + G1Mul<TFr>>(
+    data: &mut [TG1],
+    roots: &[TFr],
+    roots_stride: usize,
+) {
+    dit(data, roots, roots_stride, |lo, hi, root| {
+        let hi_times_root = hi.mul(root);
+        *hi = lo.sub(&hi_times_root);
+        *lo = lo.add_or_dbl(&hi_times_root);
+    });
+}
+
+/// Roots for a transform of `len` elements, together with their stride
+fn transform_roots<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    len: usize,
+    inverse: bool,
+) -> Result<(&[TFr], usize), String> {
+    if len > fs.get_max_width() {
+        return Err(String::from(
+            "Supplied list is longer than the available max width",
+        ));
+    }
+    if !len.is_power_of_two() {
+        return Err(String::from("A list with power-of-two length expected"));
+    }
+
+    // Inverse is same as regular, but all constants are reversed and results are divided by n
+    let roots = if inverse {
+        fs.get_reversed_roots_of_unity()
+    } else {
+        fs.get_expanded_roots_of_unity()
+    };
+
+    Ok((roots, fs.get_max_width() / len))
+}
+
+/// In-place FFT of `data` over the roots in `fs`, natural order in and out, run in the execution
+/// context of `fs`
+pub fn fft_fr_inplace<TFr: Fr + Send, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    data: &mut [TFr],
+    inverse: bool,
+) -> Result<(), String> {
+    let (roots, stride) = transform_roots(fs, data.len(), inverse)?;
+    let transform = |data: &mut [TFr]| {
+        fft_fr_dif(data, roots, stride);
+        reverse_bit_order(data)?;
+
+        if inverse {
+            let inv_len = TFr::from_u64(data.len() as u64).inverse();
+            data.iter_mut().for_each(|f| *f = f.mul(&inv_len));
+        }
+
+        Ok(())
+    };
+
+    #[cfg(feature = "parallel")]
+    return fs.get_execution_context().install(|| transform(data));
+
+    #[cfg(not(feature = "parallel"))]
+    transform(data)
+}
+
+/// In-place FFT of `data` over the roots in `fs`, natural order in and out, run in the execution
+/// context of `fs`
+pub fn fft_g1_inplace<TFr: Fr, TG1: G1 + G1Mul<TFr>, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    data: &mut [TG1],
+    inverse: bool,
+) -> Result<(), String> {
+    let (roots, stride) = transform_roots(fs, data.len(), inverse)?;
+    let transform = |data: &mut [TG1]| {
+        fft_g1_dif(data, roots, stride);
+        reverse_bit_order(data)?;
+
+        if inverse {
+            let inv_len = TFr::from_u64(data.len() as u64).inverse();
+            data.iter_mut().for_each(|f| *f = f.mul(&inv_len));
+        }
+
+        Ok(())
+    };
+
+    #[cfg(feature = "parallel")]
+    return fs.get_execution_context().install(|| transform(data));
+
+    #[cfg(not(feature = "parallel"))]
+    transform(data)
+}
+
+/// Turn `evens`, the values of a polynomial of degree below `n = evens.len()` at the even powers
+/// of a `2n`-th root of unity, into its values at the odd powers.
+///
+/// The inverse transform of the evens leaves `n` times the coefficients in bit-reversed order.
+/// Evaluating at the odd powers `w^(2i + 1)` is the same as evaluating the polynomial with
+/// coefficients `c_j * w^j` at the even powers, so the coefficients are scaled where they lie and
+/// transformed forward again, which brings them back to natural order. `evens.len()` must be a
+/// power of two no larger than half the width of `fs`.
+pub fn das_fft_extension<TFr: Fr + Send, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    evens: &mut [TFr],
+) {
+    let n = evens.len();
+    let stride = fs.get_max_width() / (2 * n);
+    let expanded_roots = fs.get_expanded_roots_of_unity();
+
+    fft_fr_dif(evens, fs.get_reversed_roots_of_unity(), 2 * stride);
+
+    let inv_len = TFr::from_u64(n as u64).inverse();
+    let unused_bit_len = n.leading_zeros() + 1;
+    for (i, coeff) in evens.iter_mut().enumerate() {
+        let j = i.reverse_bits().checked_shr(unused_bit_len).unwrap_or(0);
+        let shift = expanded_roots[j * stride].mul(&inv_len);
+        *coeff = coeff.mul(&shift);
+    }
+
+    fft_fr_dit(evens, expanded_roots, 2 * stride);
+}
+
+/// Powers `shift^i` and `shift^-i` of a coset shift, for `i` below the length they were
+/// computed for
+#[derive(Debug, Clone)]
+pub struct ShiftPowers<TFr> {
+    pub shift: TFr,
+    pub powers: Vec<TFr>,
+    pub inverse_powers: Vec<TFr>,
+}
+
+impl<TFr: Fr> ShiftPowers<TFr> {
+    pub fn new(shift: &TFr, len: usize) -> Self {
+        let powers_of = |x: &TFr| {
+            let mut powers = Vec::with_capacity(len);
+            let mut power = TFr::one();
+            for _ in 0..len {
+                powers.push(power.clone());
+                power = power.mul(x);
+            }
+            powers
+        };
+
+        Self {
+            shift: shift.clone(),
+            powers: powers_of(shift),
+            inverse_powers: powers_of(&shift.inverse()),
+        }
+    }
+}
+
+/// How many shifts a [`CosetCache`] holds before it drops the oldest
+#[cfg(feature = "std")]
+const COSET_CACHE_SIZE: usize = 8;
+
+/// The shift powers FFT settings have handed out, so that transforms over the same coset (every
+/// recovery uses the same one, for example) only compute them once. Only the most recent few
+/// shifts are kept. Without `std` there is no lock to keep them behind, and every lookup computes
+/// the powers afresh.
+pub struct CosetCache<TFr> {
+    #[cfg(feature = "std")]
+    entries: std::sync::RwLock<Vec<Arc<ShiftPowers<TFr>>>>,
+    #[cfg(not(feature = "std"))]
+    entries: core::marker::PhantomData<TFr>,
+}
+
+impl<TFr: Fr> CosetCache<TFr> {
+    /// Powers of `shift`, at least `len` of them
+    pub fn get(&self, shift: &TFr, len: usize) -> Arc<ShiftPowers<TFr>> {
+        #[cfg(feature = "std")]
+        {
+            let cached = self
+                .entries
+                .read()
+                .unwrap_or_else(|err| err.into_inner())
+                .iter()
+                .find(|entry| entry.shift.equals(shift) && entry.powers.len() >= len)
+                .cloned();
+            if let Some(cached) = cached {
+                return cached;
+            }
+
+            let computed = Arc::new(ShiftPowers::new(shift, len));
+
+            let mut entries = self.entries.write().unwrap_or_else(|err| err.into_inner());
+            entries.retain(|entry| !entry.shift.equals(shift));
+            if entries.len() >= COSET_CACHE_SIZE {
+                entries.remove(0);
+            }
+            entries.push(computed.clone());
+
+            computed
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            Arc::new(ShiftPowers::new(shift, len))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn entries(&self) -> Vec<Arc<ShiftPowers<TFr>>> {
+        self.entries
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+}
+
+impl<TFr> Default for CosetCache<TFr> {
+    fn default() -> Self {
+        Self {
+            entries: Default::default(),
+        }
+    }
+}
+
+impl<TFr: Fr> Clone for CosetCache<TFr> {
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(feature = "std")]
+            entries: std::sync::RwLock::new(self.entries()),
+            #[cfg(not(feature = "std"))]
+            entries: Default::default(),
+        }
+    }
+}
+
+impl<TFr: Fr> fmt::Debug for CosetCache<TFr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(feature = "std")]
+        {
+            write!(f, "CosetCache({} shifts)", self.entries().len())
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            f.write_str("CosetCache")
+        }
+    }
+}
+
+/// Shift powers for a coset transform of `len` elements
+fn coset_shift_powers<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    shift: &TFr,
+    len: usize,
+) -> Result<Arc<ShiftPowers<TFr>>, String> {
+    if shift.is_zero() {
+        return Err(String::from("Coset shift must not be zero"));
+    }
+    if len > fs.get_max_width() {
+        return Err(String::from(
+            "Supplied list is longer than the available max width",
+        ));
+    }
+
+    Ok(fs.get_coset_shift_powers(shift, len))
+}
+
+/// FFT over the coset `shift * w^i`. Forward, it evaluates the polynomial with coefficients
+/// `data` over the coset; inverse, it interpolates values given over the coset.
+pub fn coset_fft_fr<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    data: &[TFr],
+    shift: &TFr,
+    inverse: bool,
+) -> Result<Vec<TFr>, String> {
+    let shift_powers = coset_shift_powers(fs, shift, data.len())?;
+
+    if inverse {
+        let mut coeffs = data.to_vec();
+        fs.fft_fr_inplace(&mut coeffs, true)?;
+        coeffs
+            .iter_mut()
+            .zip(&shift_powers.inverse_powers)
+            .for_each(|(coeff, power)| *coeff = coeff.mul(power));
+        Ok(coeffs)
+    } else {
+        let mut evals = data
+            .iter()
+            .zip(&shift_powers.powers)
+            .map(|(coeff, power)| coeff.mul(power))
+            .collect::<Vec<_>>();
+        fs.fft_fr_inplace(&mut evals, false)?;
+        Ok(evals)
+    }
+}
+
+/// [`coset_fft_fr`] over G1
+pub fn coset_fft_g1<TFr: Fr, TG1: G1 + G1Mul<TFr>, TFFTSettings: FFTSettings<TFr> + FFTG1<TG1>>(
+    fs: &TFFTSettings,
+    data: &[TG1],
+    shift: &TFr,
+    inverse: bool,
+) -> Result<Vec<TG1>, String> {
+    let shift_powers = coset_shift_powers(fs, shift, data.len())?;
+
+    if inverse {
+        let mut coeffs = data.to_vec();
+        fs.fft_g1_inplace(&mut coeffs, true)?;
+        coeffs
+            .iter_mut()
+            .zip(&shift_powers.inverse_powers)
+            .for_each(|(coeff, power)| *coeff = coeff.mul(power));
+        Ok(coeffs)
+    } else {
+        let mut evals = data
+            .iter()
+            .zip(&shift_powers.powers)
+            .map(|(coeff, power)| coeff.mul(power))
+            .collect::<Vec<_>>();
+        fs.fft_g1_inplace(&mut evals, false)?;
+        Ok(evals)
+    }
+}
+
+/// Big-endian bytes of the primitive cube root of unity `7^((r - 1) / 3)`. Besides its `2^32`,
+/// `r - 1` has a single factor of three that FFTs can use, which gives domains of size `3 * 2^k`.
+const CUBE_ROOT_OF_UNITY: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xac, 0x45, 0xa4, 0x01, 0x00, 0x01, 0xa4, 0x02, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff,
+];
+
+/// `m` for domains of size `3 * m`, `m` a power of two
+fn radix3_split(size: usize) -> Option<usize> {
+    let m = size / 3;
+    (3 * m == size && m.is_power_of_two()).then_some(m)
+}
+
+/// Primitive `size`-th root of unity `w`, for sizes that are a power of two or three times one.
+///
+/// Power-of-two sizes use the root the settings already have. For `size = 3m`, `w` is chosen so
+/// that `w^3` is the settings' `m`-th root, so the power-of-two transforms of the settings can be
+/// reused for the radix-3 one, and so that every power-of-two subgroup of the domain is the
+/// domain of the same size from the settings.
+pub fn mixed_radix_root<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    size: usize,
+) -> Result<TFr, String> {
+    let max_width = fs.get_max_width();
+    let expanded_roots = fs.get_expanded_roots_of_unity();
+
+    if size.is_power_of_two() {
+        if size > max_width {
+            return Err(String::from(
+                "Domain size must be less than or equal to FFTSettings max width",
+            ));
+        }
+        return Ok(expanded_roots[max_width / size].clone());
+    }
+
+    let m = radix3_split(size)
+        .ok_or_else(|| String::from("Domain size must be a power of 2 or 3 times a power of 2"))?;
+    if m > max_width {
+        return Err(String::from(
+            "Domain size must be at most 3 times FFTSettings max width",
+        ));
+    }
+
+    // w = c * v^b, with c a primitive cube root, v the m-th root and 3b = 1 (mod m). Then
+    // w^3 = v and w^m = c^m, which is primitive as m is not a multiple of 3
+    let b = if m % 3 == 2 {
+        (m + 1) / 3
+    } else {
+        (2 * m + 1) / 3
+    };
+    let cube_root = TFr::from_bytes(&CUBE_ROOT_OF_UNITY)?;
+
+    Ok(cube_root.mul(&expanded_roots[(b * (max_width / m)) % max_width]))
+}
+
+/// Points `w^i` of the domain of size `size`, in natural order. See [`mixed_radix_root`].
+pub fn mixed_radix_domain<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    size: usize,
+) -> Result<Vec<TFr>, String> {
+    let root = mixed_radix_root(fs, size)?;
+
+    let mut domain = Vec::with_capacity(size);
+    let mut point = TFr::one();
+    for _ in 0..size {
+        domain.push(point.clone());
+        point = point.mul(&root);
+    }
+
+    Ok(domain)
+}
+
+/// FFT over the domain of [`mixed_radix_root`], for data of any length it supports.
+///
+/// Power-of-two lengths go straight to `fft_fr`. Lengths `3m` take one radix-3 step on top:
+/// the three interleaved thirds of `data` are transformed over the `m`-th roots, and output
+/// `k + tm` combines their `k`-th values with twiddles `w^(jk)` and cube roots `c^(jt)`.
+pub fn fft_fr_mixed<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    data: &[TFr],
+    inverse: bool,
+) -> Result<Vec<TFr>, String> {
+    let n = data.len();
+    if n.is_power_of_two() {
+        return fs.fft_fr(data, inverse);
+    }
+
+    let root = mixed_radix_root(fs, n)?;
+    let root = if inverse { root.inverse() } else { root };
+    let m = n / 3;
+
+    let thirds = (0..3)
+        .map(|j| {
+            let third = data.iter().skip(j).step_by(3).cloned().collect::<Vec<_>>();
+            fs.fft_fr(&third, inverse)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let cube_root = root.pow(m);
+    let cube_root_sq = cube_root.mul(&cube_root);
+
+    let mut out = alloc::vec![TFr::zero(); n];
+    let mut twiddle = TFr::one();
+    for k in 0..m {
+        let a = &thirds[0][k];
+        let b = thirds[1][k].mul(&twiddle);
+        let c = thirds[2][k].mul(&twiddle.mul(&twiddle));
+
+        out[k] = a.add(&b).add(&c);
+        out[k + m] = a.add(&b.mul(&cube_root)).add(&c.mul(&cube_root_sq));
+        out[k + 2 * m] = a.add(&b.mul(&cube_root_sq)).add(&c.mul(&cube_root));
+
+        twiddle = twiddle.mul(&root);
+    }
+
+    if inverse {
+        // The power-of-two transforms have already divided by m
+        let inv_three = TFr::from_u64(3).inverse();
+        out.iter_mut()
+            .for_each(|value| *value = value.mul(&inv_three));
+    }
+
+    Ok(out)
+}
+
+/// Reed–Solomon extension by `factor`. `data` holds the values of a polynomial of degree below
+/// `n = data.len()` over the `n`-th roots of unity, and the result its values over the
+/// `factor * n`-th roots, both in natural order. Since the smaller domain is a subgroup of the
+/// larger one, `result[factor * i] == data[i]`.
+///
+/// `factor * n` has to be a domain size [`mixed_radix_root`] supports, so extension by 3 or 6
+/// works as well as by powers of two.
+pub fn rs_extension<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    data: &[TFr],
+    factor: usize,
+) -> Result<Vec<TFr>, String> {
+    if !data.len().is_power_of_two() {
+        return Err(String::from("Data length must be a power of 2"));
+    }
+    if factor == 0 {
+        return Err(String::from("Extension factor must be positive"));
+    }
+
+    let mut coeffs = fs.fft_fr(data, true)?;
+    coeffs.resize(data.len() * factor, TFr::zero());
+
+    fft_fr_mixed(fs, &coeffs, false)
+}
+
+/// Position, among the `k` cosets of an extension by `k`, of the coset sample group `t` holds.
+/// Bit reversal for power-of-two `k`, and for `k = 3m` the mixed-radix digit reversal that
+/// matches the radix-3 step of [`fft_fr_mixed`].
+fn reverse_coset_index(t: usize, k: usize) -> usize {
+    let reverse = |i: usize, len: usize| {
+        i.reverse_bits()
+            .checked_shr(len.leading_zeros() + 1)
+            .unwrap_or(0)
+    };
+
+    match radix3_split(k) {
+        Some(m) => 3 * reverse(t % m, m) + t / m,
+        None => reverse(t, k),
+    }
+}
+
+/// Index into the natural-order extended domain of each sample, for an extension of `n` values
+/// by `k`. See [`das_fft_extension_k`].
+fn das_sample_indices(n: usize, k: usize) -> Result<Vec<usize>, String> {
+    if !n.is_power_of_two() {
+        return Err(String::from("Data length must be a power of 2"));
+    }
+    if !k.is_power_of_two() && radix3_split(k).is_none() {
+        return Err(String::from(
+            "Extension factor must be a power of 2 or 3 times a power of 2",
+        ));
+    }
+
+    let unused_bit_len = n.leading_zeros() + 1;
+    Ok((0..k * n)
+        .map(|p| {
+            let q = (p % n)
+                .reverse_bits()
+                .checked_shr(unused_bit_len)
+                .unwrap_or(0);
+            k * q + reverse_coset_index(p / n, k)
+        })
+        .collect())
+}
+
+/// Extension of `data`, the values over the `n`-th roots of unity in natural order, to all
+/// `k * n` values of its polynomial, in sample order.
+///
+/// Samples come in `k` groups of `n`, each group being one coset of the `n`-th roots inside the
+/// larger domain, in bit-reversed order within the group. For power-of-two `k` this is plain
+/// bit-reversed order over the whole domain. The first group is always the original data, so
+/// the code is systematic.
+pub fn das_fft_extension_k<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    data: &[TFr],
+    k: usize,
+) -> Result<Vec<TFr>, String> {
+    let indices = das_sample_indices(data.len(), k)?;
+    let natural = rs_extension(fs, data, k)?;
+
+    Ok(indices.into_iter().map(|i| natural[i].clone()).collect())
+}
+
+/// Whether `samples`, in the order of [`das_fft_extension_k`], is a codeword of rate `1 / k`,
+/// i.e. the values of a polynomial of degree below `samples.len() / k`.
+pub fn das_verify_extension_k<TFr: Fr, TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>>(
+    fs: &TFFTSettings,
+    samples: &[TFr],
+    k: usize,
+) -> Result<bool, String> {
+    if samples.len().checked_rem(k) != Some(0) {
+        return Err(String::from(
+            "Sample count must be a multiple of the extension factor",
+        ));
+    }
+
+    let n = samples.len() / k;
+    let indices = das_sample_indices(n, k)?;
+
+    let mut natural = alloc::vec![TFr::zero(); samples.len()];
+    for (sample, i) in samples.iter().zip(indices) {
+        natural[i] = sample.clone();
+    }
+
+    let coeffs = fft_fr_mixed(fs, &natural, true)?;
+
+    Ok(coeffs[n..].iter().all(|coeff| coeff.is_zero()))
+}
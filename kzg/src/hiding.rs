@@ -0,0 +1,186 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::{
+    FFTSettings, Fr, G1Affine, G1ConstantTime, G1Fp, G1GetFp, G1LinComb, G1Mul, G2Mul, KZGSettings,
+    PairingVerify, Poly, G1, G2,
+};
+
+/// Settings for hiding KZG commitments (PolyCommit_Ped from KZG10, section 3.3).
+///
+/// Next to the usual setup this holds `[τ^i]h` for a second generator `h`, whose discrete log
+/// relative to the G1 generator nobody may know. A commitment `[p(τ)] + [r(τ)]h` blinds `p` with
+/// a random polynomial `r` of up to `h_powers.len()` coefficients, and for any other polynomial
+/// there's a blinding giving the same commitment, so it reveals nothing about `p`. Every opening
+/// reveals one value of `r`, so up to `h_powers.len() - 1` openings of a commitment stay hiding.
+///
+/// Polynomials and blindings are secret, so all MSMs over them go through
+/// [`G1LinComb::g1_lincomb_ct`].
+pub struct HidingKZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    ks: TKZGSettings,
+    h_powers: Vec<TG1>,
+    _phantom: PhantomData<(TFr, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine)>,
+}
+
+/// Opening of a hiding commitment: a commitment to both quotients, and the value of the
+/// blinding polynomial at the point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HidingProof<TFr, TG1> {
+    pub witness: TG1,
+    pub blinding_eval: TFr,
+}
+
+impl<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+    HidingKZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1ConstantTime
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>
+        + PairingVerify<TG1, TG2>,
+    TG2: G2 + G2Mul<TFr>,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    /// `h_powers` are `[τ^i]h` for `i` from 0, from the same ceremony as `ks`.
+    pub fn new(ks: TKZGSettings, h_powers: Vec<TG1>) -> Result<Self, String> {
+        if h_powers.len() < 2 {
+            return Err(String::from("Hiding setup needs at least two powers of h"));
+        }
+        if h_powers.len() > ks.get_g1_monomial().len() {
+            return Err(String::from(
+                "Hiding setup must not have more powers of h than of the G1 generator",
+            ));
+        }
+
+        Ok(Self {
+            ks,
+            h_powers,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Extends a setup whose trapdoor `secret` is known, as for setups from
+    /// `generate_trusted_setup`, with `len` powers of `h`. `h` should come from hashing to the
+    /// curve, so that nobody knows its discrete log. Setups from a ceremony need the ceremony to
+    /// produce the powers of `h` as well, and go through [`Self::new`].
+    pub fn from_secret(
+        ks: TKZGSettings,
+        h: &TG1,
+        secret: &TFr,
+        len: usize,
+    ) -> Result<Self, String> {
+        let secret_g1 = ks.get_g1_monomial();
+        if secret_g1.len() < 2 || !TG1::generator().mul_ct(secret).equals(&secret_g1[1]) {
+            return Err(String::from("Secret does not match the setup"));
+        }
+
+        let mut power = TFr::one();
+        let h_powers = (0..len)
+            .map(|_| {
+                let h_power = h.mul_ct(&power);
+                power = power.mul(secret);
+                h_power
+            })
+            .collect();
+
+        Self::new(ks, h_powers)
+    }
+
+    pub fn get_kzg_settings(&self) -> &TKZGSettings {
+        &self.ks
+    }
+
+    pub fn get_h_powers(&self) -> &[TG1] {
+        &self.h_powers
+    }
+
+    /// Random blinding polynomial of the full length the setup supports.
+    #[cfg(feature = "rand")]
+    pub fn random_blinding(&self) -> TPoly {
+        let coeffs = (0..self.h_powers.len())
+            .map(|_| TFr::rand())
+            .collect::<Vec<_>>();
+        TPoly::from_coeffs(&coeffs)
+    }
+
+    /// `[p(τ)] + [r(τ)]h`, for `r` the blinding polynomial.
+    pub fn commit(&self, p: &TPoly, blinding: &TPoly) -> Result<TG1, String> {
+        self.blinded_msm(p, blinding)
+    }
+
+    /// Opens the commitment to `p` with `blinding` at `z`. The value `p(z)` is left to the
+    /// caller, as with [`KZGSettings::compute_proof_single`].
+    pub fn compute_proof(
+        &self,
+        p: &TPoly,
+        blinding: &TPoly,
+        z: &TFr,
+    ) -> Result<HidingProof<TFr, TG1>, String> {
+        let witness = self.blinded_msm(&p.divide_by_linear(z), &blinding.divide_by_linear(z))?;
+
+        Ok(HidingProof {
+            witness,
+            blinding_eval: blinding.eval(z),
+        })
+    }
+
+    /// Checks that the polynomial behind `commitment` takes the value `y` at `z`, i.e. that
+    /// `e(C - [y] - [r(z)]h, [1]) = e(W, [τ - z])`.
+    pub fn check_proof(
+        &self,
+        commitment: &TG1,
+        proof: &HidingProof<TFr, TG1>,
+        z: &TFr,
+        y: &TFr,
+    ) -> Result<bool, String> {
+        let lhs = commitment
+            .sub(&TG1::generator_mul(y))
+            .sub(&self.h_powers[0].mul(&proof.blinding_eval));
+        let s_minus_z = self.ks.get_g2_secret()[1].sub(&TG2::generator_mul(z));
+
+        Ok(TG1::verify(
+            &lhs,
+            &TG2::generator(),
+            &proof.witness,
+            &s_minus_z,
+        ))
+    }
+
+    fn blinded_msm(&self, p: &TPoly, blinding: &TPoly) -> Result<TG1, String> {
+        let secret_g1 = self.ks.get_g1_monomial();
+        if p.len() > secret_g1.len() {
+            return Err(String::from("Polynomial is longer than secret g1"));
+        }
+        if blinding.len() > self.h_powers.len() {
+            return Err(String::from(
+                "Blinding polynomial is longer than the powers of h",
+            ));
+        }
+
+        let mut points = secret_g1[..p.len()].to_vec();
+        points.extend_from_slice(&self.h_powers[..blinding.len()]);
+        let mut scalars = p.get_coeffs().to_vec();
+        scalars.extend_from_slice(blinding.get_coeffs());
+
+        Ok(TG1::g1_lincomb_ct(&points, &scalars, points.len()))
+    }
+}
@@ -3,13 +3,26 @@
 extern crate alloc;
 
 use alloc::string::String;
+use alloc::sync::Arc;
 use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::ops::RangeInclusive;
 use msm::precompute::PrecomputationTable;
 
+pub mod aggregation;
+pub mod batch_opening;
 pub mod common_utils;
+pub mod das2d;
 pub mod eip_4844;
+pub mod fft;
+pub mod hiding;
 pub mod msm;
+pub mod multipoint;
+pub mod pcs;
+pub mod poly_eval;
+pub mod recovery;
+pub mod shplonk;
+pub mod transcript;
 
 pub trait Fr: Default + Clone + PartialEq + Sync {
     fn null() -> Self;
@@ -131,8 +144,31 @@ pub trait G1GetFp<TFp: G1Fp>: G1 + Clone {
     fn z_mut(&mut self) -> &mut TFp;
 }
 
+/// Group operations free of data-dependent branches, which the constant-time APIs
+/// ([`G1Mul::mul_ct`], [`G1LinComb::g1_lincomb_ct`]) are built from. Only backends whose
+/// `dbl_assign` and `add_or_dbl_assign` are constant time as well should implement it.
+pub trait G1ConstantTime: G1 {
+    /// Return `b` if `choice` is `1` and `a` if it is `0`. Must not branch on `choice`.
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self;
+}
+
 pub trait G1Mul<TFr: Fr>: G1 + Clone {
     fn mul(&self, b: &TFr) -> Self;
+
+    /// Multiply by a secret scalar. Unlike [`G1Mul::mul`], which may skip work depending on
+    /// the digits of `b`, this performs the same sequence of group operations for every scalar.
+    fn mul_ct(&self, b: &TFr) -> Self
+    where
+        Self: G1ConstantTime,
+    {
+        msm::constant_time::g1_mul_ct(self, &b.to_scalar())
+    }
+
+    /// Multiply the generator by `b`. Backends should override this with a fixed-base table
+    /// (see [`msm::fixed_base`]), as the generator is multiplied on every verification.
+    fn generator_mul(b: &TFr) -> Self {
+        Self::generator().mul(b)
+    }
 }
 
 pub trait G1LinComb<TFr: Fr, TG1Fp: G1Fp, TG1Affine: G1Affine<Self, TG1Fp>>:
@@ -144,6 +180,15 @@ pub trait G1LinComb<TFr: Fr, TG1Fp: G1Fp, TG1Affine: G1Affine<Self, TG1Fp>>:
         len: usize,
         precomputation: Option<&PrecomputationTable<TFr, Self, TG1Fp, TG1Affine>>,
     ) -> Self;
+
+    /// Constant-time counterpart of [`G1LinComb::g1_lincomb`], to be used when the scalars are
+    /// secret (e.g. blinding factors). See [`msm::constant_time::g1_msm_ct`].
+    fn g1_lincomb_ct(points: &[Self], scalars: &[TFr], len: usize) -> Self
+    where
+        Self: G1ConstantTime,
+    {
+        msm::constant_time::g1_msm_ct(&points[..len], &scalars[..len])
+    }
 }
 
 pub trait G1Fp: Clone + Default + Sync + Copy + PartialEq + Debug + Send {
@@ -312,10 +357,23 @@ impl Scalar256 {
         unsafe { core::slice::from_raw_parts(&*(ptr as *const [u64; N]), 1)[0] }
     }
 
-    fn is_zero(&self) -> bool {
+    pub fn is_zero(&self) -> bool {
         self.data == Self::ZERO.data
     }
 
+    /// Position of the highest set bit plus one, or `0` for the zero scalar
+    pub const fn num_bits(&self) -> usize {
+        let mut i = self.data.len();
+        while i > 0 {
+            i -= 1;
+            if self.data[i] != 0 {
+                return 64 * (i + 1) - self.data[i].leading_zeros() as usize;
+            }
+        }
+
+        0
+    }
+
     fn divn(&mut self, mut n: u32) {
         const N: usize = 4;
         if n >= (64 * N) as u32 {
@@ -365,6 +423,15 @@ pub trait G2: Clone + Default {
 
 pub trait G2Mul<Fr>: Clone {
     fn mul(&self, b: &Fr) -> Self;
+
+    /// Multiply the generator by `b`. Backends should override this with a fixed-base table
+    /// (see [`msm::fixed_base`]), as the generator is multiplied on every verification.
+    fn generator_mul(b: &Fr) -> Self
+    where
+        Self: G2,
+    {
+        Self::generator().mul(b)
+    }
 }
 
 pub trait PairingVerify<TG1: G1, TG2: G2> {
@@ -373,14 +440,80 @@ pub trait PairingVerify<TG1: G1, TG2: G2> {
 
 pub trait FFTFr<Coeff: Fr> {
     fn fft_fr(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+
+    /// Same as `fft_fr`, but transforms `data` in place. Backends override this with the
+    /// iterative transforms in [`fft`], the default only saves the caller a copy.
+    fn fft_fr_inplace(&self, data: &mut [Coeff], inverse: bool) -> Result<(), String> {
+        let ret = self.fft_fr(data, inverse)?;
+        data.clone_from_slice(&ret);
+        Ok(())
+    }
+
+    /// FFT over the coset `shift * w^i` of the usual domain. See [`fft::coset_fft_fr`].
+    fn coset_fft_fr(
+        &self,
+        data: &[Coeff],
+        shift: &Coeff,
+        inverse: bool,
+    ) -> Result<Vec<Coeff>, String>
+    where
+        Self: FFTSettings<Coeff>,
+    {
+        fft::coset_fft_fr(self, data, shift, inverse)
+    }
+
+    /// FFT over domains of size `3 * 2^k` as well as powers of two. See [`fft::fft_fr_mixed`].
+    fn fft_fr_mixed(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>
+    where
+        Self: FFTSettings<Coeff>,
+    {
+        fft::fft_fr_mixed(self, data, inverse)
+    }
 }
 
 pub trait FFTG1<Coeff: G1> {
     fn fft_g1(&self, data: &[Coeff], inverse: bool) -> Result<Vec<Coeff>, String>;
+
+    /// Same as `fft_g1`, but transforms `data` in place
+    fn fft_g1_inplace(&self, data: &mut [Coeff], inverse: bool) -> Result<(), String> {
+        let ret = self.fft_g1(data, inverse)?;
+        data.clone_from_slice(&ret);
+        Ok(())
+    }
+
+    /// FFT over the coset `shift * w^i` of the usual domain. See [`fft::coset_fft_g1`].
+    fn coset_fft_g1<TFr: Fr>(
+        &self,
+        data: &[Coeff],
+        shift: &TFr,
+        inverse: bool,
+    ) -> Result<Vec<Coeff>, String>
+    where
+        Self: FFTSettings<TFr>,
+        Coeff: G1Mul<TFr>,
+    {
+        fft::coset_fft_g1(self, data, shift, inverse)
+    }
 }
 
 pub trait DAS<Coeff: Fr> {
     fn das_fft_extension(&self, evens: &[Coeff]) -> Result<Vec<Coeff>, String>;
+
+    /// Extension of `data` by `k` in sample order. See [`fft::das_fft_extension_k`].
+    fn das_fft_extension_k(&self, data: &[Coeff], k: usize) -> Result<Vec<Coeff>, String>
+    where
+        Self: FFTSettings<Coeff> + FFTFr<Coeff>,
+    {
+        fft::das_fft_extension_k(self, data, k)
+    }
+
+    /// Whether `samples` is a valid extension by `k`. See [`fft::das_verify_extension_k`].
+    fn das_verify_extension_k(&self, samples: &[Coeff], k: usize) -> Result<bool, String>
+    where
+        Self: FFTSettings<Coeff> + FFTFr<Coeff>,
+    {
+        fft::das_verify_extension_k(self, samples, k)
+    }
 }
 
 pub trait ZeroPoly<Coeff: Fr, Polynomial: Poly<Coeff>> {
@@ -428,9 +561,29 @@ pub trait FFTSettings<Coeff: Fr>: Default + Clone {
 
     fn get_reversed_roots_of_unity(&self) -> &[Coeff];
 
+    /// Points of the domain of size `size` in natural order, which may be three times a power of
+    /// two as well as a power of two. See [`fft::mixed_radix_root`].
+    fn get_domain(&self, size: usize) -> Result<Vec<Coeff>, String> {
+        fft::mixed_radix_domain(self, size)
+    }
+
     fn get_roots_of_unity_at(&self, i: usize) -> Coeff;
 
     fn get_roots_of_unity(&self) -> &[Coeff];
+
+    /// Powers of `shift` and of its inverse for coset transforms, at least `len` of each.
+    /// Settings that keep a [`fft::CosetCache`] look them up there instead of computing them on
+    /// every call.
+    fn get_coset_shift_powers(&self, shift: &Coeff, len: usize) -> Arc<fft::ShiftPowers<Coeff>> {
+        Arc::new(fft::ShiftPowers::new(shift, len))
+    }
+
+    /// Pool that the FFTs over these settings, and recovery from samples, install. `KZGSettings`
+    /// built from the settings take the same context. The default is the caller's pool.
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &msm::thread_pool::ExecutionContext {
+        msm::thread_pool::ExecutionContext::global_ref()
+    }
 }
 
 pub trait FFTSettingsPoly<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
@@ -483,6 +636,135 @@ pub trait Poly<Coeff: Fr>: Default + Clone {
     fn fast_div(&mut self, x: &Self) -> Result<Self, String>;
 
     fn mul_direct(&mut self, x: &Self, len: usize) -> Result<Self, String>;
+
+    fn add(&self, other: &Self) -> Self {
+        let (a, b) = (self.get_coeffs(), other.get_coeffs());
+        let mut coeffs = Vec::from(if a.len() >= b.len() { a } else { b });
+        let shorter = if a.len() >= b.len() { b } else { a };
+        for (coeff, other) in coeffs.iter_mut().zip(shorter) {
+            *coeff = coeff.add(other);
+        }
+
+        Self::from_coeffs(&coeffs)
+    }
+
+    fn sub(&self, other: &Self) -> Self {
+        let (a, b) = (self.get_coeffs(), other.get_coeffs());
+        let mut coeffs = Vec::from(a);
+        coeffs.resize(a.len().max(b.len()), Coeff::zero());
+        for (coeff, other) in coeffs.iter_mut().zip(b) {
+            *coeff = coeff.sub(other);
+        }
+
+        Self::from_coeffs(&coeffs)
+    }
+
+    fn mul_scalar(&self, x: &Coeff) -> Self {
+        let coeffs = self
+            .get_coeffs()
+            .iter()
+            .map(|coeff| coeff.mul(x))
+            .collect::<Vec<_>>();
+
+        Self::from_coeffs(&coeffs)
+    }
+
+    /// Formal derivative. One coefficient shorter than `self`.
+    fn derivative(&self) -> Self {
+        let coeffs = self
+            .get_coeffs()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, coeff)| coeff.mul(&Coeff::from_u64(i as u64)))
+            .collect::<Vec<_>>();
+
+        Self::from_coeffs(&coeffs)
+    }
+
+    /// `self(other(x))`, by Horner's rule. The result has `(self.len() - 1) * (other.len() - 1) + 1`
+    /// coefficients.
+    fn compose(&self, other: &Self) -> Result<Self, String> {
+        let coeffs = self.get_coeffs();
+        let Some((last, rest)) = coeffs.split_last() else {
+            return Ok(Self::new(0));
+        };
+        if other.is_empty() {
+            return Ok(Self::from_coeffs(&coeffs[..1]));
+        }
+
+        let mut out = Self::from_coeffs(core::slice::from_ref(last));
+        for coeff in rest.iter().rev() {
+            let len = out.len() + other.len() - 1;
+            out = out.mul_direct(other, len)?;
+            out.set_coeff_at(0, &out.get_coeff_at(0).add(coeff));
+        }
+
+        Ok(out)
+    }
+
+    /// Polynomial of length `xs.len()` taking the value `ys[i]` at `xs[i]`, by Lagrange
+    /// interpolation. Quadratic in the number of points.
+    fn interpolate(xs: &[Coeff], ys: &[Coeff]) -> Result<Self, String> {
+        if xs.len() != ys.len() {
+            return Err(String::from("xs and ys must have the same length"));
+        }
+
+        let n = xs.len();
+
+        // Vanishing polynomial of all points, prod(x - xs[i])
+        let mut vanishing = alloc::vec![Coeff::zero(); n + 1];
+        vanishing[0] = Coeff::one();
+        for (k, x) in xs.iter().enumerate() {
+            for j in (1..=k + 1).rev() {
+                vanishing[j] = vanishing[j - 1].sub(&vanishing[j].mul(x));
+            }
+            vanishing[0] = vanishing[0].mul(x).negate();
+        }
+
+        let mut coeffs = alloc::vec![Coeff::zero(); n];
+        let mut basis = alloc::vec![Coeff::zero(); n];
+        for (x, y) in xs.iter().zip(ys) {
+            // vanishing / (x - xs[i]) is the i-th Lagrange basis polynomial up to a factor of
+            // its value at xs[i]
+            basis[n - 1] = vanishing[n].clone();
+            for j in (1..n).rev() {
+                basis[j - 1] = vanishing[j].add(&basis[j].mul(x));
+            }
+
+            let denominator = basis
+                .iter()
+                .rev()
+                .fold(Coeff::zero(), |acc, coeff| acc.mul(x).add(coeff));
+            if denominator.is_zero() {
+                return Err(String::from("Interpolation points must be distinct"));
+            }
+
+            let factor = y.div(&denominator)?;
+            for (coeff, basis) in coeffs.iter_mut().zip(&basis) {
+                *coeff = coeff.add(&basis.mul(&factor));
+            }
+        }
+
+        Ok(Self::from_coeffs(&coeffs))
+    }
+
+    /// Quotient of `self` by `(x - z)`, by synthetic division. The remainder, `self(z)`, is
+    /// dropped, so this is the quotient polynomial of a KZG opening at `z`.
+    fn divide_by_linear(&self, z: &Coeff) -> Self {
+        let coeffs = self.get_coeffs();
+        if coeffs.is_empty() {
+            return Self::new(0);
+        }
+
+        let mut out = Vec::from(&coeffs[1..]);
+        for i in (1..out.len()).rev() {
+            let tmp = out[i].mul(z);
+            out[i - 1] = out[i - 1].add(&tmp);
+        }
+
+        Self::from_coeffs(&out)
+    }
 }
 
 pub trait PolyRecover<Coeff: Fr, Polynomial: Poly<Coeff>, FSettings: FFTSettings<Coeff>> {
@@ -514,8 +796,62 @@ pub trait KZGSettings<
         fs: &Fs,
     ) -> Result<Self, String>;
 
+    /// Settings for an EIP-4844 setup, whose `secret_g1` are the Lagrange-form points in
+    /// bit-reversed order, and `secret_g1_monomial` the `[τ^i]` of the same setup. The former
+    /// back the EIP-4844 functions and [`Self::commit_to_poly_lagrange`], the latter everything
+    /// working on coefficients. Without `secret_g1_monomial`, they are derived from `secret_g1`
+    /// with an FFT in G1 the first time [`Self::get_g1_monomial`] is called.
+    ///
+    /// The default is for settings keeping a single set of points: it makes them over
+    /// `secret_g1` with [`Self::new`] and drops `secret_g1_monomial`. The EIP-4844 functions
+    /// work on them as before, but [`Self::get_g1_monomial`] then returns the Lagrange-form
+    /// points, so nothing working on coefficients may use them.
+    fn new_with_monomial(
+        secret_g1: &[Coeff2],
+        _secret_g1_monomial: Option<&[Coeff2]>,
+        secret_g2: &[Coeff3],
+        length: usize,
+        fs: &Fs,
+    ) -> Result<Self, String> {
+        Self::new(secret_g1, secret_g2, length, fs)
+    }
+
+    /// Commits to `p` given by its coefficients, using [`Self::get_g1_monomial`]. The
+    /// precomputation is over [`Self::get_g1_secret`], so for settings made with
+    /// [`Self::new_with_monomial`] this runs a plain MSM, without it.
     fn commit_to_poly(&self, p: &Polynomial) -> Result<Coeff2, String>;
 
+    /// Same as [`Self::commit_to_poly`], named for symmetry with
+    /// [`Self::commit_to_poly_lagrange`].
+    fn commit_to_poly_monomial(&self, p: &Polynomial) -> Result<Coeff2, String> {
+        self.commit_to_poly(p)
+    }
+
+    /// Commits to the polynomial taking `evals[i]` at the `i`-th of the bit-reversed roots of
+    /// unity, as a blob does. Needs the settings to have been made with
+    /// [`Self::new_with_monomial`].
+    fn commit_to_poly_lagrange(&self, evals: &[Coeff1]) -> Result<Coeff2, String>
+    where
+        Coeff2: G1LinComb<Coeff1, TG1Fp, TG1Affine>,
+    {
+        let lagrange = self.get_g1_lagrange_brp();
+        if lagrange.is_empty() {
+            return Err(String::from("Settings have no Lagrange-form points"));
+        }
+        if evals.len() != lagrange.len() {
+            return Err(String::from(
+                "Evaluations must be as many as the Lagrange-form points",
+            ));
+        }
+
+        Ok(Coeff2::g1_lincomb(
+            lagrange,
+            evals,
+            evals.len(),
+            self.get_precomputation(),
+        ))
+    }
+
     fn compute_proof_single(&self, p: &Polynomial, x: &Coeff1) -> Result<Coeff2, String>;
 
     fn check_proof_single(
@@ -526,6 +862,105 @@ pub trait KZGSettings<
         value: &Coeff1,
     ) -> Result<bool, String>;
 
+    /// One proof opening every polynomial of `polys` at `z`. The polynomials are combined with
+    /// powers of a challenge derived from `commitments`, `z` and the values at `z` (see
+    /// [`batch_opening::compute_batch_same_point_challenge`]), and the combination is opened
+    /// with [`Self::compute_proof_single`].
+    fn compute_proof_batch_same_point(
+        &self,
+        polys: &[Polynomial],
+        commitments: &[Coeff2],
+        z: &Coeff1,
+    ) -> Result<Coeff2, String> {
+        if polys.len() != commitments.len() {
+            return Err(String::from(
+                "Batch must have one commitment per polynomial",
+            ));
+        }
+
+        let values = polys.iter().map(|p| p.eval(z)).collect::<Vec<_>>();
+        let r = batch_opening::compute_batch_same_point_challenge(commitments, z, &values)?;
+        let powers = batch_opening::challenge_powers(&r, polys.len());
+
+        self.compute_proof_single(&batch_opening::combine_polys(polys, &powers), z)
+    }
+
+    /// Checks a proof from [`Self::compute_proof_batch_same_point`] that the polynomials of
+    /// `commitments` take `values` at `z`.
+    fn check_proof_batch_same_point(
+        &self,
+        commitments: &[Coeff2],
+        proof: &Coeff2,
+        z: &Coeff1,
+        values: &[Coeff1],
+    ) -> Result<bool, String>
+    where
+        Coeff2: G1LinComb<Coeff1, TG1Fp, TG1Affine>,
+    {
+        let r = batch_opening::compute_batch_same_point_challenge(commitments, z, values)?;
+        let powers = batch_opening::challenge_powers(&r, commitments.len());
+
+        let commitment = Coeff2::g1_lincomb(commitments, &powers, commitments.len(), None);
+        let value = batch_opening::combine_values(values, &powers);
+
+        self.check_proof_single(&commitment, proof, z, &value)
+    }
+
+    /// Degree bounds `d` for which this setup can prove `deg p < d`. With `n` points in
+    /// [`Self::get_g1_monomial`] the proof commits to `x^(n - d) * p`, and checking it takes
+    /// `[τ^(n - d)]` from [`Self::get_g2_secret`], so the bounds go from `n` down to one more than
+    /// `n` minus the G2 point count.
+    fn get_degree_bound_range(&self) -> RangeInclusive<usize> {
+        let n = self.get_g1_monomial().len();
+        match self.get_g2_secret().len() {
+            0 => n + 1..=n,
+            m => n.saturating_sub(m - 1)..=n,
+        }
+    }
+
+    /// Proof that `p` has degree below `d`: a commitment to `p` shifted up by `x^(n - d)`, which
+    /// only fits in the setup if the degree bound holds.
+    fn compute_degree_bound_proof(&self, p: &Polynomial, d: usize) -> Result<Coeff2, String> {
+        let range = self.get_degree_bound_range();
+        if !range.contains(&d) {
+            return Err(String::from("Degree bound is not supported by the setup"));
+        }
+
+        let coeffs = p.get_coeffs();
+        if coeffs.iter().skip(d).any(|coeff| !coeff.is_zero()) {
+            return Err(String::from("Polynomial does not satisfy the degree bound"));
+        }
+
+        let mut shifted = alloc::vec![Coeff1::zero(); range.end() - d];
+        shifted.extend_from_slice(&coeffs[..coeffs.len().min(d)]);
+
+        self.commit_to_poly(&Polynomial::from_coeffs(&shifted))
+    }
+
+    /// Checks that `proof` shows the polynomial of `commitment` to have degree below `d`, i.e.
+    /// that `e(C, [τ^(n - d)]) = e(proof, [1])`.
+    fn check_degree_bound_proof(
+        &self,
+        commitment: &Coeff2,
+        proof: &Coeff2,
+        d: usize,
+    ) -> Result<bool, String>
+    where
+        Coeff2: PairingVerify<Coeff2, Coeff3>,
+    {
+        let range = self.get_degree_bound_range();
+        if !range.contains(&d) {
+            return Err(String::from("Degree bound is not supported by the setup"));
+        }
+
+        Ok(Coeff2::verify(
+            commitment,
+            &self.get_g2_secret()[range.end() - d],
+            proof,
+            &Coeff3::generator(),
+        ))
+    }
+
     fn compute_proof_multi(&self, p: &Polynomial, x: &Coeff1, n: usize) -> Result<Coeff2, String>;
 
     fn check_proof_multi(
@@ -543,11 +978,36 @@ pub trait KZGSettings<
 
     fn get_fft_settings(&self) -> &Fs;
 
+    /// The G1 points the settings were made with, which the precomputation is over: `[τ^i]`
+    /// for [`Self::new`], the Lagrange-form points for [`Self::new_with_monomial`].
     fn get_g1_secret(&self) -> &[Coeff2];
 
+    /// `[τ^i]`, whichever way the settings were made. The default returns
+    /// [`Self::get_g1_secret`], which holds only for settings made with [`Self::new`].
+    fn get_g1_monomial(&self) -> &[Coeff2] {
+        self.get_g1_secret()
+    }
+
+    /// The Lagrange-form points in bit-reversed order, empty unless the settings were made with
+    /// [`Self::new_with_monomial`]. Empty by default.
+    fn get_g1_lagrange_brp(&self) -> &[Coeff2] {
+        &[]
+    }
+
+    /// `[τ^i]` in G2. Only the first two are needed to check proofs, the rest limit which degree
+    /// bounds can be proven (see [`Self::get_degree_bound_range`]): loaded EIP-4844 setups have 65,
+    /// for bounds from 4032 up.
     fn get_g2_secret(&self) -> &[Coeff3];
 
     fn get_precomputation(&self) -> Option<&PrecomputationTable<Coeff1, Coeff2, TG1Fp, TG1Affine>>;
+
+    /// Pool that [`Self::commit_to_poly`], [`Self::compute_proof_multi`], FK20 proofs built on these
+    /// settings and the EIP-4844 commitment, proof and batch verification entry points install.
+    /// Settings built from an [`FFTSettings`] take its context. The default is the caller's pool.
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &msm::thread_pool::ExecutionContext {
+        msm::thread_pool::ExecutionContext::global_ref()
+    }
 }
 
 pub trait FK20SingleSettings<
@@ -1,10 +1,15 @@
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 use core::marker::PhantomData;
 
 use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, Scalar256, G1};
 
 use super::pippenger_utils::{
-    booth_decode, booth_encode, get_wval_limb, is_zero, p1_dadd, p1_to_jacobian,
-    pippenger_window_size, type_is_zero, P1XYZZ,
+    booth_decode, booth_encode, get_wval_limb, is_zero, max_num_bits, p1_dadd, p1_to_jacobian,
+    type_is_zero, P1XYZZ,
 };
 
 #[derive(Debug, Clone)]
@@ -31,6 +36,7 @@ const NBITS: usize = 255;
 #[derive(Debug, Clone, Copy)]
 enum BgmwWindow {
     Sync(usize),
+    #[cfg(feature = "bgmw")]
     Parallel((usize, usize, usize)),
 }
 
@@ -50,6 +56,7 @@ const fn get_table_dimensions(window: BgmwWindow) -> (usize, usize) {
     {
         window_width = match window {
             BgmwWindow::Sync(wnd) => wnd,
+            #[cfg(feature = "bgmw")]
             BgmwWindow::Parallel((_, ny, wnd)) => return (wnd, ny),
         }
     }
@@ -71,6 +78,7 @@ const fn get_sequential_window_size(window: BgmwWindow) -> usize {
     {
         match window {
             BgmwWindow::Sync(wnd) => wnd,
+            #[cfg(feature = "bgmw")]
             BgmwWindow::Parallel(_) => {
                 panic!("Cannot use parallel BGMW table in sequential version")
             }
@@ -85,30 +93,56 @@ impl<
         TG1Affine: G1Affine<TG1, TG1Fp>,
     > BgmwTable<TFr, TG1, TG1Fp, TG1Affine>
 {
+    // Only reachable through `precompute`, when the `bgmw` feature selects this table
+    #[cfg(all(feature = "bgmw", any(not(feature = "arkmsm"), feature = "parallel")))]
     pub fn new(points: &[TG1]) -> Result<Option<Self>, String> {
-        let window = Self::window(points.len());
+        Self::build(points, Self::window(points.len())).map(Some)
+    }
+
+    /// Build a table with a fixed window width. Tables built this way are always multiplied
+    /// sequentially.
+    pub fn with_window(points: &[TG1], window: usize) -> Result<Self, String> {
+        #[cfg(feature = "parallel")]
+        let window = BgmwWindow::Sync(window);
+
+        Self::build(points, window)
+    }
+
+    /// Number of precomputed points a table for `npoints` points with the given window holds
+    pub const fn table_size(npoints: usize, window: usize) -> usize {
+        #[cfg(feature = "parallel")]
+        let window = BgmwWindow::Sync(window);
+
+        npoints * get_table_dimensions(window).1
+    }
 
+    fn build(points: &[TG1], window: BgmwWindow) -> Result<Self, String> {
         let (window_width, h) = get_table_dimensions(window);
 
-        let mut table: Vec<TG1Affine> = Vec::new();
-        let q = TFr::from_u64(1u64 << window_width);
+        let mut table: Vec<TG1> = Vec::new();
 
         table
             .try_reserve_exact(points.len() * h)
             .map_err(|_| "BGMW precomputation table is too large".to_string())?;
 
-        unsafe { table.set_len(points.len() * h) };
+        table.resize(points.len() * h, TG1::default());
 
         for i in 0..points.len() {
             let mut tmp_point = points[i].clone();
             for j in 0..h {
                 let idx = j * points.len() + i;
-                table[idx] = TG1Affine::into_affine(&tmp_point);
-                tmp_point = tmp_point.mul(&q);
+                table[idx] = tmp_point.clone();
+                // Times 2^w, by doublings rather than a full-width scalar multiplication
+                for _ in 0..window_width {
+                    tmp_point.dbl_assign();
+                }
             }
         }
 
-        Ok(Some(Self {
+        // Convert all at once, so that the inversions are batched
+        let table = TG1Affine::into_affines(&table);
+
+        Ok(Self {
             numpoints: points.len(),
             points: table,
             window,
@@ -117,11 +151,16 @@ impl<
             fr_marker: PhantomData,
             g1_fp_marker: PhantomData,
             g1_marker: PhantomData,
-        }))
+        })
     }
 
     pub fn multiply_sequential(&self, scalars: &[Scalar256]) -> TG1 {
         let window = get_sequential_window_size(self.window);
+        let nbits = max_num_bits(scalars);
+        if nbits == 0 {
+            return TG1::identity();
+        }
+
         let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
 
         let mut wbits: usize = 255 % window;
@@ -137,14 +176,18 @@ impl<
                 break;
             }
 
-            p1_tile_bgmw(
-                &self.points[q_idx * self.numpoints..(q_idx + 1) * self.numpoints],
-                scalars,
-                &mut buckets,
-                bit0,
-                wbits,
-                cbits,
-            );
+            // Booth digits of this window are read from bit `bit0 - 1` up, so they are all zero
+            // once the scalars are narrower than that
+            if bit0 <= nbits {
+                p1_tile_bgmw(
+                    &self.points[q_idx * self.numpoints..(q_idx + 1) * self.numpoints],
+                    scalars,
+                    &mut buckets,
+                    bit0,
+                    wbits,
+                    cbits,
+                );
+            }
 
             cbits = window;
             wbits = window;
@@ -164,18 +207,17 @@ impl<
         ret
     }
 
-    #[cfg(feature = "parallel")]
+    #[cfg(all(feature = "parallel", feature = "bgmw"))]
     pub fn multiply_parallel(&self, scalars: &[Scalar256]) -> TG1 {
-        use super::{
-            cell::Cell,
-            thread_pool::{da_pool, ThreadPoolExt},
-        };
+        use super::cell::Cell;
         use core::sync::atomic::{AtomicUsize, Ordering};
-        use std::sync::{mpsc, Arc};
 
         let npoints = scalars.len();
-        let pool = da_pool();
-        let ncpus = pool.max_count();
+        let ncpus = rayon::current_num_threads();
+        let nbits = max_num_bits(scalars);
+        if nbits == 0 {
+            return TG1::identity();
+        }
 
         struct Tile {
             x: usize,
@@ -219,11 +261,11 @@ impl<
         }
         let grid = &grid[..];
 
-        let mut row_sync: Vec<AtomicUsize> = Vec::with_capacity(ny);
-        row_sync.resize_with(ny, Default::default);
-        let counter = Arc::new(AtomicUsize::new(0));
-        let (tx, rx) = mpsc::channel();
-        let n_workers = core::cmp::min(ncpus, total);
+        // Rows are stored from the most significant window down, and rows starting above the
+        // widest scalar only see zero digits
+        let skipped = nx * (ny - 1).saturating_sub(nbits / window);
+        let counter = AtomicUsize::new(skipped);
+        let n_workers = core::cmp::min(ncpus, total - skipped);
 
         let mut results: Vec<Cell<TG1>> = Vec::with_capacity(n_workers);
         #[allow(clippy::uninit_vec)]
@@ -233,65 +275,60 @@ impl<
 
         let results = &results[..];
 
-        #[allow(clippy::needless_range_loop)]
-        for worker_index in 0..n_workers {
-            let tx = tx.clone();
-            let counter = counter.clone();
-
-            pool.joined_execute(move || {
-                let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
-                loop {
-                    let work = counter.fetch_add(1, Ordering::Relaxed);
-                    if work >= total {
-                        integrate_buckets(
-                            unsafe { results[worker_index].as_ptr().as_mut() }.unwrap(),
-                            &buckets,
-                            window - 1,
-                        );
-                        tx.send(worker_index).expect("disaster");
-
-                        break;
-                    }
+        rayon::scope(|s| {
+            for result in results {
+                let counter = &counter;
 
-                    let x = grid[work].0.x;
-                    let y = grid[work].0.y;
-                    let dx = grid[work].0.dx;
+                s.spawn(move |_| {
+                    let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
+                    loop {
+                        let work = counter.fetch_add(1, Ordering::Relaxed);
+                        if work >= total {
+                            integrate_buckets(result.as_mut(), &buckets, window - 1);
 
-                    let row_start = (y / window) * self.numpoints + x;
-                    let points = &self.points[row_start..(row_start + dx)];
+                            break;
+                        }
 
-                    let (wbits, cbits) = if y + window > NBITS {
-                        let wbits = NBITS - y;
-                        (wbits, wbits + 1)
-                    } else {
-                        (window, window)
-                    };
+                        let x = grid[work].0.x;
+                        let y = grid[work].0.y;
+                        let dx = grid[work].0.dx;
 
-                    p1_tile_bgmw(points, &scalars[x..], &mut buckets, y, wbits, cbits);
-                }
-            });
-        }
+                        let row_start = (y / window) * self.numpoints + x;
+                        let points = &self.points[row_start..(row_start + dx)];
 
-        let mut ret = <TG1>::default();
-        for _ in 0..n_workers {
-            let idx = rx.recv().unwrap();
+                        let (wbits, cbits) = if y + window > NBITS {
+                            let wbits = NBITS - y;
+                            (wbits, wbits + 1)
+                        } else {
+                            (window, window)
+                        };
+
+                        p1_tile_bgmw(points, &scalars[x..], &mut buckets, y, wbits, cbits);
+                    }
+                });
+            }
+        });
 
-            ret.add_or_dbl_assign(results[idx].as_mut());
+        let mut ret = <TG1>::default();
+        for result in results {
+            ret.add_or_dbl_assign(result.as_mut());
         }
         ret
     }
 
+    #[cfg(all(feature = "bgmw", any(not(feature = "arkmsm"), feature = "parallel")))]
     fn window(npoints: usize) -> BgmwWindow {
+        use super::pippenger_utils::pippenger_window_size;
+
         #[cfg(feature = "parallel")]
         {
             let default_window = pippenger_window_size(npoints);
 
-            use super::{parallel_pippenger_utils::breakdown, thread_pool::da_pool};
+            use super::parallel_pippenger_utils::breakdown;
 
-            let pool = da_pool();
-            let ncpus = pool.max_count();
+            let ncpus = rayon::current_num_threads();
             if npoints > 32 && ncpus > 2 {
-                BgmwWindow::Parallel(breakdown(default_window, ncpus))
+                BgmwWindow::Parallel(breakdown(NBITS, default_window, ncpus))
             } else {
                 BgmwWindow::Sync(default_window)
             }
@@ -0,0 +1,240 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use crate::{Fr, G1ConstantTime, Scalar256, G1};
+
+use super::pippenger_utils::get_wval_limb;
+
+/// Window width of the constant-time routines. Every window costs a full scan over the
+/// `2^w`-entry table of each point, so larger windows trade fewer additions for more selects.
+pub const CT_WINDOW: usize = 4;
+
+const CT_TABLE_SIZE: usize = 1 << CT_WINDOW;
+const NBITS: usize = 256;
+
+/// Returns `1` if `a == b` and `0` otherwise, without branching.
+#[inline(always)]
+pub fn ct_eq(a: u64, b: u64) -> u64 {
+    let x = a ^ b;
+    ((x | x.wrapping_neg()) >> 63) ^ 1
+}
+
+/// Returns `b` if `choice` is `1` and `a` if it is `0`, limb by limb and without branching.
+/// Backends use this to implement [`G1ConstantTime::conditional_select`].
+#[inline(always)]
+pub fn ct_select_limbs<const N: usize>(a: &[u64; N], b: &[u64; N], choice: u64) -> [u64; N] {
+    // Keep the compiler from turning the mask back into a branch
+    let mask = core::hint::black_box(0u64.wrapping_sub(choice));
+    core::array::from_fn(|i| a[i] ^ (mask & (a[i] ^ b[i])))
+}
+
+/// The group operations the routines below are built from. They only see the group through this
+/// trait, so the tests can swap in a group that records the operations it is asked to do.
+trait CtGroup: Clone {
+    fn identity() -> Self;
+    fn dbl_assign(&mut self);
+    fn add_assign(&mut self, b: &Self);
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self;
+}
+
+impl<TG1: G1ConstantTime> CtGroup for TG1 {
+    fn identity() -> Self {
+        G1::identity()
+    }
+
+    fn dbl_assign(&mut self) {
+        G1::dbl_assign(self)
+    }
+
+    fn add_assign(&mut self, b: &Self) {
+        G1::add_or_dbl_assign(self, b)
+    }
+
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        G1ConstantTime::conditional_select(a, b, choice)
+    }
+}
+
+/// `[0, P, 2P, ..., (2^w - 1)P]`. The point is public, so the table may be built with ordinary
+/// (variable-time) arithmetic.
+fn ct_table<TG: CtGroup>(point: &TG) -> Vec<TG> {
+    let mut table = Vec::with_capacity(CT_TABLE_SIZE);
+    table.push(TG::identity());
+    table.push(point.clone());
+    for i in 2..CT_TABLE_SIZE {
+        let mut next = table[i - 1].clone();
+        next.add_assign(point);
+        table.push(next);
+    }
+
+    table
+}
+
+/// Read `table[digit]` touching every entry, so that neither the control flow nor the memory
+/// access pattern depends on `digit`.
+fn ct_lookup<TG: CtGroup>(table: &[TG], digit: u64) -> TG {
+    let mut ret = table[0].clone();
+    for (i, point) in table.iter().enumerate().skip(1) {
+        ret = TG::conditional_select(&ret, point, ct_eq(i as u64, digit));
+    }
+
+    ret
+}
+
+fn ct_digit(scalar: &Scalar256, window: usize) -> u64 {
+    get_wval_limb(scalar, window * CT_WINDOW, CT_WINDOW) & (CT_TABLE_SIZE as u64 - 1)
+}
+
+fn msm_ct_scalars<TG: CtGroup>(points: &[TG], scalars: &[Scalar256]) -> TG {
+    let tables = points.iter().map(ct_table).collect::<Vec<_>>();
+
+    let mut acc = TG::identity();
+    for window in (0..NBITS / CT_WINDOW).rev() {
+        for _ in 0..CT_WINDOW {
+            acc.dbl_assign();
+        }
+
+        for (table, scalar) in tables.iter().zip(scalars) {
+            acc.add_assign(&ct_lookup(table, ct_digit(scalar, window)));
+        }
+    }
+
+    acc
+}
+
+/// Constant-time `scalar * point`.
+///
+/// Fixed 4-bit windows over all 256 bits of the scalar, with one doubling chain and one
+/// addition per window regardless of the digit value, and table reads done by a full scan.
+/// This is only as constant-time as the backend's `dbl_assign`, `add_or_dbl_assign` and
+/// `conditional_select`, which must be free of data-dependent branches themselves.
+pub fn g1_mul_ct<TG1: G1ConstantTime>(point: &TG1, scalar: &Scalar256) -> TG1 {
+    msm_ct_scalars(core::slice::from_ref(point), core::slice::from_ref(scalar))
+}
+
+/// Constant-time multi-scalar multiplication, for when the scalars are secret.
+///
+/// Same algorithm as [`g1_mul_ct`], with the doublings shared between all points. It does
+/// `n * 64` additions and `256` doublings regardless of the scalars, which is a lot more than
+/// Pippenger needs, so only use it when the scalars must not leak.
+pub fn g1_msm_ct<TFr: Fr, TG1: G1ConstantTime>(points: &[TG1], scalars: &[TFr]) -> TG1 {
+    assert_eq!(
+        points.len(),
+        scalars.len(),
+        "Points and scalars must have the same length"
+    );
+
+    let scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+    msm_ct_scalars(points, &scalars)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+
+    use super::{ct_eq, ct_select_limbs, msm_ct_scalars, CtGroup, CT_TABLE_SIZE, CT_WINDOW, NBITS};
+    use crate::Scalar256;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Op {
+        Dbl,
+        Add,
+        Select,
+    }
+
+    std::thread_local! {
+        static TRACE: RefCell<Vec<Op>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record(op: Op) {
+        TRACE.with(|trace| trace.borrow_mut().push(op));
+    }
+
+    /// Integers modulo 2^64 standing in for the group, recording every operation done on them
+    #[derive(Clone)]
+    struct Traced(u64);
+
+    impl CtGroup for Traced {
+        fn identity() -> Self {
+            Traced(0)
+        }
+
+        fn dbl_assign(&mut self) {
+            record(Op::Dbl);
+            self.0 = self.0.wrapping_add(self.0);
+        }
+
+        fn add_assign(&mut self, b: &Self) {
+            record(Op::Add);
+            self.0 = self.0.wrapping_add(b.0);
+        }
+
+        fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+            record(Op::Select);
+            Traced(ct_select_limbs(&[a.0], &[b.0], choice)[0])
+        }
+    }
+
+    fn traced_msm(points: &[u64], scalars: &[Scalar256]) -> (u64, Vec<Op>) {
+        TRACE.with(|trace| trace.borrow_mut().clear());
+        let points = points.iter().copied().map(Traced).collect::<Vec<_>>();
+        let result = msm_ct_scalars(&points, scalars);
+        (result.0, TRACE.with(|trace| trace.take()))
+    }
+
+    #[test]
+    fn ct_eq_works() {
+        assert_eq!(ct_eq(0, 0), 1);
+        assert_eq!(ct_eq(15, 15), 1);
+        assert_eq!(ct_eq(u64::MAX, u64::MAX), 1);
+        assert_eq!(ct_eq(0, 1), 0);
+        assert_eq!(ct_eq(1 << 63, 0), 0);
+        assert_eq!(ct_eq(u64::MAX, 0), 0);
+    }
+
+    #[test]
+    fn ct_select_limbs_works() {
+        let a = [1u64, 2, 3];
+        let b = [u64::MAX, 0, 1 << 63];
+        assert_eq!(ct_select_limbs(&a, &b, 0), a);
+        assert_eq!(ct_select_limbs(&a, &b, 1), b);
+    }
+
+    #[test]
+    fn msm_ct_operations_do_not_depend_on_scalars() {
+        let points = [3u64, 0x1234_5678_9abc_def1];
+        let cases = [
+            [Scalar256::from_u64([0; 4]), Scalar256::from_u64([0; 4])],
+            [Scalar256::from_u64_s(1), Scalar256::from_u64_s(15)],
+            [
+                Scalar256::from_u64([u64::MAX; 4]),
+                Scalar256::from_u64([u64::MAX; 4]),
+            ],
+            [
+                Scalar256::from_u64([0x0123_4567_89ab_cdef, 7, 0, 1 << 63]),
+                Scalar256::from_u64([0xf0f0_f0f0_0f0f_0f0f, 0, u64::MAX, 42]),
+            ],
+        ];
+
+        let (_, expected) = traced_msm(&points, &cases[0]);
+        let windows = NBITS / CT_WINDOW;
+        let table_adds = points.len() * (CT_TABLE_SIZE - 2);
+        let window_ops = CT_WINDOW + points.len() * CT_TABLE_SIZE;
+        assert_eq!(expected.len(), table_adds + windows * window_ops);
+
+        for scalars in &cases {
+            let (result, trace) = traced_msm(&points, scalars);
+            assert_eq!(trace, expected);
+
+            // Only the lowest limb of each scalar survives modulo 2^64
+            let want = points.iter().zip(scalars).fold(0u64, |acc, (p, s)| {
+                acc.wrapping_add(p.wrapping_mul(s.data[0]))
+            });
+            assert_eq!(result, want);
+        }
+    }
+}
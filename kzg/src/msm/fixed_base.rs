@@ -0,0 +1,248 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::{Fr, G1Affine, G1Fp, G1GetFp, G1Mul, Scalar256, G1, G2};
+
+use super::bgmw::BgmwTable;
+use super::pippenger_utils::get_wval_limb;
+
+/// Window width used for the generator tables. With 4-bit windows the table holds 64 windows of
+/// 15 points each, which is ~140 KiB for G1 and ~280 KiB for G2 in projective form.
+pub const FIXED_BASE_WINDOW: usize = 4;
+
+const NBITS: usize = 256;
+
+/// Fixed-base windowed table for a single point `B`.
+///
+/// For window `i` and non-zero digit `d` the table stores `d * 2^(i * w) * B`, so a scalar
+/// multiplication is a sum of one table entry per non-zero window, with no doublings at all.
+#[derive(Debug, Clone)]
+pub struct FixedBaseTable<T> {
+    window: usize,
+    points: Vec<T>,
+    add: fn(&T, &T) -> T,
+}
+
+impl<T: Clone> FixedBaseTable<T> {
+    /// Build a table for `base`. `add` must handle equal inputs (i.e. be an "add or double").
+    pub fn new(base: &T, window: usize, add: fn(&T, &T) -> T) -> Self {
+        assert!(window > 0 && window <= 16, "Window size must be in 1..=16");
+
+        let digits = (1usize << window) - 1;
+        let windows = (NBITS + window - 1) / window;
+
+        let mut points = Vec::with_capacity(windows * digits);
+        let mut window_base = base.clone();
+        for _ in 0..windows {
+            let mut acc = window_base.clone();
+            points.push(acc.clone());
+            for _ in 1..digits {
+                acc = add(&acc, &window_base);
+                points.push(acc.clone());
+            }
+
+            // (2^w - 1) * B_i + B_i = 2^w * B_i = B_{i+1}
+            window_base = add(&acc, &window_base);
+        }
+
+        Self {
+            window,
+            points,
+            add,
+        }
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// The point this table was built for
+    pub fn base(&self) -> &T {
+        &self.points[0]
+    }
+
+    /// Multiply the base point by `scalar`. Returns `None` if the scalar is zero, as the table
+    /// does not know how to construct the identity of `T`.
+    pub fn mul(&self, scalar: &Scalar256) -> Option<T> {
+        let digits = (1usize << self.window) - 1;
+
+        let mut acc: Option<T> = None;
+        let mut bit = 0;
+        let mut row = 0;
+        while bit < NBITS {
+            let bits = core::cmp::min(self.window, NBITS - bit);
+            let digit = (get_wval_limb(scalar, bit, bits) & ((1u64 << bits) - 1)) as usize;
+            if digit != 0 {
+                let point = &self.points[row * digits + digit - 1];
+                acc = Some(match acc {
+                    Some(acc) => (self.add)(&acc, point),
+                    None => point.clone(),
+                });
+            }
+
+            bit += self.window;
+            row += 1;
+        }
+
+        acc
+    }
+}
+
+pub fn g1_fixed_base_table<TG1: G1>(base: &TG1) -> FixedBaseTable<TG1> {
+    FixedBaseTable::new(base, FIXED_BASE_WINDOW, TG1::add_or_dbl)
+}
+
+pub fn g1_fixed_base_mul<TFr: Fr, TG1: G1>(table: &FixedBaseTable<TG1>, scalar: &TFr) -> TG1 {
+    table.mul(&scalar.to_scalar()).unwrap_or_else(TG1::identity)
+}
+
+pub fn g2_fixed_base_table<TG2: G2>(base: &TG2) -> FixedBaseTable<TG2> {
+    FixedBaseTable::new(base, FIXED_BASE_WINDOW, |a, b| a.clone().add_or_dbl(b))
+}
+
+pub fn g2_fixed_base_mul<TFr: Fr, TG2: G2>(table: &FixedBaseTable<TG2>, scalar: &TFr) -> TG2 {
+    table.mul(&scalar.to_scalar()).unwrap_or_else(|| {
+        // G2 has no identity constructor, so build it as `B - B`
+        table.base().sub(table.base())
+    })
+}
+
+const MIN_MSM_WINDOW: usize = 2;
+const MAX_MSM_WINDOW: usize = 16;
+
+/// Multi-scalar multiplication over a set of points that is fixed in advance.
+///
+/// Uses the BGMW method: every point is stored together with its `2^(i * w)` multiples, so all
+/// windows of all scalars fall into a single set of buckets and no doublings are needed at
+/// multiplication time. This is the same table [`super::precompute::precompute`] builds for the
+/// trusted setup with the `bgmw` feature, but available for any point set and with explicit
+/// control over its size. Like the rest of `kzg::msm`, it expects `G1GetFp` to expose Jacobian
+/// coordinates.
+#[derive(Debug, Clone)]
+pub struct FixedBaseMsm<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    table: BgmwTable<TFr, TG1, TG1Fp, TG1Affine>,
+    numpoints: usize,
+    window: usize,
+}
+
+impl<TFr, TG1, TG1Fp, TG1Affine> FixedBaseMsm<TFr, TG1, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    /// Build a table with the window that is fastest for `points.len()` points, without any
+    /// limit on its size.
+    pub fn new(points: &[TG1]) -> Result<Self, String> {
+        Self::with_params(points, None, None)
+    }
+
+    /// Build a table for `points`.
+    ///
+    /// * `window` - bucket window width in bits (`2..=16`). If `None`, the fastest window is
+    ///   picked, and widened as far as needed to respect `max_table_size`.
+    /// * `max_table_size` - cap on the number of precomputed points, each of which is a
+    ///   `TG1Affine`. The table holds roughly `points.len() * 256 / window` of them.
+    pub fn with_params(
+        points: &[TG1],
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Self, String> {
+        if points.is_empty() {
+            return Err(String::from("Points must not be empty"));
+        }
+
+        let numpoints = points.len();
+        let fits = |window: usize| match max_table_size {
+            Some(max) => {
+                BgmwTable::<TFr, TG1, TG1Fp, TG1Affine>::table_size(numpoints.max(2), window) <= max
+            }
+            None => true,
+        };
+
+        let window = match window {
+            Some(window) => {
+                if !(MIN_MSM_WINDOW..=MAX_MSM_WINDOW).contains(&window) {
+                    return Err(String::from("Window size must be in 2..=16"));
+                }
+                if !fits(window) {
+                    return Err(String::from(
+                        "Precomputation table does not fit in the given table size",
+                    ));
+                }
+                window
+            }
+            None => (default_msm_window(numpoints)..=MAX_MSM_WINDOW)
+                .find(|&window| fits(window))
+                .ok_or_else(|| {
+                    String::from("Precomputation table does not fit in the given table size")
+                })?,
+        };
+
+        // The bucket accumulation reads scalars in pairs, so a single point is padded with a
+        // copy of itself that is always multiplied by zero.
+        let table = if numpoints == 1 {
+            BgmwTable::with_window(&[points[0].clone(), points[0].clone()], window)?
+        } else {
+            BgmwTable::with_window(points, window)?
+        };
+
+        Ok(Self {
+            table,
+            numpoints,
+            window,
+        })
+    }
+
+    /// Compute `sum(scalars[i] * points[i])`. `scalars` must have as many elements as the table
+    /// has points.
+    pub fn multiply(&self, scalars: &[TFr]) -> Result<TG1, String> {
+        if scalars.len() != self.numpoints {
+            return Err(String::from(
+                "Scalars length must be equal to the number of points",
+            ));
+        }
+
+        let mut scalars = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
+        if self.numpoints == 1 {
+            scalars.push(Scalar256::default());
+        }
+
+        Ok(self.table.multiply_sequential(&scalars))
+    }
+
+    /// Number of points the table was built for
+    pub fn len(&self) -> usize {
+        self.numpoints
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.numpoints == 0
+    }
+
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Number of precomputed points held by the table
+    pub fn table_size(&self) -> usize {
+        BgmwTable::<TFr, TG1, TG1Fp, TG1Affine>::table_size(self.numpoints.max(2), self.window)
+    }
+}
+
+/// Each point is added once per row of the table, and the buckets are summed once at the end,
+/// so the cost is about `npoints * 256 / w + 2^w` additions.
+fn default_msm_window(npoints: usize) -> usize {
+    (MIN_MSM_WINDOW..=MAX_MSM_WINDOW)
+        .min_by_key(|&window| npoints * (NBITS / window + 1) + (1 << window))
+        .unwrap()
+}
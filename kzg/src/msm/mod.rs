@@ -1,5 +1,7 @@
 pub mod arkmsm;
 pub mod cell;
+pub mod constant_time;
+pub mod fixed_base;
 pub mod msm_impls;
 pub mod precompute;
 #[cfg(feature = "parallel")]
@@ -9,9 +11,7 @@ pub mod tiling_parallel_pippenger;
 pub mod tiling_pippenger_ops;
 pub mod types;
 
+mod bgmw;
 #[cfg(feature = "parallel")]
 mod parallel_pippenger_utils;
 mod pippenger_utils;
-
-#[cfg(all(feature = "bgmw", any(not(feature = "arkmsm"), feature = "parallel")))]
-mod bgmw;
@@ -11,46 +11,27 @@ use super::tiling_pippenger_ops::tiling_pippenger;
 #[cfg(feature = "parallel")]
 use super::tiling_parallel_pippenger::{parallel_affine_conv, tiling_parallel_pippenger};
 
-#[cfg(feature = "parallel")]
-fn msm_parallel<
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
-    TG1Fp: G1Fp,
-    TG1Affine: G1Affine<TG1, TG1Fp>,
->(
-    points: &[TG1Affine],
-    scalars: &[Scalar256],
-    precomputation: Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
-) -> TG1 {
-    if let Some(precomputation) = precomputation {
-        precomputation.multiply_parallel(scalars)
-    } else {
-        tiling_parallel_pippenger(points, scalars)
-    }
-}
+/// Nonzero scalars no wider than this go through a separate pass with fewer windows. Blobs are
+/// often padded with zeros or hold small integers, which would otherwise pay for all 255 bits.
+pub const SMALL_SCALAR_BITS: usize = 64;
 
 #[cfg(not(feature = "parallel"))]
 #[allow(clippy::extra_unused_type_parameters)]
 #[allow(unused_variables)]
 fn msm_sequential<
-    TFr: Fr,
-    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+    TG1: G1 + G1GetFp<TG1Fp>,
     TG1Fp: G1Fp,
     TG1Affine: G1Affine<TG1, TG1Fp>,
     TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
 >(
     points: &[TG1Affine],
     scalars: &[Scalar256],
-    precomputation: Option<&PrecomputationTable<TFr, TG1, TG1Fp, TG1Affine>>,
+    nbits: usize,
 ) -> TG1 {
     #[cfg(not(feature = "arkmsm"))]
     {
         assert!(core::cmp::min(points.len(), scalars.len()) > 1);
-        if let Some(precomputation) = precomputation {
-            precomputation.multiply_sequential(scalars)
-        } else {
-            tiling_pippenger(points, scalars)
-        }
+        tiling_pippenger(points, scalars, nbits)
     }
 
     #[cfg(feature = "arkmsm")]
@@ -69,6 +50,65 @@ fn batch_convert<TG1: G1, TFp: G1Fp, TG1Affine: G1Affine<TG1, TFp> + Sized>(
     return TG1Affine::into_affines(points);
 }
 
+/// Nonzero scalars that are multiplied in the same pass, with the indices of their points
+#[derive(Default)]
+struct ScalarGroup {
+    indices: Vec<usize>,
+    scalars: Vec<Scalar256>,
+    nbits: usize,
+}
+
+impl ScalarGroup {
+    fn push(&mut self, index: usize, scalar: Scalar256, nbits: usize) {
+        self.indices.push(index);
+        self.scalars.push(scalar);
+        self.nbits = core::cmp::max(self.nbits, nbits);
+    }
+
+    #[allow(clippy::extra_unused_type_parameters)]
+    fn multiply<
+        TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+        TProjAddAffine: G1ProjAddAffine<TG1, TG1Fp, TG1Affine>,
+        TFr: Fr,
+    >(
+        &self,
+        points: &[TG1],
+        scalars: &[TFr],
+    ) -> TG1 {
+        if self.indices.len() < 8 {
+            let mut out = TG1::default();
+            for &i in &self.indices {
+                out.add_or_dbl_assign(&points[i].mul(&scalars[i]));
+            }
+            return out;
+        }
+
+        // Only the points that are actually used are converted to affine
+        let points = if self.indices.len() == points.len() {
+            batch_convert::<TG1, TG1Fp, TG1Affine>(points)
+        } else {
+            let points = self
+                .indices
+                .iter()
+                .map(|&i| points[i].clone())
+                .collect::<Vec<_>>();
+            batch_convert::<TG1, TG1Fp, TG1Affine>(&points)
+        };
+
+        #[cfg(feature = "parallel")]
+        return tiling_parallel_pippenger(&points, &self.scalars, self.nbits);
+
+        #[cfg(not(feature = "parallel"))]
+        return msm_sequential::<TG1, TG1Fp, TG1Affine, TProjAddAffine>(
+            &points,
+            &self.scalars,
+            self.nbits,
+        );
+    }
+}
+
 #[allow(clippy::extra_unused_type_parameters)]
 pub fn msm<
     TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
@@ -91,19 +131,44 @@ pub fn msm<
         return out;
     }
 
-    let points = batch_convert::<TG1, TG1Fp, TG1Affine>(&points[0..len]);
-    let scalars = scalars[0..len]
-        .iter()
-        .map(TFr::to_scalar)
-        .collect::<Vec<_>>();
+    let (points, scalars) = (&points[0..len], &scalars[0..len]);
+    let scalars256 = scalars.iter().map(TFr::to_scalar).collect::<Vec<_>>();
 
-    #[cfg(feature = "parallel")]
-    return msm_parallel::<TFr, TG1, TG1Fp, TG1Affine>(&points, &scalars, precomputation);
+    // Precomputed tables are laid out by point index, so they take every scalar and skip the
+    // windows above the widest one themselves
+    if let Some(precomputation) = precomputation {
+        #[cfg(feature = "parallel")]
+        return precomputation.multiply_parallel(&scalars256);
 
-    #[cfg(not(feature = "parallel"))]
-    return msm_sequential::<TFr, TG1, TG1Fp, TG1Affine, TProjAddAffine>(
-        &points,
-        &scalars,
-        precomputation,
-    );
+        #[cfg(not(feature = "parallel"))]
+        return precomputation.multiply_sequential(&scalars256);
+    }
+
+    // Zero scalars are dropped before paying for the affine conversion of their points.
+    // Sequential arkmsm always runs over all 255 bits, so there splitting off the small scalars
+    // would only add a pass.
+    let split_small = cfg!(any(not(feature = "arkmsm"), feature = "parallel"));
+    let mut small = ScalarGroup::default();
+    let mut large = ScalarGroup::default();
+    for (i, scalar) in scalars256.into_iter().enumerate() {
+        let nbits = scalar.num_bits();
+        if nbits == 0 {
+            continue;
+        }
+
+        let group = if split_small && nbits <= SMALL_SCALAR_BITS {
+            &mut small
+        } else {
+            &mut large
+        };
+        group.push(i, scalar, nbits);
+    }
+
+    let mut out = large.multiply::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(points, scalars);
+    if !small.indices.is_empty() {
+        out.add_or_dbl_assign(
+            &small.multiply::<TG1, TG1Fp, TG1Affine, TProjAddAffine, TFr>(points, scalars),
+        );
+    }
+    out
 }
@@ -1,35 +1,47 @@
 use crate::msm::pippenger_utils::num_bits;
 
-pub const fn breakdown(window: usize, ncpus: usize) -> (usize, usize, usize) {
-    const NBITS: usize = 255;
+/// Split an MSM over `nbits`-bit scalars into an `nx` by `ny` grid of tiles with `wnd`-bit
+/// windows, so that there is enough work for `ncpus` threads.
+pub const fn breakdown(nbits: usize, window: usize, ncpus: usize) -> (usize, usize, usize) {
     let mut nx: usize;
     let mut wnd: usize;
 
-    if NBITS > window * ncpus {
+    if nbits > window * ncpus {
         nx = 1;
         wnd = num_bits(ncpus / 4);
         if (window + wnd) > 18 {
             wnd = window - wnd;
         } else {
-            wnd = (NBITS / window + ncpus - 1) / ncpus;
-            if (NBITS / (window + 1) + ncpus - 1) / ncpus < wnd {
+            wnd = (nbits / window + ncpus - 1) / ncpus;
+            if (nbits / (window + 1) + ncpus - 1) / ncpus < wnd {
                 wnd = window + 1;
             } else {
                 wnd = window;
             }
         }
     } else {
+        // Narrow scalars leave few rows, so the window must not shrink to nothing while
+        // splitting the points into more columns
         nx = 2;
-        wnd = window - 2;
-        while (NBITS / wnd + 1) * nx < ncpus {
+        wnd = narrowed_window(window, nx);
+        while (nbits / wnd + 1) * nx < ncpus && narrowed_window(window, nx + 1) > 1 {
             nx += 1;
-            wnd = window - num_bits(3 * nx / 2);
+            wnd = narrowed_window(window, nx);
         }
         nx -= 1;
-        wnd = window - num_bits(3 * nx / 2);
+        wnd = narrowed_window(window, nx);
     }
-    let ny = NBITS / wnd + 1;
-    wnd = NBITS / ny + 1;
+    let ny = nbits / wnd + 1;
+    wnd = nbits / ny + 1;
 
     (nx, ny, wnd)
 }
+
+const fn narrowed_window(window: usize, nx: usize) -> usize {
+    let narrowing = num_bits(3 * nx / 2);
+    if window > narrowing + 1 {
+        window - narrowing
+    } else {
+        1
+    }
+}
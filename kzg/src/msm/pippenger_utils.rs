@@ -284,6 +284,11 @@ pub const fn num_bits(l: usize) -> usize {
     8 * core::mem::size_of::<usize>() - l.leading_zeros() as usize
 }
 
+/// Bit length of the widest scalar, or `0` if all of them are zero
+pub fn max_num_bits(scalars: &[Scalar256]) -> usize {
+    scalars.iter().map(Scalar256::num_bits).max().unwrap_or(0)
+}
+
 pub const fn pippenger_window_size(npoints: usize) -> usize {
     let wbits = num_bits(npoints);
 
@@ -299,10 +304,25 @@ pub const fn pippenger_window_size(npoints: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use crate::{
-        msm::pippenger_utils::{booth_encode, get_wval_limb},
+        msm::pippenger_utils::{booth_encode, get_wval_limb, max_num_bits},
         Scalar256,
     };
 
+    #[test]
+    fn max_num_bits_works() {
+        assert_eq!(max_num_bits(&[]), 0);
+        assert_eq!(max_num_bits(&[Scalar256::ZERO, Scalar256::ZERO]), 0);
+        assert_eq!(max_num_bits(&[Scalar256::ZERO, Scalar256::ONE]), 1);
+        assert_eq!(
+            max_num_bits(&[Scalar256::from_u64_s(u64::MAX), Scalar256::from_u64_s(5)]),
+            64
+        );
+        assert_eq!(
+            max_num_bits(&[Scalar256::from_u64([0, 0, 0, 1 << 62])]),
+            255
+        );
+    }
+
     #[test]
     fn booth_encode_must_produce_correct_results() {
         assert_eq!(booth_encode(0, 1), 0);
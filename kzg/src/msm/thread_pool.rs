@@ -1,33 +1,86 @@
-pub trait ThreadPoolExt {
-    fn joined_execute<'any, F>(&self, job: F)
-    where
-        F: FnOnce() + Send + 'any;
-}
+extern crate alloc;
 
-use core::mem::transmute;
-use std::sync::{Mutex, Once};
-use threadpool::ThreadPool;
+use alloc::{string::String, sync::Arc};
+use core::fmt;
 
-pub fn da_pool() -> ThreadPool {
-    static INIT: Once = Once::new();
-    static mut POOL: *const Mutex<ThreadPool> = 0 as *const Mutex<ThreadPool>;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 
-    INIT.call_once(|| {
-        let pool = Mutex::new(ThreadPool::default());
-        unsafe { POOL = transmute(Box::new(pool)) };
-    });
-    unsafe { (*POOL).lock().unwrap().clone() }
+/// Where parallel work runs.
+///
+/// MSM, FFT and batch verification all use rayon, so they run on whatever pool is current when
+/// they are called. By default that is rayon's global pool, which makes the whole library share a
+/// single pool. To use a different one, either run individual calls through [`Self::install`],
+/// or attach a context to the `FFTSettings`, which passes it on to the `KZGSettings` built from
+/// it. The FFTs, recovery, commitments, multi-proofs, FK20 and the EIP-4844 entry points install
+/// that context themselves (see [`crate::FFTSettings::get_execution_context`] and
+/// [`crate::KZGSettings::get_execution_context`]).
+#[derive(Clone, Default)]
+pub struct ExecutionContext {
+    pool: Option<Arc<ThreadPool>>,
 }
 
-type Thunk<'any> = Box<dyn FnOnce() + Send + 'any>;
+static GLOBAL: ExecutionContext = ExecutionContext::global();
+
+impl ExecutionContext {
+    /// Run on rayon's global pool, or on the pool the caller is already running in.
+    pub const fn global() -> Self {
+        Self { pool: None }
+    }
+
+    /// Shared instance of [`Self::global`], for settings that do not carry their own context.
+    pub fn global_ref() -> &'static Self {
+        &GLOBAL
+    }
+
+    /// Run on a pool owned by the caller, e.g. one shared with the rest of the application.
+    pub fn from_pool(pool: Arc<ThreadPool>) -> Self {
+        Self { pool: Some(pool) }
+    }
+
+    /// Run on a dedicated pool of `num_threads` threads.
+    pub fn with_threads(num_threads: usize) -> Result<Self, String> {
+        if num_threads == 0 {
+            return Err(String::from("Thread count must be positive"));
+        }
+
+        ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map(|pool| Self::from_pool(Arc::new(pool)))
+            .map_err(|err| format!("Failed to build thread pool: {err}"))
+    }
+
+    pub fn pool(&self) -> Option<&Arc<ThreadPool>> {
+        self.pool.as_ref()
+    }
+
+    /// Number of threads parallel work will be split across.
+    pub fn num_threads(&self) -> usize {
+        match &self.pool {
+            Some(pool) => pool.current_num_threads(),
+            None => rayon::current_num_threads(),
+        }
+    }
+
+    /// Run `op` in this context. Everything rayon-based called from `op`, including nested
+    /// MSMs and FFTs, runs on the same pool.
+    pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+        match &self.pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+}
 
-impl ThreadPoolExt for ThreadPool {
-    fn joined_execute<'scope, F>(&self, job: F)
-    where
-        F: FnOnce() + Send + 'scope,
-    {
-        // Bypass 'lifetime limitations by brute force. It works,
-        // because we explicitly join the threads...
-        self.execute(unsafe { transmute::<Thunk<'scope>, Thunk<'static>>(Box::new(job)) })
+impl fmt::Debug for ExecutionContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.pool {
+            Some(pool) => write!(
+                f,
+                "ExecutionContext({} threads)",
+                pool.current_num_threads()
+            ),
+            None => f.write_str("ExecutionContext(global)"),
+        }
     }
 }
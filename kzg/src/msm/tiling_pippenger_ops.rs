@@ -41,10 +41,10 @@ pub fn p1s_tile_pippenger_pub<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Af
     buckets: &mut [P1XYZZ<TFp>],
     bit0: usize,
     window: usize,
+    nbits: usize,
 ) {
-    const NBITS: usize = 255;
-    let (wbits, cbits) = if bit0 + window > NBITS {
-        let wbits = NBITS - bit0;
+    let (wbits, cbits) = if bit0 + window > nbits {
+        let wbits = nbits - bit0;
         (wbits, wbits + 1)
     } else {
         (window, window)
@@ -132,16 +132,19 @@ pub fn p1s_tile_pippenger<TG1: G1 + G1GetFp<TFp>, TFp: G1Fp, TG1Affine: G1Affine
     p1_integrate_buckets(ret, buckets, cbits - 1);
 }
 
+/// Pippenger over the lowest `nbits` bits of the scalars. All scalars must be below `2^nbits`,
+/// which lets narrow scalars skip the windows that would only ever see zero digits.
 pub fn tiling_pippenger<TG1: G1 + G1GetFp<TG1Fp>, TG1Fp: G1Fp, TG1Affine: G1Affine<TG1, TG1Fp>>(
     points: &[TG1Affine],
     scalars: &[Scalar256],
+    nbits: usize,
 ) -> TG1 {
     let window = pippenger_window_size(points.len());
     let mut buckets = vec![P1XYZZ::<TG1Fp>::default(); 1 << (window - 1)];
 
-    let mut wbits: usize = 255 % window;
+    let mut wbits: usize = nbits % window;
     let mut cbits: usize = wbits + 1;
-    let mut bit0: usize = 255;
+    let mut bit0: usize = nbits;
     let mut tile = TG1::default();
 
     let mut ret = TG1::default();
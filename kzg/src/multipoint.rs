@@ -0,0 +1,230 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::common_utils::{log2_pow2, next_pow_of_2};
+use crate::{FFTSettings, FFTSettingsPoly, Fr, Poly};
+
+// Below these lengths schoolbook multiplication beats FFT, same as in the backends' `Poly::mul`
+const DIRECT_MUL_THRESHOLD: usize = 64;
+// Nodes covering at most this many points have their remainder evaluated at each point directly
+// instead of being reduced further down the tree
+const DIRECT_EVAL_THRESHOLD: usize = 32;
+
+/// Subproduct tree over a set of points `xs`.
+///
+/// Leaves are the linear factors `x - xs[i]`, and every other node is the product of its two
+/// children, so the root is the vanishing polynomial of all the points. Building the tree, and
+/// evaluating or interpolating over it, takes O(n log² n) field operations for n points.
+///
+/// Nodes of layer `l` cover `2^l` consecutive points. When a layer has an odd number of nodes
+/// the last one has no sibling and is carried up to the next layer as is.
+pub struct SubproductTree<TFr, TPoly, TFFTSettings>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    xs: Vec<TFr>,
+    layers: Vec<Vec<TPoly>>,
+    fs: TFFTSettings,
+}
+
+impl<TFr, TPoly, TFFTSettings> SubproductTree<TFr, TPoly, TFFTSettings>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    pub fn new(xs: &[TFr]) -> Result<Self, String> {
+        if xs.is_empty() {
+            return Err(String::from("Subproduct tree needs at least one point"));
+        }
+
+        // The longest product anywhere is a node of up to twice the padded point count
+        let fs = TFFTSettings::new(log2_pow2(next_pow_of_2(xs.len())) + 1)?;
+
+        let leaves = xs
+            .iter()
+            .map(|x| TPoly::from_coeffs(&[x.negate(), TFr::one()]))
+            .collect::<Vec<_>>();
+
+        let mut layers = vec![leaves];
+        while layers[layers.len() - 1].len() > 1 {
+            let layer = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => mul(left, right, left.len() + right.len() - 1, &fs),
+                    _ => Ok(pair[0].clone()),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            layers.push(layer);
+        }
+
+        Ok(Self {
+            xs: xs.to_vec(),
+            layers,
+            fs,
+        })
+    }
+
+    pub fn points(&self) -> &[TFr] {
+        &self.xs
+    }
+
+    /// Vanishing polynomial of all points, `prod(x - xs[i])`.
+    pub fn root(&self) -> &TPoly {
+        &self.layers[self.layers.len() - 1][0]
+    }
+
+    /// Evaluates `poly` at every point, by reducing it modulo the nodes from the root down.
+    pub fn evaluate(&self, poly: &TPoly) -> Result<Vec<TFr>, String> {
+        let mut level = self.layers.len() - 1;
+        let mut remainders = vec![remainder(poly, self.root(), &self.fs)?];
+
+        while level > 0 && (1 << level) > DIRECT_EVAL_THRESHOLD {
+            level -= 1;
+            remainders = self.layers[level]
+                .iter()
+                .enumerate()
+                .map(|(i, node)| remainder(&remainders[i / 2], node, &self.fs))
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(self
+            .xs
+            .chunks(1 << level)
+            .zip(&remainders)
+            .flat_map(|(xs, remainder)| xs.iter().map(move |x| remainder.eval(x)))
+            .collect())
+    }
+
+    /// Polynomial of length `xs.len()` taking the value `ys[i]` at `xs[i]`.
+    ///
+    /// Uses the Lagrange form `sum(ys[i] / m'(xs[i]) * m(x) / (x - xs[i]))`, where `m` is the
+    /// root, and combines the terms from the leaves up.
+    pub fn interpolate(&self, ys: &[TFr]) -> Result<TPoly, String> {
+        if ys.len() != self.xs.len() {
+            return Err(String::from("ys must have one value per point"));
+        }
+
+        let denominators = self.evaluate(&self.root().derivative())?;
+
+        let mut layer = ys
+            .iter()
+            .zip(&denominators)
+            .map(|(y, denominator)| {
+                if denominator.is_zero() {
+                    return Err(String::from("Interpolation points must be distinct"));
+                }
+                Ok(TPoly::from_coeffs(&[y.div(denominator)?]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for nodes in &self.layers[..self.layers.len() - 1] {
+            layer = layer
+                .chunks(2)
+                .zip(nodes.chunks(2))
+                .map(|(values, nodes)| match (values, nodes) {
+                    ([left_value, right_value], [left_node, right_node]) => {
+                        let len = left_node.len() + right_node.len() - 2;
+                        let left = mul(left_value, right_node, len, &self.fs)?;
+                        let right = mul(right_value, left_node, len, &self.fs)?;
+                        Ok(left.add(&right))
+                    }
+                    _ => Ok(values[0].clone()),
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+        }
+
+        Ok(layer.swap_remove(0))
+    }
+}
+
+/// Evaluates `poly` at every point of `xs` in O(n log² n), n being the larger of the polynomial
+/// length and the number of points.
+pub fn evaluate_multipoint<TFr, TPoly, TFFTSettings>(
+    poly: &TPoly,
+    xs: &[TFr],
+) -> Result<Vec<TFr>, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    if xs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    SubproductTree::<TFr, TPoly, TFFTSettings>::new(xs)?.evaluate(poly)
+}
+
+/// Polynomial of length `xs.len()` taking the value `ys[i]` at `xs[i]`, in O(n log² n). See
+/// [`Poly::interpolate`] for the quadratic version, which is faster for a handful of points.
+pub fn interpolate_multipoint<TFr, TPoly, TFFTSettings>(
+    xs: &[TFr],
+    ys: &[TFr],
+) -> Result<TPoly, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    if xs.len() != ys.len() {
+        return Err(String::from("xs and ys must have the same length"));
+    }
+    if xs.is_empty() {
+        return Ok(TPoly::new(0));
+    }
+
+    SubproductTree::<TFr, TPoly, TFFTSettings>::new(xs)?.interpolate(ys)
+}
+
+/// First `len` coefficients of `a * b`.
+fn mul<TFr, TPoly, TFFTSettings>(
+    a: &TPoly,
+    b: &TPoly,
+    len: usize,
+    fs: &TFFTSettings,
+) -> Result<TPoly, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    if a.len() < DIRECT_MUL_THRESHOLD || b.len() < DIRECT_MUL_THRESHOLD {
+        a.clone().mul_direct(b, len)
+    } else {
+        TFFTSettings::poly_mul_fft(a, b, len, Some(fs))
+    }
+}
+
+/// `poly mod node`, as `poly - (poly / node) * node`. Only the low `node.len() - 1` coefficients
+/// of the product are needed, since the rest cancel out.
+fn remainder<TFr, TPoly, TFFTSettings>(
+    poly: &TPoly,
+    node: &TPoly,
+    fs: &TFFTSettings,
+) -> Result<TPoly, String>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTSettingsPoly<TFr, TPoly, TFFTSettings>,
+{
+    let degree = node.len() - 1;
+    if poly.len() <= degree {
+        return Ok(poly.clone());
+    }
+
+    let quotient = poly.clone().div(node)?;
+    let product = mul(&quotient, node, degree, fs)?;
+    let coeffs = poly.get_coeffs()[..degree]
+        .iter()
+        .zip(product.get_coeffs())
+        .map(|(coeff, product)| coeff.sub(product))
+        .collect::<Vec<_>>();
+
+    Ok(TPoly::from_coeffs(&coeffs))
+}
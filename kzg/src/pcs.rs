@@ -0,0 +1,486 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::batch_opening::{challenge_powers, combine_values, compute_batch_same_point_challenge};
+use crate::common_utils::{is_power_of_two, log2_pow2, reverse_bit_order};
+use crate::{
+    FFTFr, FFTSettings, FK20MultiSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul,
+    KZGSettings, PairingVerify, Poly, G1, G2,
+};
+
+/// Commitments to polynomials, and proofs of what they evaluate to, for one variant of KZG.
+///
+/// Proving and verifying take different keys. The prover key is whatever the variant commits and
+/// opens with, [`KZGSettings`] over the whole setup for most of them. The verifier key, cut down
+/// from it by [`Self::verifier_key`], holds only the few points checking a proof takes, so it can
+/// be shipped without the rest of the setup.
+pub trait PolynomialCommitmentScheme {
+    type Polynomial;
+
+    type Commitment;
+
+    /// Where [`Self::open`] opens a polynomial.
+    type Point;
+
+    /// The point a polynomial was opened at together with the values it takes there, which is
+    /// what [`Self::verify`] checks the proof against.
+    type Opening;
+
+    type Proof;
+
+    type ProverKey;
+
+    type VerifierKey;
+
+    fn verifier_key(pk: &Self::ProverKey) -> Result<Self::VerifierKey, String>;
+
+    fn commit(pk: &Self::ProverKey, poly: &Self::Polynomial) -> Result<Self::Commitment, String>;
+
+    /// Opens `poly`, committed to as `commitment`, at `point`.
+    fn open(
+        pk: &Self::ProverKey,
+        poly: &Self::Polynomial,
+        commitment: &Self::Commitment,
+        point: &Self::Point,
+    ) -> Result<(Self::Opening, Self::Proof), String>;
+
+    fn verify(
+        vk: &Self::VerifierKey,
+        commitment: &Self::Commitment,
+        opening: &Self::Opening,
+        proof: &Self::Proof,
+    ) -> Result<bool, String>;
+}
+
+/// Everything checking a single-point opening takes: the G1 and G2 generators and `[τ]` in G2.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KzgVerifierKey<TG1, TG2> {
+    pub g1: TG1,
+    pub g2: TG2,
+    pub tau_g2: TG2,
+}
+
+impl<TG1: G1, TG2: G2> KzgVerifierKey<TG1, TG2> {
+    /// Takes the generators and `[τ]` from a setup's first monomial G1 point and first two G2
+    /// points.
+    pub fn from_setup(g1_monomial: &[TG1], g2: &[TG2]) -> Result<Self, String> {
+        if g1_monomial.is_empty() || g2.len() < 2 {
+            return Err(String::from(
+                "Verifier key needs one G1 and two G2 points from the setup",
+            ));
+        }
+
+        Ok(Self {
+            g1: g1_monomial[0].clone(),
+            g2: g2[0].clone(),
+            tau_g2: g2[1].clone(),
+        })
+    }
+
+    /// Checks that `proof` opens `commitment` to `y` at `z`. Rather than `e(C - [y], [1]) =
+    /// e(π, [τ - z])` this checks `e(C - [y] + z * π, [1]) = e(π, [τ])`, which is the same
+    /// equation with the multiplication by `z` moved to G1, where it is cheaper.
+    pub fn check_proof_single<TFr: Fr>(
+        &self,
+        commitment: &TG1,
+        proof: &TG1,
+        z: &TFr,
+        y: &TFr,
+    ) -> bool
+    where
+        TG1: G1Mul<TFr> + PairingVerify<TG1, TG2>,
+    {
+        let lhs = commitment.sub(&self.g1.mul(y)).add_or_dbl(&proof.mul(z));
+        TG1::verify(&lhs, &self.g2, proof, &self.tau_g2)
+    }
+}
+
+/// A point and the value a polynomial takes there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KzgOpening<TFr> {
+    pub z: TFr,
+    pub y: TFr,
+}
+
+/// Plain KZG: one polynomial opened at one point, with [`KZGSettings::compute_proof_single`].
+#[allow(clippy::type_complexity)]
+pub struct Kzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>(
+    PhantomData<(
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+    )>,
+);
+
+impl<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> PolynomialCommitmentScheme
+    for Kzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    type Polynomial = TPoly;
+    type Commitment = TG1;
+    type Point = TFr;
+    type Opening = KzgOpening<TFr>;
+    type Proof = TG1;
+    type ProverKey = TKZGSettings;
+    type VerifierKey = KzgVerifierKey<TG1, TG2>;
+
+    fn verifier_key(pk: &TKZGSettings) -> Result<Self::VerifierKey, String> {
+        KzgVerifierKey::from_setup(pk.get_g1_monomial(), pk.get_g2_secret())
+    }
+
+    fn commit(pk: &TKZGSettings, poly: &TPoly) -> Result<TG1, String> {
+        pk.commit_to_poly(poly)
+    }
+
+    fn open(
+        pk: &TKZGSettings,
+        poly: &TPoly,
+        _commitment: &TG1,
+        point: &TFr,
+    ) -> Result<(KzgOpening<TFr>, TG1), String> {
+        let proof = pk.compute_proof_single(poly, point)?;
+        let opening = KzgOpening {
+            z: point.clone(),
+            y: poly.eval(point),
+        };
+
+        Ok((opening, proof))
+    }
+
+    fn verify(
+        vk: &Self::VerifierKey,
+        commitment: &TG1,
+        opening: &KzgOpening<TFr>,
+        proof: &TG1,
+    ) -> Result<bool, String> {
+        Ok(vk.check_proof_single(commitment, proof, &opening.z, &opening.y))
+    }
+}
+
+/// A point and the values several polynomials take there.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BatchKzgOpening<TFr> {
+    pub z: TFr,
+    pub ys: Vec<TFr>,
+}
+
+/// Several polynomials opened at the same point with one proof, with
+/// [`KZGSettings::compute_proof_batch_same_point`]. The verifier combines the commitments and
+/// values with the same challenge powers and checks a single opening, so it needs no more than
+/// for [`Kzg`].
+#[allow(clippy::type_complexity)]
+pub struct BatchKzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>(
+    PhantomData<(
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TG1Fp,
+        TG1Affine,
+    )>,
+);
+
+impl<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine> PolynomialCommitmentScheme
+    for BatchKzg<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>
+where
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>
+        + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    type Polynomial = Vec<TPoly>;
+    type Commitment = Vec<TG1>;
+    type Point = TFr;
+    type Opening = BatchKzgOpening<TFr>;
+    type Proof = TG1;
+    type ProverKey = TKZGSettings;
+    type VerifierKey = KzgVerifierKey<TG1, TG2>;
+
+    fn verifier_key(pk: &TKZGSettings) -> Result<Self::VerifierKey, String> {
+        KzgVerifierKey::from_setup(pk.get_g1_monomial(), pk.get_g2_secret())
+    }
+
+    fn commit(pk: &TKZGSettings, polys: &Vec<TPoly>) -> Result<Vec<TG1>, String> {
+        polys.iter().map(|poly| pk.commit_to_poly(poly)).collect()
+    }
+
+    fn open(
+        pk: &TKZGSettings,
+        polys: &Vec<TPoly>,
+        commitments: &Vec<TG1>,
+        point: &TFr,
+    ) -> Result<(BatchKzgOpening<TFr>, TG1), String> {
+        let proof = pk.compute_proof_batch_same_point(polys, commitments, point)?;
+        let opening = BatchKzgOpening {
+            z: point.clone(),
+            ys: polys.iter().map(|poly| poly.eval(point)).collect(),
+        };
+
+        Ok((opening, proof))
+    }
+
+    fn verify(
+        vk: &Self::VerifierKey,
+        commitments: &Vec<TG1>,
+        opening: &BatchKzgOpening<TFr>,
+        proof: &TG1,
+    ) -> Result<bool, String> {
+        let r = compute_batch_same_point_challenge(commitments, &opening.z, &opening.ys)?;
+        let powers = challenge_powers(&r, commitments.len());
+
+        let commitment = TG1::g1_lincomb(commitments, &powers, commitments.len(), None);
+        let value = combine_values(&opening.ys, &powers);
+
+        Ok(vk.check_proof_single(&commitment, proof, &opening.z, &value))
+    }
+}
+
+/// Settings for FK20 multi-proofs of polynomials of up to `n2 / 2` coefficients, which open them
+/// over the `n2 / chunk_len` cosets of size `chunk_len` that cover the domain of size `n2`.
+pub struct FK20MultiProverKey<TFFTSettings, TKZGSettings, TFK20MultiSettings> {
+    ks: TKZGSettings,
+    fk: TFK20MultiSettings,
+    fs: TFFTSettings,
+    chunk_len: usize,
+}
+
+impl<TFFTSettings, TKZGSettings, TFK20MultiSettings>
+    FK20MultiProverKey<TFFTSettings, TKZGSettings, TFK20MultiSettings>
+{
+    pub fn new<TFr, TG1, TG2, TPoly, TG1Fp, TG1Affine>(
+        ks: TKZGSettings,
+        n2: usize,
+        chunk_len: usize,
+    ) -> Result<Self, String>
+    where
+        TFr: Fr,
+        TG1: G1 + G1Mul<TFr> + G1GetFp<TG1Fp>,
+        TG2: G2,
+        TFFTSettings: FFTSettings<TFr>,
+        TPoly: Poly<TFr>,
+        TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+        TFK20MultiSettings:
+            FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+        TG1Fp: G1Fp,
+        TG1Affine: G1Affine<TG1, TG1Fp>,
+    {
+        if !is_power_of_two(n2) || !is_power_of_two(chunk_len) || chunk_len > n2 / 2 {
+            return Err(String::from(
+                "n2 and chunk_len must be powers of two, with chunk_len at most n2 / 2",
+            ));
+        }
+
+        let fk = TFK20MultiSettings::new(&ks, n2, chunk_len)?;
+        let fs = TFFTSettings::new(log2_pow2(n2))?;
+
+        Ok(Self {
+            ks,
+            fk,
+            fs,
+            chunk_len,
+        })
+    }
+
+    pub fn get_kzg_settings(&self) -> &TKZGSettings {
+        &self.ks
+    }
+
+    pub fn get_fk20_settings(&self) -> &TFK20MultiSettings {
+        &self.fk
+    }
+}
+
+/// Everything checking FK20 multi-proofs takes: `[τ^i]` in G1 for `i < chunk_len`, to commit to
+/// the polynomial interpolating the values over a coset, the G2 generator and `[τ^chunk_len]`
+/// in G2, and the roots of unity of the domain the cosets cover.
+#[derive(Debug, Clone)]
+pub struct FK20MultiVerifierKey<TG1, TG2, TFFTSettings> {
+    pub g1_powers: Vec<TG1>,
+    pub g2: TG2,
+    pub tau_pow_g2: TG2,
+    pub fs: TFFTSettings,
+}
+
+/// The values of a polynomial over every coset: `ys[i]` are those over the coset of the `i`-th
+/// proof, `x_i * w^j` for `x_i` the `i * chunk_len`-th of the bit-reversed roots of unity and
+/// `w` the root of unity of order `chunk_len`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FK20MultiOpening<TFr> {
+    pub ys: Vec<Vec<TFr>>,
+}
+
+/// FK20 multi-proofs: one polynomial opened over every coset of the domain at once, with
+/// [`FK20MultiSettings::data_availability`]. There's nothing to choose where to open it, so the
+/// point is `()`.
+#[allow(clippy::type_complexity)]
+pub struct FK20Multi<
+    TFr,
+    TG1,
+    TG2,
+    TFFTSettings,
+    TPoly,
+    TKZGSettings,
+    TFK20MultiSettings,
+    TG1Fp,
+    TG1Affine,
+>(
+    PhantomData<(
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TFK20MultiSettings,
+        TG1Fp,
+        TG1Affine,
+    )>,
+);
+
+impl<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TFK20MultiSettings, TG1Fp, TG1Affine>
+    PolynomialCommitmentScheme
+    for FK20Multi<
+        TFr,
+        TG1,
+        TG2,
+        TFFTSettings,
+        TPoly,
+        TKZGSettings,
+        TFK20MultiSettings,
+        TG1Fp,
+        TG1Affine,
+    >
+where
+    TFr: Fr,
+    TG1: G1
+        + G1Mul<TFr>
+        + G1GetFp<TG1Fp>
+        + G1LinComb<TFr, TG1Fp, TG1Affine>
+        + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TFK20MultiSettings:
+        FK20MultiSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TKZGSettings, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+{
+    type Polynomial = TPoly;
+    type Commitment = TG1;
+    type Point = ();
+    type Opening = FK20MultiOpening<TFr>;
+    type Proof = Vec<TG1>;
+    type ProverKey = FK20MultiProverKey<TFFTSettings, TKZGSettings, TFK20MultiSettings>;
+    type VerifierKey = FK20MultiVerifierKey<TG1, TG2, TFFTSettings>;
+
+    fn verifier_key(pk: &Self::ProverKey) -> Result<Self::VerifierKey, String> {
+        let g1 = pk.ks.get_g1_monomial();
+        let g2 = pk.ks.get_g2_secret();
+        if g1.len() < pk.chunk_len || g2.len() <= pk.chunk_len {
+            return Err(String::from("Setup is too short for the chunk length"));
+        }
+
+        Ok(FK20MultiVerifierKey {
+            g1_powers: g1[..pk.chunk_len].to_vec(),
+            g2: g2[0].clone(),
+            tau_pow_g2: g2[pk.chunk_len].clone(),
+            fs: pk.fs.clone(),
+        })
+    }
+
+    fn commit(pk: &Self::ProverKey, poly: &TPoly) -> Result<TG1, String> {
+        pk.ks.commit_to_poly(poly)
+    }
+
+    fn open(
+        pk: &Self::ProverKey,
+        poly: &TPoly,
+        _commitment: &TG1,
+        _point: &(),
+    ) -> Result<(FK20MultiOpening<TFr>, Vec<TG1>), String> {
+        let n2 = pk.fs.get_max_width();
+        if poly.len() > n2 / 2 {
+            return Err(String::from("Polynomial is longer than half the domain"));
+        }
+
+        let proofs = pk.fk.data_availability(poly)?;
+
+        // The values over the whole domain in bit-reversed order, where every coset is a chunk
+        let mut coeffs = poly.get_coeffs().to_vec();
+        coeffs.resize(n2, TFr::zero());
+        let mut evals = pk.fs.fft_fr(&coeffs, false)?;
+        reverse_bit_order(&mut evals)?;
+
+        let ys = evals
+            .chunks(pk.chunk_len)
+            .map(|chunk| {
+                let mut ys = chunk.to_vec();
+                reverse_bit_order(&mut ys)?;
+                Ok(ys)
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok((FK20MultiOpening { ys }, proofs))
+    }
+
+    /// Checks every coset, with `e(C - [I] + x^n * π, [1]) = e(π, [τ^n])` for `I` the polynomial
+    /// interpolating the values over the coset of `x`, like [`Kzg`] does for single points.
+    fn verify(
+        vk: &Self::VerifierKey,
+        commitment: &TG1,
+        opening: &FK20MultiOpening<TFr>,
+        proofs: &Vec<TG1>,
+    ) -> Result<bool, String> {
+        let chunk_len = vk.g1_powers.len();
+        if opening.ys.len() != proofs.len()
+            || opening.ys.len() * chunk_len != vk.fs.get_max_width()
+            || opening.ys.iter().any(|ys| ys.len() != chunk_len)
+        {
+            return Err(String::from(
+                "Opening must have one chunk of values per coset, and a proof for each",
+            ));
+        }
+
+        for (i, (ys, proof)) in opening.ys.iter().zip(proofs).enumerate() {
+            let x = vk.fs.get_roots_of_unity_at(i * chunk_len);
+            let interpolation = vk.fs.coset_fft_fr(ys, &x, true)?;
+            let interpolation = TG1::g1_lincomb(&vk.g1_powers, &interpolation, chunk_len, None);
+
+            let lhs = commitment
+                .sub(&interpolation)
+                .add_or_dbl(&proof.mul(&x.pow(chunk_len)));
+            if !TG1::verify(&lhs, &vk.g2, proof, &vk.tau_pow_g2) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+}
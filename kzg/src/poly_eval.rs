@@ -0,0 +1,255 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::common_utils::reverse_bit_order;
+use crate::{FFTFr, FFTSettings, Fr, Poly};
+
+/// Polynomial in evaluation form: its values over a power-of-two domain of roots of unity.
+///
+/// The type doesn't fix the order of the domain. Operations that need the domain points take
+/// them as a slice in the same order as the evaluations, so the same code serves natural order
+/// (as produced by [`Self::from_poly`]) and the bit-reversed order used for EIP-4844 blobs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolyEval<TFr: Fr> {
+    evals: Vec<TFr>,
+}
+
+impl<TFr: Fr> PolyEval<TFr> {
+    pub fn new(evals: Vec<TFr>) -> Result<Self, String> {
+        if !evals.len().is_power_of_two() {
+            return Err(String::from("Domain size must be a power of 2"));
+        }
+
+        Ok(Self { evals })
+    }
+
+    pub fn from_evals(evals: &[TFr]) -> Result<Self, String> {
+        Self::new(evals.to_vec())
+    }
+
+    /// Evaluates `poly` over the `len`-th roots of unity, in natural order.
+    pub fn from_poly<TPoly: Poly<TFr>, TFFTSettings: FFTFr<TFr>>(
+        poly: &TPoly,
+        len: usize,
+        fs: &TFFTSettings,
+    ) -> Result<Self, String> {
+        if poly.len() > len {
+            return Err(String::from(
+                "Polynomial must not be longer than the domain",
+            ));
+        }
+
+        let mut coeffs = poly.get_coeffs().to_vec();
+        coeffs.resize(len, TFr::zero());
+
+        Self::new(fs.fft_fr(&coeffs, false)?)
+    }
+
+    /// Coefficient form of the polynomial. Expects the evaluations in natural order.
+    pub fn to_poly<TPoly: Poly<TFr>, TFFTSettings: FFTFr<TFr>>(
+        &self,
+        fs: &TFFTSettings,
+    ) -> Result<TPoly, String> {
+        Ok(TPoly::from_coeffs(&fs.fft_fr(&self.evals, true)?))
+    }
+
+    pub fn evals(&self) -> &[TFr] {
+        &self.evals
+    }
+
+    pub fn len(&self) -> usize {
+        self.evals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.evals.is_empty()
+    }
+
+    /// Switches between natural and bit-reversed order of the domain.
+    pub fn reverse_bit_order(&mut self) -> Result<(), String> {
+        reverse_bit_order(&mut self.evals)
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, String> {
+        self.zip_with(other, |a, b| a.add(b))
+    }
+
+    pub fn sub(&self, other: &Self) -> Result<Self, String> {
+        self.zip_with(other, |a, b| a.sub(b))
+    }
+
+    /// Pointwise product. Only equal to the product of the polynomials when that still fits in
+    /// the domain, i.e. when the degrees sum to less than its size.
+    pub fn mul(&self, other: &Self) -> Result<Self, String> {
+        self.zip_with(other, |a, b| a.mul(b))
+    }
+
+    pub fn mul_scalar(&self, x: &TFr) -> Self {
+        Self {
+            evals: self.evals.iter().map(|eval| eval.mul(x)).collect(),
+        }
+    }
+
+    /// Value of the polynomial at `z`, which may be anywhere, by the barycentric formula.
+    /// `roots` are the domain points, in the same order as the evaluations.
+    pub fn evaluate(&self, roots: &[TFr], z: &TFr) -> Result<TFr, String> {
+        match self.inverse_differences(roots, z)? {
+            Differences::InDomain(m) => Ok(self.evals[m].clone()),
+            Differences::Outside(inverses) => Ok(self.barycentric(roots, z, &inverses)),
+        }
+    }
+
+    /// Quotient `(p(X) - p(z)) / (X - z)` in evaluation form over the same domain, along with
+    /// `p(z)`. The inverses of `ω_i - z` are shared between the two, so this costs a single
+    /// batch inversion.
+    pub fn quotient_by_linear(&self, roots: &[TFr], z: &TFr) -> Result<(Self, TFr), String> {
+        match self.inverse_differences(roots, z)? {
+            Differences::Outside(inverses) => {
+                let y = self.barycentric(roots, z, &inverses);
+                let evals = self
+                    .evals
+                    .iter()
+                    .zip(&inverses)
+                    .map(|(eval, inverse)| eval.sub(&y).mul(inverse))
+                    .collect();
+
+                Ok((Self { evals }, y))
+            }
+            Differences::InDomain(m) => {
+                // The quotient at ω_m = z isn't a difference quotient, but follows from the
+                // others as q_m = sum((p_i - y) * ω_i / (z * (z - ω_i))) = -sum(q_i * ω_i) / z
+                let y = self.evals[m].clone();
+                let mut inverses = roots
+                    .iter()
+                    .enumerate()
+                    .map(|(i, root)| if i == m { TFr::one() } else { root.sub(z) })
+                    .collect::<Vec<_>>();
+                batch_inverse(&mut inverses)?;
+
+                let mut q_m = TFr::zero();
+                let mut evals = self
+                    .evals
+                    .iter()
+                    .zip(roots)
+                    .zip(&inverses)
+                    .enumerate()
+                    .map(|(i, ((eval, root), inverse))| {
+                        if i == m {
+                            return TFr::zero();
+                        }
+                        let q_i = eval.sub(&y).mul(inverse);
+                        q_m = q_m.sub(&q_i.mul(root));
+                        q_i
+                    })
+                    .collect::<Vec<_>>();
+                evals[m] = q_m.mul(&z.inverse());
+
+                Ok((Self { evals }, y))
+            }
+        }
+    }
+
+    fn zip_with(&self, other: &Self, op: impl Fn(&TFr, &TFr) -> TFr) -> Result<Self, String> {
+        if self.len() != other.len() {
+            return Err(String::from("Evaluations must be over the same domain"));
+        }
+
+        Ok(Self {
+            evals: self
+                .evals
+                .iter()
+                .zip(&other.evals)
+                .map(|(a, b)| op(a, b))
+                .collect(),
+        })
+    }
+
+    fn inverse_differences(&self, roots: &[TFr], z: &TFr) -> Result<Differences<TFr>, String> {
+        if roots.len() != self.len() {
+            return Err(String::from("Roots must have one point per evaluation"));
+        }
+        if let Some(m) = roots.iter().position(|root| root.equals(z)) {
+            return Ok(Differences::InDomain(m));
+        }
+
+        let mut inverses = roots.iter().map(|root| root.sub(z)).collect::<Vec<_>>();
+        batch_inverse(&mut inverses)?;
+
+        Ok(Differences::Outside(inverses))
+    }
+
+    /// `p(z) = (z^n - 1) / n * sum(p_i * ω_i / (z - ω_i))`, with `inverses` holding
+    /// `1 / (ω_i - z)`.
+    fn barycentric(&self, roots: &[TFr], z: &TFr, inverses: &[TFr]) -> TFr {
+        let n = self.len();
+        let sum = self
+            .evals
+            .iter()
+            .zip(roots)
+            .zip(inverses)
+            .fold(TFr::zero(), |acc, ((eval, root), inverse)| {
+                acc.add(&eval.mul(root).mul(inverse))
+            });
+
+        // The sign of the sum is flipped by the inverses, so flip it back with 1 - z^n
+        let factor = TFr::one()
+            .sub(&z.pow(n))
+            .mul(&TFr::from_u64(n as u64).inverse());
+
+        sum.mul(&factor)
+    }
+}
+
+enum Differences<TFr> {
+    /// `z` is the domain point at this index
+    InDomain(usize),
+    /// `1 / (ω_i - z)` for every domain point
+    Outside(Vec<TFr>),
+}
+
+/// Roots of unity of a domain of size `len`, in natural order.
+pub fn domain<TFr: Fr, TFFTSettings: FFTSettings<TFr>>(
+    fs: &TFFTSettings,
+    len: usize,
+) -> Result<Vec<TFr>, String> {
+    if !len.is_power_of_two() || len > fs.get_max_width() {
+        return Err(String::from(
+            "Domain size must be a power of 2 no larger than FFTSettings max width",
+        ));
+    }
+
+    let stride = fs.get_max_width() / len;
+    Ok(fs
+        .get_expanded_roots_of_unity()
+        .iter()
+        .step_by(stride)
+        .take(len)
+        .cloned()
+        .collect())
+}
+
+/// Inverts every value with a single field inversion (Montgomery's trick).
+pub(crate) fn batch_inverse<TFr: Fr>(values: &mut [TFr]) -> Result<(), String> {
+    let mut prefix = vec![TFr::one(); values.len()];
+    let mut accumulator = TFr::one();
+    for (prefix, value) in prefix.iter_mut().zip(values.iter()) {
+        *prefix = accumulator.clone();
+        accumulator = accumulator.mul(value);
+    }
+
+    if accumulator.is_zero() {
+        return Err(String::from("Zero input"));
+    }
+
+    accumulator = accumulator.inverse();
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        let inverse = accumulator.mul(&prefix);
+        accumulator = accumulator.mul(value);
+        *value = inverse;
+    }
+
+    Ok(())
+}
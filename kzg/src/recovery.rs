@@ -0,0 +1,189 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::common_utils::next_pow_of_2;
+use crate::{FFTFr, FFTSettings, Fr, Poly, ZeroPoly};
+
+// Number of indices each partial of the vanishing polynomial covers
+const INDICES_PER_PARTIAL: usize = 64;
+// Number of partials multiplied together at a time when reducing them
+const REDUCTION_FACTOR: usize = 4;
+// The division in recovery happens over the coset `w^i / SCALE_FACTOR`, same as in the backends'
+// `PolyRecover`, which none of the roots of unity lie on
+const SCALE_FACTOR: u64 = 5;
+
+/// Recovery of erasure-coded data from samples arriving one at a time.
+///
+/// The domain is split into runs of consecutive indices, each with a partial vanishing
+/// polynomial over the indices still missing from it. All partials start out as built by
+/// [`ZeroPoly::do_zero_poly_mul_partial`], and every sample received divides the `x - w^i` of its
+/// index out of its partial, so [`Self::recover`] only has to multiply the partials together
+/// rather than rebuild the vanishing polynomial of the missing samples from scratch.
+pub struct Recoverer<'a, TFr, TPoly, TFFTSettings>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + ZeroPoly<TFr, TPoly>,
+{
+    fs: &'a TFFTSettings,
+    samples: Vec<Option<TFr>>,
+    received: usize,
+    partials: Vec<TPoly>,
+    _fr: PhantomData<TFr>,
+}
+
+impl<'a, TFr, TPoly, TFFTSettings> Recoverer<'a, TFr, TPoly, TFFTSettings>
+where
+    TFr: Fr,
+    TPoly: Poly<TFr>,
+    TFFTSettings: FFTSettings<TFr> + FFTFr<TFr> + ZeroPoly<TFr, TPoly>,
+{
+    /// Recoverer for `len` samples, the values of a polynomial of length `len / 2` over the
+    /// `len`-th roots of unity, in natural order.
+    pub fn new(fs: &'a TFFTSettings, len: usize) -> Result<Self, String> {
+        if !len.is_power_of_two() || len > fs.get_max_width() {
+            return Err(String::from(
+                "Sample count must be a power of 2 no larger than FFTSettings max width",
+            ));
+        }
+
+        let stride = fs.get_max_width() / len;
+        let indices = (0..len).collect::<Vec<_>>();
+        let partials = indices
+            .chunks(INDICES_PER_PARTIAL)
+            .map(|indices| fs.do_zero_poly_mul_partial(indices, stride))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            fs,
+            samples: vec![None; len],
+            received: 0,
+            partials,
+            _fr: PhantomData,
+        })
+    }
+
+    /// Records the sample at `index`. Receiving the same sample again is a no-op, but receiving a
+    /// different value for an index already known is an error.
+    pub fn add_sample(&mut self, index: usize, sample: TFr) -> Result<(), String> {
+        let stride = self.fs.get_max_width() / self.samples.len();
+        match self.samples.get(index) {
+            None => return Err(String::from("Sample index out of range")),
+            Some(Some(known)) if known.equals(&sample) => return Ok(()),
+            Some(Some(_)) => {
+                return Err(String::from(
+                    "Sample conflicts with the one received before",
+                ))
+            }
+            Some(None) => {}
+        }
+
+        let root = self.fs.get_expanded_roots_of_unity_at(index * stride);
+        let partial = &mut self.partials[index / INDICES_PER_PARTIAL];
+        *partial = partial.divide_by_linear(&root);
+
+        self.samples[index] = Some(sample);
+        self.received += 1;
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Number of distinct samples received so far.
+    pub fn received(&self) -> usize {
+        self.received
+    }
+
+    pub fn samples(&self) -> &[Option<TFr>] {
+        &self.samples
+    }
+
+    /// Whether enough samples have arrived for [`Self::recover`], i.e. at least half of them.
+    pub fn can_recover(&self) -> bool {
+        2 * self.received >= self.samples.len()
+    }
+
+    /// Vanishing polynomial of the indices still missing.
+    pub fn zero_poly(&self) -> Result<TPoly, String> {
+        let mut partials = self
+            .partials
+            .iter()
+            .filter(|partial| partial.len() > 1)
+            .cloned()
+            .collect::<Vec<_>>();
+        if partials.is_empty() {
+            return Ok(TPoly::from_coeffs(&[TFr::one()]));
+        }
+
+        while partials.len() > 1 {
+            partials = partials
+                .chunks(REDUCTION_FACTOR)
+                .map(|partials| {
+                    if partials.len() == 1 {
+                        return Ok(partials[0].clone());
+                    }
+
+                    let degree = partials.iter().map(|p| p.len() - 1).sum::<usize>();
+                    self.fs.reduce_partials(next_pow_of_2(degree + 1), partials)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(partials.swap_remove(0))
+    }
+
+    /// All samples, the missing ones recovered.
+    ///
+    /// With `Z` the vanishing polynomial of the missing indices and `D` the polynomial of the
+    /// data, `D * Z` is known at every index, as it's zero wherever a sample is missing. `D` is
+    /// then `(D * Z) / Z`, divided over a coset on which `Z` has no zeroes.
+    pub fn recover(&self) -> Result<Vec<TFr>, String> {
+        if !self.can_recover() {
+            return Err(String::from(
+                "Impossible to recover, too many shards are missing",
+            ));
+        }
+        if self.received == self.samples.len() {
+            return Ok(self.samples.iter().flatten().cloned().collect());
+        }
+
+        let len = self.samples.len();
+        let mut zero_coeffs = self.zero_poly()?.get_coeffs().to_vec();
+        zero_coeffs.resize(len, TFr::zero());
+        let zero_eval = self.fs.fft_fr(&zero_coeffs, false)?;
+
+        let poly_with_zero_eval = self
+            .samples
+            .iter()
+            .zip(&zero_eval)
+            .map(|(sample, zero_eval)| match sample {
+                Some(sample) => sample.mul(zero_eval),
+                None => TFr::zero(),
+            })
+            .collect::<Vec<_>>();
+        let poly_with_zero = self.fs.fft_fr(&poly_with_zero_eval, true)?;
+
+        let shift = TFr::from_u64(SCALE_FACTOR).inverse();
+        let coset_poly_with_zero = self.fs.coset_fft_fr(&poly_with_zero, &shift, false)?;
+        let coset_zero = self.fs.coset_fft_fr(&zero_coeffs, &shift, false)?;
+        let coset_data = coset_poly_with_zero
+            .iter()
+            .zip(&coset_zero)
+            .map(|(numerator, denominator)| numerator.div(denominator))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let data_coeffs = self.fs.coset_fft_fr(&coset_data, &shift, true)?;
+        self.fs.fft_fr(&data_coeffs, false)
+    }
+}
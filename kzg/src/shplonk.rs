@@ -0,0 +1,218 @@
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::batch_opening::challenge_powers;
+use crate::transcript::{Sha256Transcript, Transcript};
+use crate::{
+    FFTSettings, Fr, G1Affine, G1Fp, G1GetFp, G1LinComb, G1Mul, KZGSettings, PairingVerify, Poly,
+    G1, G2,
+};
+
+pub const SHPLONK_DOMAIN: [u8; 16] = [
+    83, 72, 80, 76, 79, 78, 75, 95, 95, 95, 95, 95, 95, 86, 49, 95,
+]; // "SHPLONK______V1_"
+
+/// Opening of several polynomials, each at its own set of points (BDFG20, section 4).
+///
+/// `w` commits to `h = sum(γ^i * (f_i - r_i) / Z_i)`, where `r_i` interpolates `f_i` over its
+/// points and `Z_i` vanishes on them. `w_prime` is a KZG opening at a second challenge `z` of
+/// the polynomial `L`, which the verifier can commit to from the commitments and `w` alone, and
+/// which is zero at `z` exactly when every `f_i - r_i` is divisible by its `Z_i`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShplonkProof<TG1> {
+    pub w: TG1,
+    pub w_prime: TG1,
+}
+
+/// Proof that `polys[i]` takes its values at every point of `points[i]`. The polynomials are
+/// committed to as `commitments`, which go into the challenges.
+pub fn compute_shplonk_proof<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    polys: &[TPoly],
+    commitments: &[TG1],
+    points: &[Vec<TFr>],
+    s: &TKZGSettings,
+) -> Result<ShplonkProof<TG1>, String> {
+    if polys.len() != commitments.len() {
+        return Err(String::from(
+            "Openings must have one commitment per polynomial",
+        ));
+    }
+
+    let values = polys
+        .iter()
+        .zip(points)
+        .map(|(poly, points)| points.iter().map(|x| poly.eval(x)).collect())
+        .collect::<Vec<Vec<_>>>();
+    let mut transcript = compute_transcript(commitments, points, &values)?;
+    let gamma = transcript.challenge_scalar(b"gamma");
+    let gamma_powers = challenge_powers(&gamma, polys.len());
+
+    let mut interpolations = Vec::with_capacity(polys.len());
+    let mut h = TPoly::new(0);
+    for (((poly, points), values), gamma_power) in
+        polys.iter().zip(points).zip(&values).zip(&gamma_powers)
+    {
+        let interpolation = TPoly::interpolate(points, values)?;
+        let mut numerator = poly.sub(&interpolation);
+        let vanishing = TPoly::from_coeffs(&vanishing_poly(points));
+
+        if numerator.len() >= vanishing.len() {
+            h = h.add(&numerator.div(&vanishing)?.mul_scalar(gamma_power));
+        }
+        interpolations.push(interpolation);
+    }
+
+    let w = s.commit_to_poly(&h)?;
+    transcript.append_commitment(b"w", &w);
+    let z = transcript.challenge_scalar(b"z");
+
+    // L = sum(γ^i * Z_T\S_i(z) * (f_i - r_i(z))) - Z_T(z) * h
+    let (zero_eval, scalars) = opening_scalars(points, &gamma_powers, &z)?;
+    let mut constant = TFr::zero();
+    let mut l = h.mul_scalar(&zero_eval.negate());
+    for ((poly, interpolation), scalar) in polys.iter().zip(&interpolations).zip(&scalars) {
+        l = l.add(&poly.mul_scalar(scalar));
+        constant = constant.sub(&interpolation.eval(&z).mul(scalar));
+    }
+    l = l.add(&TPoly::from_coeffs(&[constant]));
+
+    let w_prime = s.commit_to_poly(&l.divide_by_linear(&z))?;
+
+    Ok(ShplonkProof { w, w_prime })
+}
+
+/// Checks that the polynomials of `commitments` take `values[i]` at `points[i]`.
+pub fn verify_shplonk_proof<
+    TFr: Fr,
+    TG1: G1 + G1GetFp<TG1Fp> + G1Mul<TFr> + G1LinComb<TFr, TG1Fp, TG1Affine> + PairingVerify<TG1, TG2>,
+    TG2: G2,
+    TFFTSettings: FFTSettings<TFr>,
+    TPoly: Poly<TFr>,
+    TKZGSettings: KZGSettings<TFr, TG1, TG2, TFFTSettings, TPoly, TG1Fp, TG1Affine>,
+    TG1Fp: G1Fp,
+    TG1Affine: G1Affine<TG1, TG1Fp>,
+>(
+    commitments: &[TG1],
+    points: &[Vec<TFr>],
+    values: &[Vec<TFr>],
+    proof: &ShplonkProof<TG1>,
+    s: &TKZGSettings,
+) -> Result<bool, String> {
+    let mut transcript = compute_transcript(commitments, points, values)?;
+    let gamma = transcript.challenge_scalar(b"gamma");
+    let gamma_powers = challenge_powers(&gamma, commitments.len());
+    transcript.append_commitment(b"w", &proof.w);
+    let z = transcript.challenge_scalar(b"z");
+    let (zero_eval, mut scalars) = opening_scalars(points, &gamma_powers, &z)?;
+
+    // [L] + z * W' as a single MSM, the commitments followed by the generator, W and W'
+    let mut constant = TFr::zero();
+    for ((points, values), scalar) in points.iter().zip(values).zip(&scalars) {
+        let interpolation = TPoly::interpolate(points, values)?;
+        constant = constant.sub(&interpolation.eval(&z).mul(scalar));
+    }
+    scalars.extend([constant, zero_eval.negate(), z]);
+
+    let mut bases = commitments.to_vec();
+    bases.extend([TG1::generator(), proof.w.clone(), proof.w_prime.clone()]);
+    let lhs = TG1::g1_lincomb(&bases, &scalars, bases.len(), None);
+
+    Ok(TG1::verify(
+        &lhs,
+        &TG2::generator(),
+        &proof.w_prime,
+        &s.get_g2_secret()[1],
+    ))
+}
+
+/// `Z_T(z)`, for `T` the union of all points, and `γ^i * Z_T\S_i(z)` for every set `S_i`.
+fn opening_scalars<TFr: Fr>(
+    points: &[Vec<TFr>],
+    gamma_powers: &[TFr],
+    z: &TFr,
+) -> Result<(TFr, Vec<TFr>), String> {
+    let mut all_points: Vec<&TFr> = Vec::new();
+    for x in points.iter().flatten() {
+        if !all_points.iter().any(|known| known.equals(x)) {
+            all_points.push(x);
+        }
+    }
+    let zero_eval = all_points
+        .iter()
+        .fold(TFr::one(), |acc, x| acc.mul(&z.sub(x)));
+
+    let scalars = points
+        .iter()
+        .zip(gamma_powers)
+        .map(|(points, gamma_power)| {
+            let set_eval = points.iter().fold(TFr::one(), |acc, x| acc.mul(&z.sub(x)));
+            Ok(zero_eval.div(&set_eval)?.mul(gamma_power))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok((zero_eval, scalars))
+}
+
+/// Coefficients of `prod(x - points[i])`.
+fn vanishing_poly<TFr: Fr>(points: &[TFr]) -> Vec<TFr> {
+    let mut coeffs = Vec::with_capacity(points.len() + 1);
+    coeffs.push(TFr::one());
+    for x in points {
+        coeffs.push(TFr::zero());
+        for i in (1..coeffs.len()).rev() {
+            coeffs[i] = coeffs[i - 1].sub(&coeffs[i].mul(x));
+        }
+        coeffs[0] = coeffs[0].mul(&x.negate());
+    }
+    coeffs
+}
+
+/// Transcript binding the commitments, points and values, for the first challenge `γ`. The
+/// second, `z`, the point `L` is opened at, then binds `W` too. Also checks the shapes.
+fn compute_transcript<TFr: Fr, TG1: G1>(
+    commitments: &[TG1],
+    points: &[Vec<TFr>],
+    values: &[Vec<TFr>],
+) -> Result<Sha256Transcript, String> {
+    if commitments.is_empty() || commitments.len() != points.len() {
+        return Err(String::from(
+            "Openings must have one set of points per commitment, and at least one",
+        ));
+    }
+    if values.len() != points.len() {
+        return Err(String::from(
+            "Openings must have one set of values per commitment",
+        ));
+    }
+
+    let mut transcript = Sha256Transcript::new(&SHPLONK_DOMAIN);
+    transcript.append_u64(b"count", commitments.len() as u64);
+
+    for ((commitment, points), values) in commitments.iter().zip(points).zip(values) {
+        if points.is_empty() || points.len() != values.len() {
+            return Err(String::from(
+                "Every opening must have one value per point, and at least one",
+            ));
+        }
+
+        transcript.append_commitment(b"commitment", commitment);
+        transcript.append_u64(b"points", points.len() as u64);
+        for (x, y) in points.iter().zip(values) {
+            transcript.append_scalar(b"x", x);
+            transcript.append_scalar(b"y", y);
+        }
+    }
+
+    Ok(transcript)
+}
@@ -0,0 +1,142 @@
+extern crate alloc;
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use sha2::digest::consts::U32;
+use sha2::{Digest, Sha256};
+
+use crate::eip_4844::hash_to_bls_field;
+use crate::{Fr, G1};
+
+/// Fiat–Shamir transcript, which the prover and verifier of a protocol feed the same messages
+/// to, in the same order, to derive the same challenges.
+///
+/// Every message comes with a label naming it. Implementations bind the labels and the lengths
+/// of the messages into the challenges, so that different sequences of messages never hash the
+/// same. The one exception is [`HashTranscript::legacy`], which reproduces the challenges of
+/// formats fixed before, byte for byte.
+pub trait Transcript {
+    /// Transcript of the protocol with domain separator `domain`.
+    fn new(domain: &[u8]) -> Self;
+
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]);
+
+    /// Appends `value` as 8 big-endian bytes.
+    fn append_u64(&mut self, label: &'static [u8], value: u64) {
+        self.append_bytes(label, &value.to_be_bytes());
+    }
+
+    /// Appends the compressed encoding of `commitment`.
+    fn append_commitment<TG1: G1>(&mut self, label: &'static [u8], commitment: &TG1) {
+        self.append_bytes(label, &commitment.to_bytes());
+    }
+
+    /// Appends the canonical encoding of `scalar`.
+    fn append_scalar<TFr: Fr>(&mut self, label: &'static [u8], scalar: &TFr) {
+        self.append_bytes(label, &scalar.to_bytes());
+    }
+
+    /// Challenge binding everything appended so far. Later challenges bind it in turn.
+    fn challenge_scalar<TFr: Fr>(&mut self, label: &'static [u8]) -> TFr;
+}
+
+/// Transcript hashing the domain separator and the messages with `D` (SHA-256 unless given).
+/// Each of them goes in as its label, then the message, both preceded by their length as a
+/// big-endian u64, and the domain separator by its length alone. A challenge appends its label
+/// the same way, with an empty message. The challenge is the digest mapped with
+/// [`hash_to_bls_field`], after which the transcript starts over from the domain separator
+/// followed by the challenge, under its label.
+///
+/// In [legacy](Self::legacy) mode the domain separator and the messages are concatenated, and
+/// labels and lengths left out.
+#[derive(Debug, Clone)]
+pub struct HashTranscript<D = Sha256> {
+    domain: Vec<u8>,
+    bytes: Vec<u8>,
+    legacy: bool,
+    _digest: PhantomData<D>,
+}
+
+pub type Sha256Transcript = HashTranscript<Sha256>;
+
+impl<D> HashTranscript<D> {
+    /// Same as [`Transcript::new`], with room for `capacity` bytes of input, domain separator
+    /// included.
+    pub fn with_capacity(domain: &[u8], capacity: usize) -> Self {
+        let mut transcript = Self::empty(domain, capacity, false);
+        append_framed(&mut transcript.bytes, domain);
+        transcript
+    }
+
+    /// Transcript concatenating the domain separator and the messages, without labels or
+    /// lengths, with room for `capacity` bytes of input. Only for formats fixed that way before,
+    /// as the EIP-4844 challenges. As nothing delimits the messages, such a format must fix
+    /// their number and sizes up front, or append the counts it needs to tell them apart.
+    pub fn legacy(domain: &[u8], capacity: usize) -> Self {
+        let mut transcript = Self::empty(domain, capacity, true);
+        transcript.bytes.extend_from_slice(domain);
+        transcript
+    }
+
+    /// Input the next challenge is the hash of.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn empty(domain: &[u8], capacity: usize, legacy: bool) -> Self {
+        Self {
+            domain: domain.to_vec(),
+            bytes: Vec::with_capacity(capacity),
+            legacy,
+            _digest: PhantomData,
+        }
+    }
+
+    fn append_message(&mut self, label: &[u8], bytes: &[u8]) {
+        if self.legacy {
+            self.bytes.extend_from_slice(bytes);
+        } else {
+            append_framed(&mut self.bytes, label);
+            append_framed(&mut self.bytes, bytes);
+        }
+    }
+
+    fn restart(&mut self, label: &[u8], challenge: &[u8]) {
+        self.bytes.clear();
+        if self.legacy {
+            self.bytes.extend_from_slice(&self.domain);
+        } else {
+            append_framed(&mut self.bytes, &self.domain);
+        }
+        self.append_message(label, challenge);
+    }
+}
+
+/// `bytes`, preceded by their length as a big-endian u64.
+fn append_framed(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+impl<D: Digest<OutputSize = U32>> Transcript for HashTranscript<D> {
+    fn new(domain: &[u8]) -> Self {
+        Self::with_capacity(domain, 8 + domain.len())
+    }
+
+    fn append_bytes(&mut self, label: &'static [u8], bytes: &[u8]) {
+        self.append_message(label, bytes);
+    }
+
+    fn challenge_scalar<TFr: Fr>(&mut self, label: &'static [u8]) -> TFr {
+        if !self.legacy {
+            self.append_message(label, &[]);
+        }
+        let digest: [u8; 32] = D::digest(&self.bytes).into();
+        let challenge = hash_to_bls_field::<TFr>(&digest);
+
+        self.restart(label, &challenge.to_bytes());
+
+        challenge
+    }
+}
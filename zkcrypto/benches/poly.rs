@@ -1,5 +1,8 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use kzg_bench::benches::poly::bench_new_poly_div;
+use kzg_bench::benches::poly::{
+    bench_multipoint_evaluate, bench_multipoint_interpolate, bench_new_poly_div,
+};
+use rust_kzg_zkcrypto::kzg_proofs::FFTSettings;
 use rust_kzg_zkcrypto::kzg_types::ZFr;
 use rust_kzg_zkcrypto::poly::PolyData;
 
@@ -7,10 +10,18 @@ fn bench_new_poly_div_(c: &mut Criterion) {
     bench_new_poly_div::<ZFr, PolyData>(c);
 }
 
+fn bench_multipoint_evaluate_(c: &mut Criterion) {
+    bench_multipoint_evaluate::<ZFr, PolyData, FFTSettings>(c);
+}
+
+fn bench_multipoint_interpolate_(c: &mut Criterion) {
+    bench_multipoint_interpolate::<ZFr, PolyData, FFTSettings>(c);
+}
+
 criterion_group! {
     name = benches;
     config = Criterion::default().sample_size(10);
-    targets = bench_new_poly_div_
+    targets = bench_new_poly_div_, bench_multipoint_evaluate_, bench_multipoint_interpolate_
 }
 
 criterion_main!(benches);
@@ -26,7 +26,10 @@ use std::io::Read;
 use rayon::prelude::*;
 
 #[cfg(feature = "std")]
-use kzg::eip_4844::load_trusted_setup_string;
+use kzg::eip_4844::{
+    load_trusted_setup_string, load_trusted_setup_string_with_monomial,
+    load_trusted_setup_with_monomial_rust,
+};
 
 #[cfg(feature = "std")]
 pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<KZGSettings, String> {
@@ -35,8 +38,13 @@ pub fn load_trusted_setup_filename_rust(filepath: &str) -> Result<KZGSettings, S
     file.read_to_string(&mut contents)
         .map_err(|_| "Unable to read file".to_string())?;
 
-    let (g1_bytes, g2_bytes) = load_trusted_setup_string(&contents)?;
-    load_trusted_setup_rust(g1_bytes.as_slice(), g2_bytes.as_slice())
+    let (g1_bytes, g2_bytes, g1_monomial_bytes) =
+        load_trusted_setup_string_with_monomial(&contents)?;
+    load_trusted_setup_with_monomial_rust(
+        g1_bytes.as_slice(),
+        g2_bytes.as_slice(),
+        g1_monomial_bytes.as_slice(),
+    )
 }
 
 fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<FFTSettings, String> {
@@ -63,6 +71,9 @@ fn fft_settings_to_rust(c_settings: *const CKZGSettings) -> Result<FFTSettings,
         expanded_roots_of_unity,
         reverse_roots_of_unity,
         roots_of_unity,
+        coset_cache: Default::default(),
+        #[cfg(feature = "parallel")]
+        execution_context: Default::default(),
     })
 }
 
@@ -82,8 +93,13 @@ fn kzg_settings_to_rust(c_settings: &CKZGSettings) -> Result<KZGSettings, String
     Ok(KZGSettings {
         fs: fft_settings_to_rust(c_settings)?,
         secret_g1,
+        // `CKZGSettings` carries only the Lagrange-form points, the monomial ones are derived
+        // from them if needed
+        secret_g1_monomial: Some(Default::default()),
         secret_g2,
         precomputation: None,
+        #[cfg(feature = "parallel")]
+        execution_context: Default::default(),
     })
 }
 
@@ -48,16 +48,29 @@ impl FFTG1<ZG1> for FFTSettings {
             &self.expanded_roots_of_unity
         };
 
-        fft_g1_fast(&mut ret, data, 1, roots, stride, 1);
+        let transform = |ret: &mut [ZG1]| {
+            fft_g1_fast(ret, data, 1, roots, stride, 1);
+
+            if inverse {
+                let inv_fr_len = ZFr::from_u64(data.len() as u64).inverse();
+                ret[..data.len()]
+                    .iter_mut()
+                    .for_each(|f| f.proj.mul_assign(&inv_fr_len.fr));
+            }
+        };
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| transform(&mut ret));
+
+        #[cfg(not(feature = "parallel"))]
+        transform(&mut ret);
 
-        if inverse {
-            let inv_fr_len = ZFr::from_u64(data.len() as u64).inverse();
-            ret[..data.len()]
-                .iter_mut()
-                .for_each(|f| f.proj.mul_assign(&inv_fr_len.fr));
-        }
         Ok(ret)
     }
+
+    fn fft_g1_inplace(&self, data: &mut [ZG1], inverse: bool) -> Result<(), String> {
+        kzg::fft::fft_g1_inplace(self, data, inverse)
+    }
 }
 
 pub fn fft_g1_slow(
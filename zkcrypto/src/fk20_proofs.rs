@@ -2,8 +2,11 @@ use crate::consts::G1_IDENTITY;
 use crate::kzg_proofs::{FFTSettings, KZGSettings};
 use crate::kzg_types::{ZFp, ZFr as BlstFr, ZG1Affine, ZG1, ZG2};
 use crate::poly::PolyData;
+use bls12_381::G1Projective;
 use kzg::common_utils::reverse_bit_order;
-use kzg::{FFTFr, FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, Poly, FFTG1, G1};
+use kzg::fft::{fft_fr_dif, fft_g1_dif, fft_g1_dit};
+use kzg::msm::fixed_base::FixedBaseMsm;
+use kzg::{FK20MultiSettings, FK20SingleSettings, Fr, G1Mul, KZGSettings as _, Poly, FFTG1};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -12,7 +15,7 @@ use rayon::prelude::*;
 #[derive(Debug, Clone, Default)]
 pub struct KzgFK20SingleSettings {
     pub ks: KZGSettings,
-    pub x_ext_fft: Vec<ZG1>,
+    pub x_ext_fft_tables: Vec<FixedBaseMsm<BlstFr, ZG1, ZFp, ZG1Affine>>,
     pub x_ext_fft_len: usize,
 }
 #[repr(C)]
@@ -20,14 +23,21 @@ pub struct KzgFK20SingleSettings {
 pub struct KzgFK20MultiSettings {
     pub ks: KZGSettings,
     pub chunk_len: usize,
-    pub x_ext_fft_files: Vec<Vec<ZG1>>,
+    pub x_ext_fft_tables: Vec<FixedBaseMsm<BlstFr, ZG1, ZFp, ZG1Affine>>,
     pub length: usize,
 }
 
-impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
-    for KzgFK20SingleSettings
-{
-    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+impl KzgFK20SingleSettings {
+    /// Same as [`FK20SingleSettings::new`], with `window` and `max_table_size` passed on to the
+    /// fixed-base tables of the Toeplitz products. There are `n2` of them, with a single point
+    /// each, and `max_table_size` caps the precomputed points of all of them together. See
+    /// [`FixedBaseMsm::with_params`].
+    pub fn with_params(
+        ks: &KZGSettings,
+        n2: usize,
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Self, String> {
         let n = n2 / 2;
 
         if n2 > ks.fs.max_width {
@@ -44,21 +54,33 @@ impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZF
 
         let mut x = Vec::new();
         for i in 0..(n - 1) {
-            x.push(ks.secret_g1[n - 2 - i])
+            x.push(ks.get_g1_monomial()[n - 2 - i])
         }
         x.push(G1_IDENTITY);
 
         let new_ks = KZGSettings {
             fs: ks.fs.clone(),
+            #[cfg(feature = "parallel")]
+            execution_context: ks.execution_context.clone(),
             ..KZGSettings::default()
         };
 
+        let x_ext_fft = toeplitz_part_1_brp(&x, &ks.fs)?;
+
         Ok(KzgFK20SingleSettings {
             ks: new_ks,
-            x_ext_fft: toeplitz_part_1(&x, &ks.fs).unwrap(),
+            x_ext_fft_tables: toeplitz_part_2_tables(&[x_ext_fft], window, max_table_size)?,
             x_ext_fft_len: n2,
         })
     }
+}
+
+impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
+    for KzgFK20SingleSettings
+{
+    fn new(ks: &KZGSettings, n2: usize) -> Result<Self, String> {
+        Self::with_params(ks, n2, None, None)
+    }
 
     fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
         let n = p.len();
@@ -83,10 +105,18 @@ impl FK20SingleSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZF
     }
 }
 
-impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
-    for KzgFK20MultiSettings
-{
-    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+impl KzgFK20MultiSettings {
+    /// Same as [`FK20MultiSettings::new`], with `window` and `max_table_size` passed on to the
+    /// fixed-base tables of the Toeplitz products. There are `n2 / chunk_len` of them, with
+    /// `chunk_len` points each, and `max_table_size` caps the precomputed points of all of them
+    /// together. See [`FixedBaseMsm::with_params`].
+    pub fn with_params(
+        ks: &KZGSettings,
+        n2: usize,
+        chunk_len: usize,
+        window: Option<usize>,
+        max_table_size: Option<usize>,
+    ) -> Result<Self, String> {
         if n2 > ks.fs.max_width {
             return Err(String::from(
                 "n2 must be equal or less than kzg settings max width",
@@ -122,7 +152,7 @@ impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp
             };
             let mut j = start;
             for i in x.iter_mut().take(k - 1) {
-                i.proj = ks.secret_g1[j].proj;
+                i.proj = ks.get_g1_monomial()[j].proj;
                 if j >= chunk_len {
                     j -= chunk_len;
                 } else {
@@ -130,21 +160,31 @@ impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp
                 }
             }
             x[k - 1] = G1_IDENTITY;
-            x_ext_fft_files.push(toeplitz_part_1(&x, &ks.fs).unwrap());
+            x_ext_fft_files.push(toeplitz_part_1_brp(&x, &ks.fs).unwrap());
         }
 
         let new_ks = KZGSettings {
             fs: ks.fs.clone(),
+            #[cfg(feature = "parallel")]
+            execution_context: ks.execution_context.clone(),
             ..KZGSettings::default()
         };
 
         Ok(KzgFK20MultiSettings {
             ks: new_ks,
-            x_ext_fft_files,
+            x_ext_fft_tables: toeplitz_part_2_tables(&x_ext_fft_files, window, max_table_size)?,
             chunk_len,
             length: n, //unsure if this is right
         })
     }
+}
+
+impl FK20MultiSettings<BlstFr, ZG1, ZG2, FFTSettings, PolyData, KZGSettings, ZFp, ZG1Affine>
+    for KzgFK20MultiSettings
+{
+    fn new(ks: &KZGSettings, n2: usize, chunk_len: usize) -> Result<Self, String> {
+        Self::with_params(ks, n2, chunk_len, None, None)
+    }
 
     fn data_availability(&self, p: &PolyData) -> Result<Vec<ZG1>, String> {
         let n = p.len();
@@ -182,12 +222,23 @@ fn fk20_single_da_opt(p: &PolyData, fk: &KzgFK20SingleSettings) -> Result<Vec<ZG
         return Err(String::from("n2 must be power of 2"));
     }
 
-    let outlen = 2 * p.len();
-    let toeplitz_coeffs = toeplitz_coeffs_step(p, outlen).unwrap();
-    let h_ext_fft = toeplitz_part_2(&toeplitz_coeffs, &fk.x_ext_fft, &fk.ks.fs).unwrap();
-    let h = toeplitz_part_3(&h_ext_fft, &fk.ks.fs).unwrap();
+    let proofs = || {
+        let outlen = 2 * p.len();
+        let toeplitz_coeffs = toeplitz_coeffs_step(p, outlen).unwrap();
+        let h_ext_fft =
+            toeplitz_part_2_multi_brp(&[toeplitz_coeffs], &fk.x_ext_fft_tables, &fk.ks.fs)?;
+        let mut h = toeplitz_part_3_brp(&h_ext_fft, &fk.ks.fs).unwrap();
 
-    fk.ks.fs.fft_g1(&h, false)
+        fk.ks.fs.fft_g1_inplace(&mut h, false)?;
+
+        Ok(h)
+    };
+
+    #[cfg(feature = "parallel")]
+    return fk.ks.execution_context.install(proofs);
+
+    #[cfg(not(feature = "parallel"))]
+    proofs()
 }
 
 fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ZG1>, String> {
@@ -203,35 +254,35 @@ fn fk20_multi_da_opt(p: &PolyData, fk: &KzgFK20MultiSettings) -> Result<Vec<ZG1>
         return Err(String::from("n2 must be power of 2"));
     }
 
-    let n = n2 / 2;
-    let k = n / fk.chunk_len;
-    let k2 = k * 2;
+    let proofs = || {
+        let n = n2 / 2;
+        let k = n / fk.chunk_len;
+        let k2 = k * 2;
 
-    let mut h_ext_fft = Vec::new();
-    for _i in 0..k2 {
-        h_ext_fft.push(G1_IDENTITY);
-    }
+        let toeplitz_coeffs = (0..fk.chunk_len)
+            .map(|i| toeplitz_coeffs_stride(p, i, fk.chunk_len, k2))
+            .collect::<Result<Vec<_>, _>>()?;
+        let h_ext_fft =
+            toeplitz_part_2_multi_brp(&toeplitz_coeffs, &fk.x_ext_fft_tables, &fk.ks.fs)?;
 
-    let mut toeplitz_coeffs = PolyData::new(n2 / fk.chunk_len);
-    for i in 0..fk.chunk_len {
-        toeplitz_coeffs =
-            toeplitz_coeffs_stride(p, i, fk.chunk_len, toeplitz_coeffs.len()).unwrap();
-        let h_ext_fft_file =
-            toeplitz_part_2(&toeplitz_coeffs, &fk.x_ext_fft_files[i], &fk.ks.fs).unwrap();
-        for j in 0..k2 {
-            h_ext_fft[j] = h_ext_fft[j].add_or_dbl(&h_ext_fft_file[j]);
+        // Calculate `h`
+        let mut h = toeplitz_part_3_brp(&h_ext_fft, &fk.ks.fs).unwrap();
+
+        // Overwrite the second half of `h` with zero
+        for i in h.iter_mut().take(k2).skip(k) {
+            i.proj = G1_IDENTITY.proj;
         }
-    }
 
-    // Calculate `h`
-    let mut h = toeplitz_part_3(&h_ext_fft, &fk.ks.fs).unwrap();
+        fk.ks.fs.fft_g1_inplace(&mut h, false)?;
 
-    // Overwrite the second half of `h` with zero
-    for i in h.iter_mut().take(k2).skip(k) {
-        i.proj = G1_IDENTITY.proj;
-    }
+        Ok(h)
+    };
+
+    #[cfg(feature = "parallel")]
+    return fk.ks.execution_context.install(proofs);
 
-    fk.ks.fs.fft_g1(&h, false)
+    #[cfg(not(feature = "parallel"))]
+    proofs()
 }
 
 fn toeplitz_coeffs_step(p: &PolyData, outlen: usize) -> Result<PolyData, String> {
@@ -272,7 +323,7 @@ fn toeplitz_coeffs_stride(
     Ok(out)
 }
 
-fn toeplitz_part_1(x: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
+fn toeplitz_part_1_brp(x: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
     let n = x.len();
     let n2 = n * 2;
 
@@ -283,41 +334,112 @@ fn toeplitz_part_1(x: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
     for _i in n..n2 {
         x_ext.push(G1_IDENTITY);
     }
-    fs.fft_g1(&x_ext, false)
+
+    // Left in bit-reversed order, as is everything up to `toeplitz_part_3_brp`
+    fft_g1_dif(&mut x_ext, &fs.expanded_roots_of_unity, fs.max_width / n2);
+    Ok(x_ext)
+}
+
+/// Fixed-base tables for `toeplitz_part_2_multi_brp`. Table `j` holds the `j`-th point of every
+/// `toeplitz_part_1_brp` output in `x_ext_fft_files`, and `max_table_size` caps the precomputed
+/// points of all tables together.
+fn toeplitz_part_2_tables(
+    x_ext_fft_files: &[Vec<ZG1>],
+    window: Option<usize>,
+    max_table_size: Option<usize>,
+) -> Result<Vec<FixedBaseMsm<BlstFr, ZG1, ZFp, ZG1Affine>>, String> {
+    let n2 = x_ext_fft_files[0].len();
+    let max_table_size = max_table_size.map(|max| max / n2);
+
+    let column = |j: usize| {
+        let points = x_ext_fft_files
+            .iter()
+            .map(|x_ext_fft| x_ext_fft[j])
+            .collect::<Vec<_>>();
+        FixedBaseMsm::with_params(&points, window, max_table_size)
+    };
+
+    #[cfg(feature = "parallel")]
+    {
+        (0..n2).into_par_iter().map(column).collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        (0..n2).map(column).collect()
+    }
 }
 
-fn toeplitz_part_2(
-    toeplitz_coeffs: &PolyData,
-    x_ext_fft: &[ZG1],
+/// Sum of the pointwise Toeplitz products over all `toeplitz_coeffs`
+fn toeplitz_part_2_multi_brp(
+    toeplitz_coeffs: &[PolyData],
+    x_ext_fft_tables: &[FixedBaseMsm<BlstFr, ZG1, ZFp, ZG1Affine>],
     fs: &FFTSettings,
 ) -> Result<Vec<ZG1>, String> {
-    let toeplitz_coeffs_fft = fs.fft_fr(&toeplitz_coeffs.coeffs, false).unwrap();
+    let toeplitz_coeffs_fft = toeplitz_coeffs
+        .iter()
+        .map(|poly| toeplitz_coeffs_fft_brp(poly, fs))
+        .collect::<Vec<_>>();
+
+    let column = |(j, table): (usize, &FixedBaseMsm<BlstFr, ZG1, ZFp, ZG1Affine>)| {
+        let scalars = toeplitz_coeffs_fft
+            .iter()
+            .map(|coeffs_fft| coeffs_fft[j])
+            .collect::<Vec<_>>();
+        table.multiply(&scalars).map(jacobian_to_homogeneous)
+    };
 
     #[cfg(feature = "parallel")]
     {
-        let out: Vec<_> = (0..toeplitz_coeffs.len())
-            .into_par_iter()
-            .map(|i| x_ext_fft[i].mul(&toeplitz_coeffs_fft[i]))
-            .collect();
-        Ok(out)
+        x_ext_fft_tables
+            .par_iter()
+            .enumerate()
+            .map(column)
+            .collect()
     }
 
     #[cfg(not(feature = "parallel"))]
     {
-        let mut out = Vec::new();
-        for i in 0..toeplitz_coeffs.len() {
-            out.push(x_ext_fft[i].mul(&toeplitz_coeffs_fft[i]));
-        }
-        Ok(out)
+        x_ext_fft_tables.iter().enumerate().map(column).collect()
     }
 }
 
-fn toeplitz_part_3(h_ext_fft: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
-    let n = h_ext_fft.len() / 2;
-    let mut out = fs.fft_g1(h_ext_fft, true).unwrap();
+/// `FixedBaseMsm`, like the rest of `kzg::msm`, leaves its result in Jacobian coordinates, while
+/// `G1Projective` is homogeneous. `(X : Y : Z)` in Jacobian coordinates is `(XZ : Y : Z^3)` in
+/// homogeneous ones.
+fn jacobian_to_homogeneous(p: ZG1) -> ZG1 {
+    let G1Projective { x, y, z } = p.proj;
+    ZG1::from_g1_projective(G1Projective {
+        x: x * z,
+        y,
+        z: z.square() * z,
+    })
+}
+
+fn toeplitz_coeffs_fft_brp(toeplitz_coeffs: &PolyData, fs: &FFTSettings) -> Vec<BlstFr> {
+    let mut coeffs = toeplitz_coeffs.coeffs.clone();
+    fft_fr_dif(
+        &mut coeffs,
+        &fs.expanded_roots_of_unity,
+        fs.max_width / toeplitz_coeffs.len(),
+    );
+    coeffs
+}
+
+fn toeplitz_part_3_brp(h_ext_fft: &[ZG1], fs: &FFTSettings) -> Result<Vec<ZG1>, String> {
+    let n2 = h_ext_fft.len();
+    let n = n2 / 2;
+    let mut out = h_ext_fft.to_vec();
+    fft_g1_dit(&mut out, &fs.reverse_roots_of_unity, fs.max_width / n2);
+
+    // Only the first half survives, so only it needs dividing by n2
+    let inv_len = BlstFr::from_u64(n2 as u64).inverse();
+    for i in out.iter_mut().take(n) {
+        *i = i.mul(&inv_len);
+    }
 
     // Zero the second half of h
-    for i in out.iter_mut().take(h_ext_fft.len()).skip(n) {
+    for i in out.iter_mut().take(n2).skip(n) {
         i.proj = G1_IDENTITY.proj;
     }
     Ok(out)
@@ -1,15 +1,21 @@
 #![allow(non_camel_case_types)]
 use crate::consts::{G1_GENERATOR, G2_GENERATOR};
-use crate::kzg_types::{ZFp, ZFr, ZG1Affine};
+use crate::kzg_types::ZFr;
+#[cfg(feature = "prover")]
+use crate::kzg_types::{ZFp, ZG1Affine};
 use crate::kzg_types::{ZFr as BlstFr, ZG1, ZG2};
 use crate::poly::PolyData;
 use bls12_381::{
     multi_miller_loop, Fp12 as ZFp12, G1Affine, G2Affine, G2Prepared, MillerLoopResult,
 };
 use kzg::eip_4844::hash_to_bls_field;
+use kzg::fft::CosetCache;
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::PrecomputationTable;
 use kzg::{Fr as FrTrait, G1Mul, G2Mul};
 use std::ops::{Add, Neg};
+#[cfg(feature = "prover")]
+use std::sync::OnceLock;
 
 #[derive(Debug, Clone)]
 pub struct FFTSettings {
@@ -18,6 +24,10 @@ pub struct FFTSettings {
     pub expanded_roots_of_unity: Vec<BlstFr>,
     pub reverse_roots_of_unity: Vec<BlstFr>,
     pub roots_of_unity: Vec<BlstFr>,
+    pub coset_cache: CosetCache<BlstFr>,
+    /// Pool FFTs and recovery run on, see [`kzg::FFTSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>, String> {
@@ -38,12 +48,22 @@ pub fn expand_root_of_unity(root: &BlstFr, width: usize) -> Result<Vec<BlstFr>,
     Ok(generated_powers)
 }
 
+#[cfg(feature = "prover")]
 #[derive(Debug, Clone, Default)]
 pub struct KZGSettings {
     pub fs: FFTSettings,
     pub secret_g1: Vec<ZG1>,
+    /// `[τ^i]` in G1 when `secret_g1` are Lagrange-form points, as for loaded EIP-4844 setups,
+    /// derived from them on first use unless given. `None` when `secret_g1` are the monomial
+    /// powers themselves.
+    pub secret_g1_monomial: Option<OnceLock<Vec<ZG1>>>,
+    /// `[τ^i]` in G2
     pub secret_g2: Vec<ZG2>,
     pub precomputation: Option<PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>>,
+    /// Pool commitments, proofs and batch verification run on, see
+    /// [`KZGSettings::get_execution_context`]
+    #[cfg(feature = "parallel")]
+    pub execution_context: kzg::msm::thread_pool::ExecutionContext,
 }
 
 pub fn generate_trusted_setup(len: usize, secret: [u8; 32usize]) -> (Vec<ZG1>, Vec<ZG2>) {
@@ -2,10 +2,12 @@ use crate::consts::{
     G1_GENERATOR, G1_IDENTITY, G1_NEGATIVE_GENERATOR, G2_GENERATOR, G2_NEGATIVE_GENERATOR,
     SCALE2_ROOT_OF_UNITY,
 };
+#[cfg(feature = "prover")]
 use crate::fft_g1::g1_linear_combination;
-use crate::kzg_proofs::{
-    expand_root_of_unity, pairings_verify, FFTSettings as ZFFTSettings, KZGSettings as ZKZGSettings,
-};
+#[cfg(feature = "prover")]
+use crate::kzg_proofs::KZGSettings as ZKZGSettings;
+use crate::kzg_proofs::{expand_root_of_unity, pairings_verify, FFTSettings as ZFFTSettings};
+#[cfg(feature = "prover")]
 use crate::poly::PolyData;
 use crate::utils::{
     blst_fr_into_pc_fr, blst_p1_into_pc_g1projective, blst_p2_into_pc_g2projective,
@@ -15,17 +17,30 @@ use bls12_381::{Fp, G1Affine, G1Projective, G2Affine, G2Projective, Scalar, MODU
 use blst::{blst_fr, blst_p1};
 use ff::Field;
 use kzg::common_utils::reverse_bit_order;
+#[cfg(feature = "prover")]
+use kzg::eip_4844::g1_monomial_from_lagrange_brp;
 use kzg::eip_4844::{BYTES_PER_FIELD_ELEMENT, BYTES_PER_G1, BYTES_PER_G2};
+use kzg::fft::ShiftPowers;
+#[cfg(feature = "prover")]
+use kzg::msm::fixed_base::{
+    g1_fixed_base_mul, g1_fixed_base_table, g2_fixed_base_mul, g2_fixed_base_table, FixedBaseTable,
+};
+#[cfg(feature = "prover")]
 use kzg::msm::precompute::{precompute, PrecomputationTable};
 use kzg::G1Affine as G1AffineTrait;
+#[cfg(feature = "prover")]
+use kzg::{FFTFr, G1LinComb, KZGSettings, Poly};
 use kzg::{
-    FFTFr, FFTSettings, Fr as KzgFr, G1Fp, G1GetFp, G1LinComb, G1Mul, G1ProjAddAffine, G2Mul,
-    KZGSettings, PairingVerify, Poly, Scalar256, G1, G2,
+    FFTSettings, Fr as KzgFr, G1ConstantTime, G1Fp, G1GetFp, G1Mul, G1ProjAddAffine, G2Mul,
+    PairingVerify, Scalar256, G1, G2,
 };
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use std::sync::Arc;
+#[cfg(feature = "prover")]
+use std::sync::OnceLock;
 
 use ff::derive::sbb;
-use subtle::{Choice, ConstantTimeEq, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 fn to_scalar(zfr: &ZFr) -> Scalar {
     zfr.fr
@@ -512,12 +527,26 @@ impl G1 for ZG1 {
     };
 }
 
+impl G1ConstantTime for ZG1 {
+    fn conditional_select(a: &Self, b: &Self, choice: u64) -> Self {
+        Self {
+            proj: G1Projective::conditional_select(&a.proj, &b.proj, Choice::from(choice as u8)),
+        }
+    }
+}
+
 impl G1Mul<ZFr> for ZG1 {
     fn mul(&self, b: &ZFr) -> Self {
         Self {
             proj: self.proj.mul(b.fr),
         }
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &ZFr) -> Self {
+        static TABLE: OnceLock<FixedBaseTable<ZG1>> = OnceLock::new();
+        g1_fixed_base_mul(TABLE.get_or_init(|| g1_fixed_base_table(&G1_GENERATOR)), b)
+    }
 }
 
 #[repr(C)]
@@ -643,6 +672,7 @@ impl G1GetFp<ZFp> for ZG1 {
     }
 }
 
+#[cfg(feature = "prover")]
 impl G1LinComb<ZFr, ZFp, ZG1Affine> for ZG1 {
     fn g1_lincomb(
         points: &[Self],
@@ -742,6 +772,12 @@ impl G2Mul<ZFr> for ZG2 {
             proj: self.proj.mul(b.fr),
         }
     }
+
+    #[cfg(feature = "prover")]
+    fn generator_mul(b: &ZFr) -> Self {
+        static TABLE: OnceLock<FixedBaseTable<ZG2>> = OnceLock::new();
+        g2_fixed_base_mul(TABLE.get_or_init(|| g2_fixed_base_table(&G2_GENERATOR)), b)
+    }
 }
 
 impl Default for ZFFTSettings {
@@ -752,6 +788,9 @@ impl Default for ZFFTSettings {
             expanded_roots_of_unity: Vec::new(),
             reverse_roots_of_unity: Vec::new(),
             roots_of_unity: Vec::new(),
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         }
     }
 }
@@ -784,6 +823,9 @@ impl FFTSettings<ZFr> for ZFFTSettings {
             expanded_roots_of_unity,
             reverse_roots_of_unity,
             roots_of_unity,
+            coset_cache: Default::default(),
+            #[cfg(feature = "parallel")]
+            execution_context: Default::default(),
         })
     }
 
@@ -814,8 +856,18 @@ impl FFTSettings<ZFr> for ZFFTSettings {
     fn get_roots_of_unity(&self) -> &[ZFr] {
         &self.roots_of_unity
     }
+
+    fn get_coset_shift_powers(&self, shift: &ZFr, len: usize) -> Arc<ShiftPowers<ZFr>> {
+        self.coset_cache.get(shift, len)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
 
+#[cfg(feature = "prover")]
 impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZGSettings {
     fn new(
         secret_g1: &[ZG1],
@@ -825,19 +877,63 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
     ) -> Result<ZKZGSettings, String> {
         Ok(Self {
             secret_g1: secret_g1.to_vec(),
+            secret_g1_monomial: None,
             secret_g2: secret_g2.to_vec(),
             fs: fft_settings.clone(),
             precomputation: precompute(secret_g1).ok().flatten(),
+            #[cfg(feature = "parallel")]
+            execution_context: fft_settings.execution_context.clone(),
+        })
+    }
+
+    fn new_with_monomial(
+        secret_g1: &[ZG1],
+        secret_g1_monomial: Option<&[ZG1]>,
+        secret_g2: &[ZG2],
+        length: usize,
+        fft_settings: &ZFFTSettings,
+    ) -> Result<ZKZGSettings, String> {
+        let monomial = match secret_g1_monomial {
+            Some(secret_g1_monomial) => {
+                if secret_g1_monomial.len() != secret_g1.len() {
+                    return Err(String::from(
+                        "Monomial and Lagrange-form G1 points must be as many",
+                    ));
+                }
+                OnceLock::from(secret_g1_monomial.to_vec())
+            }
+            None => {
+                // Checked here, deriving the monomial points later can't return an error
+                if !secret_g1.len().is_power_of_two() || secret_g1.len() > fft_settings.max_width {
+                    return Err(String::from(
+                        "Lagrange-form G1 points must be a power of two, up to the FFT width",
+                    ));
+                }
+                OnceLock::new()
+            }
+        };
+
+        Ok(Self {
+            secret_g1_monomial: Some(monomial),
+            ..Self::new(secret_g1, secret_g2, length, fft_settings)?
         })
     }
 
     fn commit_to_poly(&self, p: &PolyData) -> Result<ZG1, String> {
-        if p.coeffs.len() > self.secret_g1.len() {
+        let secret_g1 = self.get_g1_monomial();
+        if p.coeffs.len() > secret_g1.len() {
             return Err(String::from("Polynomial is longer than secret g1"));
         }
 
         let mut out = ZG1::default();
-        g1_linear_combination(&mut out, &self.secret_g1, &p.coeffs, p.coeffs.len(), None);
+        let lincomb =
+            |out: &mut ZG1| g1_linear_combination(out, secret_g1, &p.coeffs, p.coeffs.len(), None);
+
+        #[cfg(feature = "parallel")]
+        self.execution_context.install(|| lincomb(&mut out));
+
+        #[cfg(not(feature = "parallel"))]
+        lincomb(&mut out);
 
         Ok(out)
     }
@@ -847,26 +943,15 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
             return Err(String::from("Polynomial must not be empty"));
         }
 
-        // `-(x0^n)`, where `n` is `1`
-        let divisor_0 = x.negate();
-
-        // Calculate `q = p / (x^n - x0^n)` for our reduced case (see `compute_proof_multi` for
-        // generic implementation)
-        let mut out_coeffs = Vec::from(&p.coeffs[1..]);
-        for i in (1..out_coeffs.len()).rev() {
-            let tmp = out_coeffs[i].mul(&divisor_0);
-            out_coeffs[i - 1] = out_coeffs[i - 1].sub(&tmp);
-        }
-
-        let q = PolyData { coeffs: out_coeffs };
+        let q = p.divide_by_linear(x);
         let ret = self.commit_to_poly(&q)?;
         Ok(ret)
     }
 
     fn check_proof_single(&self, com: &ZG1, proof: &ZG1, x: &ZFr, y: &ZFr) -> Result<bool, String> {
-        let x_g2 = G2_GENERATOR.mul(x);
+        let x_g2 = ZG2::generator_mul(x);
         let s_minus_x: ZG2 = self.secret_g2[1].sub(&x_g2);
-        let y_g1 = G1_GENERATOR.mul(y);
+        let y_g1 = ZG1::generator_mul(y);
         let commitment_minus_y: ZG1 = com.sub(&y_g1);
 
         Ok(pairings_verify(
@@ -908,7 +993,14 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
 
         // Calculate q = p / (x^n - x0^n)
         // let q = p.div(&divisor).unwrap();
-        let q = new_polina.div(&divisor)?;
+        let divide = |dividend: &mut PolyData| dividend.div(&divisor);
+
+        #[cfg(feature = "parallel")]
+        let q = self.execution_context.install(|| divide(&mut new_polina))?;
+
+        #[cfg(not(feature = "parallel"))]
+        let q = divide(&mut new_polina)?;
+
         let ret = self.commit_to_poly(&q)?;
         Ok(ret)
     }
@@ -926,21 +1018,14 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         }
 
         // Interpolate at a coset.
-        let mut interp = PolyData {
-            coeffs: self.fs.fft_fr(ys, true)?,
+        let interp = PolyData {
+            coeffs: self.fs.coset_fft_fr(ys, x, true)?,
         };
 
-        let inv_x = x.inverse(); // Not euclidean?
-        let mut inv_x_pow = inv_x;
-        for i in 1..n {
-            interp.coeffs[i] = interp.coeffs[i].mul(&inv_x_pow);
-            inv_x_pow = inv_x_pow.mul(&inv_x);
-        }
-
         // [x^n]_2
-        let x_pow = inv_x_pow.inverse();
+        let x_pow = x.pow(n);
 
-        let xn2 = G2_GENERATOR.mul(&x_pow);
+        let xn2 = ZG2::generator_mul(&x_pow);
 
         // [s^n - x^n]_2
         let xn_minus_yn = self.secret_g2[n].sub(&xn2);
@@ -971,6 +1056,24 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
         &self.secret_g1
     }
 
+    fn get_g1_monomial(&self) -> &[ZG1] {
+        match &self.secret_g1_monomial {
+            None => &self.secret_g1,
+            // Only fails for settings built by hand with a wrong number of points
+            Some(monomial) => monomial.get_or_init(|| {
+                g1_monomial_from_lagrange_brp(&self.secret_g1, &self.fs).unwrap_or_default()
+            }),
+        }
+    }
+
+    fn get_g1_lagrange_brp(&self) -> &[ZG1] {
+        if self.secret_g1_monomial.is_some() {
+            &self.secret_g1
+        } else {
+            &[]
+        }
+    }
+
     fn get_g2_secret(&self) -> &[ZG2] {
         &self.secret_g2
     }
@@ -978,4 +1081,9 @@ impl KZGSettings<ZFr, ZG1, ZG2, ZFFTSettings, PolyData, ZFp, ZG1Affine> for ZKZG
     fn get_precomputation(&self) -> Option<&PrecomputationTable<ZFr, ZG1, ZFp, ZG1Affine>> {
         self.precomputation.as_ref()
     }
+
+    #[cfg(feature = "parallel")]
+    fn get_execution_context(&self) -> &kzg::msm::thread_pool::ExecutionContext {
+        &self.execution_context
+    }
 }
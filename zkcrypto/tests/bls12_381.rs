@@ -62,6 +62,16 @@ mod tests {
         p1_mul_works::<ZFr, ZG1>();
     }
 
+    #[test]
+    pub fn g1_generator_mul_works_() {
+        g1_generator_mul_works::<ZFr, ZG1>();
+    }
+
+    #[test]
+    pub fn g1_mul_ct_works_() {
+        g1_mul_ct_works::<ZFr, ZG1>();
+    }
+
     #[test]
     pub fn p1_sub_works_() {
         p1_sub_works::<ZG1>();
@@ -77,6 +87,11 @@ mod tests {
         p2_mul_works::<ZFr, ZG2>();
     }
 
+    #[test]
+    pub fn g2_generator_mul_works_() {
+        g2_generator_mul_works::<ZFr, ZG2>();
+    }
+
     #[test]
     pub fn p2_sub_works_() {
         p2_sub_works::<ZG2>();
@@ -102,6 +117,11 @@ mod tests {
         g1_random_linear_combination::<ZFr, ZG1, ZFp, ZG1Affine>(&g1_linear_combination);
     }
 
+    #[test]
+    pub fn g1_sparse_linear_combination_() {
+        g1_sparse_linear_combination::<ZFr, ZG1, ZFp, ZG1Affine>(&g1_linear_combination);
+    }
+
     #[test]
     pub fn pairings_work_() {
         pairings_work::<ZFr, ZG1, ZG2>(&pairings_verify);
@@ -111,4 +131,9 @@ mod tests {
     pub fn fr_is_null_works_() {
         fr_is_null_works::<ZFr>();
     }
+
+    #[test]
+    pub fn g1_lincomb_ct_works_() {
+        g1_lincomb_ct_works::<ZFr, ZG1, ZFp, ZG1Affine>();
+    }
 }
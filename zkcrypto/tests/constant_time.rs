@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::constant_time::{
+        g1_lincomb_ct_is_constant_time, g1_mul_ct_is_constant_time,
+    };
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1};
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    pub fn g1_mul_ct_is_constant_time_() {
+        g1_mul_ct_is_constant_time::<ZFr, ZG1>();
+    }
+
+    #[test]
+    #[ignore = "timing measurement, run alone with --ignored"]
+    pub fn g1_lincomb_ct_is_constant_time_() {
+        g1_lincomb_ct_is_constant_time::<ZFr, ZG1, ZFp, ZG1Affine>();
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::das::{das_extension_test_known, das_extension_test_random};
+    use kzg_bench::tests::das::{
+        das_extension_k_test_random, das_extension_test_known, das_extension_test_random,
+    };
     use rust_kzg_zkcrypto::kzg_proofs::FFTSettings;
     use rust_kzg_zkcrypto::kzg_types::ZFr;
 
@@ -13,4 +15,9 @@ mod tests {
     fn das_extension_test_random_() {
         das_extension_test_random::<ZFr, FFTSettings>();
     }
+
+    #[test]
+    fn das_extension_k_test_random_() {
+        das_extension_k_test_random::<ZFr, FFTSettings>();
+    }
 }
@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::das2d::{das_2d_commitments, das_2d_extend_and_recover};
+    use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::poly::PolyData;
+
+    #[test]
+    fn das_2d_extend_and_recover_() {
+        das_2d_extend_and_recover::<ZFr, FFTSettings, PolyData, PolyData>();
+    }
+
+    #[test]
+    fn das_2d_commitments_() {
+        das_2d_commitments::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+}
@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod tests {
-    use kzg_bench::tests::fft_g1::{compare_sft_fft, roundtrip_fft, stride_fft};
+    use kzg_bench::tests::fft_g1::{
+        compare_sft_fft, coset_fft, inplace_fft, roundtrip_fft, stride_fft,
+    };
     use rust_kzg_zkcrypto::fft_g1::{fft_g1_fast, fft_g1_slow, make_data};
     use rust_kzg_zkcrypto::kzg_proofs::FFTSettings;
     use rust_kzg_zkcrypto::kzg_types::{ZFr, ZG1};
@@ -19,4 +21,14 @@ mod tests {
     fn compare_sft_fft_() {
         compare_sft_fft::<ZFr, ZG1, FFTSettings>(&fft_g1_slow, &fft_g1_fast, &make_data);
     }
+
+    #[test]
+    fn inplace_fft_() {
+        inplace_fft::<ZFr, ZG1, FFTSettings>(&make_data);
+    }
+
+    #[test]
+    fn coset_fft_() {
+        coset_fft::<ZFr, ZG1, FFTSettings>(&make_data);
+    }
 }
@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use kzg_bench::tests::kzg_proofs::{
-        commit_to_nil_poly, commit_to_too_long_poly_returns_err, proof_multi, proof_single,
+        commit_to_nil_poly, commit_to_too_long_poly_returns_err, commitment_schemes,
+        degree_bound_proofs, hiding_commitments, proof_aggregation, proof_batch_same_point,
+        proof_multi, proof_shplonk, proof_single,
     };
     use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
     use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
@@ -39,4 +41,46 @@ mod tests {
             &generate_trusted_setup,
         );
     }
+
+    #[test]
+    fn proof_batch_same_point_() {
+        proof_batch_same_point::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn proof_shplonk_() {
+        proof_shplonk::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn proof_aggregation_() {
+        proof_aggregation::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn hiding_commitments_() {
+        hiding_commitments::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn degree_bound_proofs_() {
+        degree_bound_proofs::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
+
+    #[test]
+    fn commitment_schemes_() {
+        commitment_schemes::<ZFr, ZG1, ZG2, PolyData, FFTSettings, KZGSettings, ZFp, ZG1Affine>(
+            &generate_trusted_setup,
+        );
+    }
 }
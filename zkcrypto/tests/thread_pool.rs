@@ -0,0 +1,31 @@
+#[cfg(all(test, feature = "parallel"))]
+mod tests {
+    use kzg::msm::thread_pool::ExecutionContext;
+    use kzg::FFTSettings as _;
+    use kzg_bench::tests::msm::thread_pool::fft_and_fk20_stay_in_execution_context;
+    use rust_kzg_zkcrypto::fk20_proofs::{KzgFK20MultiSettings, KzgFK20SingleSettings};
+    use rust_kzg_zkcrypto::kzg_proofs::{generate_trusted_setup, FFTSettings, KZGSettings};
+    use rust_kzg_zkcrypto::kzg_types::{ZFp, ZFr, ZG1Affine, ZG1, ZG2};
+    use rust_kzg_zkcrypto::poly::PolyData;
+
+    #[test]
+    pub fn fft_and_fk20_stay_in_execution_context_() {
+        let fs = FFTSettings {
+            execution_context: ExecutionContext::with_threads(1).unwrap(),
+            ..FFTSettings::new(12).unwrap()
+        };
+
+        fft_and_fk20_stay_in_execution_context::<
+            ZFr,
+            ZG1,
+            ZG2,
+            PolyData,
+            FFTSettings,
+            KZGSettings,
+            KzgFK20SingleSettings,
+            KzgFK20MultiSettings,
+            ZFp,
+            ZG1Affine,
+        >(&generate_trusted_setup, &fs);
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use kzg_bench::tests::eip_4844::test_vectors_verify_with_embedded_key;
+    use rust_kzg_zkcrypto::kzg_types::{ZFr, ZG1, ZG2};
+
+    #[test]
+    pub fn test_vectors_verify_with_embedded_key_() {
+        test_vectors_verify_with_embedded_key::<ZFr, ZG1, ZG2>();
+    }
+}